@@ -0,0 +1,398 @@
+//! 异步缓存
+//!
+//! 对应示例10中的“异步缓存模式”。示例里等待者在加载者插入数据之后才开始等待通知，
+//! 可能错过`notify_waiters()`而永远挂起，或者重复加载同一个key。
+//! 这里把“检查缓存、登记加载、注册等待”放在同一次加锁中完成，保证：
+//!
+//! - 同一个key同时只有一个加载者
+//! - 等待者在释放锁之前就已经注册，不会错过唤醒
+//! - 被唤醒后重新检查缓存，而不是假设值一定存在
+//!
+//! [`SharedCache`]来自智能指针教程中基于`RefCell`的缓存，把它改造成可以在多个任务间共享的版本。
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::{Notify, RwLock};
+
+/// 同一个key只加载一次的异步缓存
+///
+/// ```
+/// use rust_tutor_async::cache::AsyncCache;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let cache = AsyncCache::new();
+/// let value = cache.get_or_load(1, |id| async move { format!("用户{}", id) }).await;
+/// assert_eq!(value, "用户1");
+/// assert_eq!(cache.get(&1), Some("用户1".to_string()));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AsyncCache<K, V> {
+    state: Mutex<CacheState<K, V>>,
+}
+
+#[derive(Debug)]
+struct CacheState<K, V> {
+    data: HashMap<K, V>,
+    // 正在加载的key，以及加载完成时用来唤醒等待者的通知
+    loading: HashMap<K, Arc<Notify>>,
+}
+
+impl<K, V> AsyncCache<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    /// 创建空缓存
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(CacheState {
+                data: HashMap::new(),
+                loading: HashMap::new(),
+            }),
+        }
+    }
+
+    /// 读取已缓存的值，不触发加载
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.state.lock().unwrap().data.get(key).cloned()
+    }
+
+    /// 已缓存的条目数
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().data.len()
+    }
+
+    /// 缓存是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 读取缓存，未命中时调用`loader`加载
+    ///
+    /// 多个任务同时请求同一个未缓存的key时，只有第一个任务执行`loader`，
+    /// 其余任务等待它完成后直接读取结果。
+    pub async fn get_or_load<F, Fut>(&self, key: K, loader: F) -> V
+    where
+        F: FnOnce(K) -> Fut,
+        Fut: Future<Output = V>,
+    {
+        let notify = loop {
+            let notify = {
+                let mut state = self.state.lock().unwrap();
+                if let Some(value) = state.data.get(&key) {
+                    return value.clone();
+                }
+                match state.loading.get(&key) {
+                    Some(notify) => notify.clone(),
+                    None => {
+                        // 没有人在加载，由当前任务负责加载
+                        let notify = Arc::new(Notify::new());
+                        state.loading.insert(key.clone(), notify.clone());
+                        break notify;
+                    }
+                }
+            };
+
+            // 其他任务正在加载：先创建Notified再检查一次状态，
+            // notify_waiters()能唤醒已经创建（哪怕尚未poll）的Notified
+            let notified = notify.notified();
+            {
+                let state = self.state.lock().unwrap();
+                if state.data.contains_key(&key) || !state.loading.contains_key(&key) {
+                    // 加载已经结束（或被取消），回到循环开头重新检查
+                    continue;
+                }
+            }
+            notified.await;
+        };
+
+        // 即使加载过程被取消，守卫也会清理加载状态并唤醒等待者，让它们重新竞争加载
+        let guard = LoadGuard {
+            cache: self,
+            key: &key,
+            notify,
+        };
+        let value = loader(key.clone()).await;
+        self.state
+            .lock()
+            .unwrap()
+            .data
+            .insert(key.clone(), value.clone());
+        drop(guard);
+        value
+    }
+}
+
+impl<K, V> Default for AsyncCache<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 加载者持有的守卫，离开作用域时移除加载标记并通知所有等待者
+struct LoadGuard<'a, K: Eq + Hash, V> {
+    cache: &'a AsyncCache<K, V>,
+    key: &'a K,
+    notify: Arc<Notify>,
+}
+
+impl<K: Eq + Hash, V> Drop for LoadGuard<'_, K, V> {
+    fn drop(&mut self) {
+        self.cache.state.lock().unwrap().loading.remove(self.key);
+        self.notify.notify_waiters();
+    }
+}
+
+/// 可以在多个任务间共享的键值缓存，记录命中和未命中次数
+///
+/// 读取只获取读锁，多个读者可以同时读取；命中统计用原子变量记录，读取时不需要写锁。
+///
+/// ```
+/// use rust_tutor_async::cache::SharedCache;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let cache = SharedCache::new();
+/// cache.set("user:1", "张三").await;
+/// assert_eq!(cache.get(&"user:1").await, Some("张三"));
+/// assert_eq!(cache.get(&"user:2").await, None);
+///
+/// let stats = cache.stats();
+/// assert_eq!((stats.hits, stats.misses), (1, 1));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SharedCache<K, V> {
+    data: RwLock<HashMap<K, V>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// 缓存命中统计的快照
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// 总请求数
+    pub fn total(&self) -> u64 {
+        self.hits + self.misses
+    }
+
+    /// 命中率，没有请求时为0
+    pub fn hit_rate(&self) -> f64 {
+        if self.total() == 0 {
+            0.0
+        } else {
+            self.hits as f64 / self.total() as f64
+        }
+    }
+}
+
+impl<K, V> SharedCache<K, V>
+where
+    K: Eq + Hash,
+    V: Clone,
+{
+    /// 创建空缓存
+    pub fn new() -> Self {
+        Self {
+            data: RwLock::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// 读取缓存的值，并记录一次命中或未命中
+    pub async fn get(&self, key: &K) -> Option<V> {
+        let value = self.data.read().await.get(key).cloned();
+        let counter = if value.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        value
+    }
+
+    /// 写入值，返回被替换的旧值
+    pub async fn set(&self, key: K, value: V) -> Option<V> {
+        self.data.write().await.insert(key, value)
+    }
+
+    /// 删除值，返回被删除的值
+    pub async fn remove(&self, key: &K) -> Option<V> {
+        self.data.write().await.remove(key)
+    }
+
+    /// 已缓存的条目数
+    pub async fn len(&self) -> usize {
+        self.data.read().await.len()
+    }
+
+    /// 缓存是否为空
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+
+    /// 当前的命中统计
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl<K, V> Default for SharedCache<K, V>
+where
+    K: Eq + Hash,
+    V: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::time::Duration;
+    use tokio::time::{sleep, timeout};
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_requests_load_once() {
+        let cache = Arc::new(AsyncCache::new());
+        let loads = Arc::new(AtomicUsize::new(0));
+
+        let tasks: Vec<_> = (0..100)
+            .map(|_| {
+                let cache = cache.clone();
+                let loads = loads.clone();
+                tokio::spawn(async move {
+                    cache
+                        .get_or_load("key", |key| async move {
+                            loads.fetch_add(1, Ordering::SeqCst);
+                            sleep(Duration::from_millis(50)).await;
+                            format!("{}的值", key)
+                        })
+                        .await
+                })
+            })
+            .collect();
+
+        // 如果有任务错过唤醒，这里会超时
+        let results = timeout(Duration::from_secs(5), futures::future::join_all(tasks))
+            .await
+            .expect("有任务没有被唤醒");
+
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+        for result in results {
+            assert_eq!(result.unwrap(), "key的值");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cached_value_skips_loader() {
+        let cache = AsyncCache::new();
+        assert_eq!(cache.get_or_load(1, |_| async { 10 }).await, 10);
+        // 命中缓存时不会调用第二个loader
+        assert_eq!(cache.get_or_load(1, |_| async { 20 }).await, 10);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_load_lets_waiter_take_over() {
+        let cache = AsyncCache::new();
+
+        // 第一个加载者被超时取消，等待者应当接手加载而不是挂起
+        let cancelled = timeout(
+            Duration::from_millis(20),
+            cache.get_or_load(1, |_| async {
+                sleep(Duration::from_secs(10)).await;
+                "慢加载"
+            }),
+        );
+        let waiter = async {
+            sleep(Duration::from_millis(5)).await;
+            cache.get_or_load(1, |_| async { "接手加载" }).await
+        };
+        let (first, second) = tokio::join!(cancelled, waiter);
+
+        assert!(first.is_err());
+        assert_eq!(second, "接手加载");
+    }
+
+    #[tokio::test]
+    async fn test_shared_cache_get_set() {
+        let cache = SharedCache::new();
+        assert!(cache.is_empty().await);
+        assert_eq!(cache.set(1, "a").await, None);
+        assert_eq!(cache.set(1, "b").await, Some("a"));
+        assert_eq!(cache.get(&1).await, Some("b"));
+        assert_eq!(cache.remove(&1).await, Some("b"));
+        assert_eq!(cache.get(&1).await, None);
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+        assert_eq!(cache.stats().hit_rate(), 0.5);
+        assert_eq!(CacheStats::default().hit_rate(), 0.0);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_shared_cache_concurrent_readers_and_writer() {
+        const READERS: u64 = 8;
+        const READS: u64 = 1_000;
+
+        let cache = Arc::new(SharedCache::new());
+        for key in 0..10 {
+            cache.set(key, key * 10).await;
+        }
+
+        // 读者读取0..20，其中0..10已缓存；写者同时写入100..200，不影响读者的结果
+        let readers: Vec<_> = (0..READERS)
+            .map(|_| {
+                let cache = cache.clone();
+                tokio::spawn(async move {
+                    for i in 0..READS {
+                        let key = i % 20;
+                        let value = cache.get(&key).await;
+                        assert_eq!(value, (key < 10).then_some(key * 10));
+                        tokio::task::yield_now().await;
+                    }
+                })
+            })
+            .collect();
+        let writer = {
+            let cache = cache.clone();
+            tokio::spawn(async move {
+                for key in 100..200 {
+                    cache.set(key, key).await;
+                    tokio::task::yield_now().await;
+                }
+            })
+        };
+
+        writer.await.unwrap();
+        for reader in readers {
+            reader.await.unwrap();
+        }
+
+        let stats = cache.stats();
+        assert_eq!(stats.total(), READERS * READS);
+        assert_eq!(stats.hits, READERS * READS / 2);
+        assert_eq!(stats.misses, READERS * READS / 2);
+        assert_eq!(cache.len().await, 110);
+    }
+}
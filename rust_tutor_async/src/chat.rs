@@ -0,0 +1,245 @@
+//! 基于广播通道的聊天室
+//!
+//! 示例4中的`broadcast`通道只有一个发布者；聊天室里每个成员既发送也接收。
+//! [`Room::join`]为每个成员创建一对发送端和接收端，所有成员共享同一个广播通道。
+//!
+//! 广播通道只保存最近的`capacity`条消息：接收太慢的成员会错过最旧的消息（`Lagged`），
+//! [`Receiver`]会记录错过的数量并继续接收，不会影响聊天室和其他成员。
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use tokio::sync::broadcast::{self, error::RecvError};
+
+/// 聊天室中的一条消息
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    /// 发送者的编号，由[`Room::join`]分配
+    pub from: u64,
+    pub text: String,
+}
+
+/// 聊天室
+///
+/// ```
+/// use rust_tutor_async::chat::Room;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let room = Room::new(16);
+/// let (alice, mut alice_inbox) = room.join();
+/// let (_bob, mut bob_inbox) = room.join();
+///
+/// alice.send("大家好");
+/// let message = bob_inbox.recv().await.unwrap();
+/// assert_eq!((message.from, message.text.as_str()), (alice.id(), "大家好"));
+/// // 默认不会收到自己发送的消息
+/// assert!(alice_inbox.try_recv().is_none());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Room {
+    tx: broadcast::Sender<Message>,
+    next_id: AtomicU64,
+    echo: bool,
+}
+
+impl Room {
+    /// 创建聊天室，每个成员最多积压`capacity`条未读消息
+    pub fn new(capacity: usize) -> Self {
+        let (tx, _) = broadcast::channel(capacity);
+        Room {
+            tx,
+            next_id: AtomicU64::new(1),
+            echo: false,
+        }
+    }
+
+    /// 设置成员是否会收到自己发送的消息（默认不会）
+    pub fn with_echo(mut self, echo: bool) -> Self {
+        self.echo = echo;
+        self
+    }
+
+    /// 加入聊天室，只会收到加入之后发送的消息
+    pub fn join(&self) -> (Sender, Receiver) {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let sender = Sender {
+            id,
+            tx: self.tx.clone(),
+        };
+        let receiver = Receiver {
+            id,
+            rx: self.tx.subscribe(),
+            echo: self.echo,
+            missed: 0,
+        };
+        (sender, receiver)
+    }
+
+    /// 当前在线（接收端还没有被丢弃）的成员数量
+    pub fn members(&self) -> usize {
+        self.tx.receiver_count()
+    }
+}
+
+/// 成员的发送端
+#[derive(Debug, Clone)]
+pub struct Sender {
+    id: u64,
+    tx: broadcast::Sender<Message>,
+}
+
+impl Sender {
+    /// 成员编号
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// 向聊天室广播消息，返回消息送达的接收端数量（没有人在线时为0）
+    ///
+    /// 数量与发送时的[`Room::members`]相同，包括发送者自己的接收端：
+    /// 即使没有开启回显，消息也会送达自己的接收端，只是在接收时被跳过。
+    pub fn send(&self, text: impl Into<String>) -> usize {
+        let message = Message {
+            from: self.id,
+            text: text.into(),
+        };
+        // 没有接收端时广播通道返回错误，对聊天室来说只是没有人听到
+        self.tx.send(message).unwrap_or(0)
+    }
+}
+
+/// 成员的接收端
+#[derive(Debug)]
+pub struct Receiver {
+    id: u64,
+    rx: broadcast::Receiver<Message>,
+    echo: bool,
+    missed: u64,
+}
+
+impl Receiver {
+    /// 成员编号
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// 等待下一条消息；聊天室和所有发送端都被丢弃后返回`None`
+    ///
+    /// 接收太慢而错过的消息会被跳过，计入[`Receiver::missed`]。
+    pub async fn recv(&mut self) -> Option<Message> {
+        loop {
+            match self.rx.recv().await {
+                Ok(message) if self.is_own(&message) => continue,
+                Ok(message) => return Some(message),
+                Err(RecvError::Lagged(skipped)) => self.missed += skipped,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// 不等待，立即返回已经到达的下一条消息
+    pub fn try_recv(&mut self) -> Option<Message> {
+        use broadcast::error::TryRecvError;
+        loop {
+            match self.rx.try_recv() {
+                Ok(message) if self.is_own(&message) => continue,
+                Ok(message) => return Some(message),
+                Err(TryRecvError::Lagged(skipped)) => self.missed += skipped,
+                Err(TryRecvError::Empty | TryRecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// 因为接收太慢而错过的消息总数
+    pub fn missed(&self) -> u64 {
+        self.missed
+    }
+
+    fn is_own(&self, message: &Message) -> bool {
+        !self.echo && message.from == self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    async fn recv_text(receiver: &mut Receiver) -> String {
+        timeout(Duration::from_secs(1), receiver.recv())
+            .await
+            .expect("应该收到消息")
+            .expect("聊天室没有关闭")
+            .text
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_without_echo() {
+        let room = Room::new(16);
+        let (a, mut a_rx) = room.join();
+        let (b, mut b_rx) = room.join();
+        let (_c, mut c_rx) = room.join();
+        assert_eq!(room.members(), 3);
+
+        // 3个接收端都收到了广播（包括a自己的），但a会跳过自己的消息
+        assert_eq!(a.send("来自a"), 3);
+        assert_eq!(b.send("来自b"), room.members());
+
+        assert_eq!(recv_text(&mut b_rx).await, "来自a");
+        assert_eq!(recv_text(&mut c_rx).await, "来自a");
+        assert_eq!(recv_text(&mut c_rx).await, "来自b");
+        // a的第一条消息是b发的，自己的消息被跳过了
+        assert_eq!(recv_text(&mut a_rx).await, "来自b");
+        assert!(a_rx.try_recv().is_none());
+        assert!(b_rx.try_recv().is_none());
+
+        // 接收端被丢弃后就不再计入
+        drop(c_rx);
+        assert_eq!(a.send("c走了"), 2);
+    }
+
+    #[tokio::test]
+    async fn test_echo_and_late_joiner() {
+        let room = Room::new(16).with_echo(true);
+        let (a, mut a_rx) = room.join();
+        a.send("早期消息");
+        assert_eq!(recv_text(&mut a_rx).await, "早期消息");
+
+        // 后加入的成员只能收到之后的消息
+        let (_late, mut late_rx) = room.join();
+        assert!(late_rx.try_recv().is_none());
+        a.send("之后的消息");
+        assert_eq!(recv_text(&mut late_rx).await, "之后的消息");
+    }
+
+    #[tokio::test]
+    async fn test_slow_receiver_skips_missed_messages() {
+        let room = Room::new(2);
+        let (fast, _fast_rx) = room.join();
+        let (_slow, mut slow_rx) = room.join();
+
+        for i in 1..=5 {
+            fast.send(format!("消息{}", i));
+        }
+
+        // 容量只有2，最早的3条消息已经被覆盖
+        assert_eq!(recv_text(&mut slow_rx).await, "消息4");
+        assert_eq!(slow_rx.missed(), 3);
+        assert_eq!(recv_text(&mut slow_rx).await, "消息5");
+
+        // 落后之后仍然可以正常收发
+        fast.send("消息6");
+        assert_eq!(recv_text(&mut slow_rx).await, "消息6");
+    }
+
+    #[tokio::test]
+    async fn test_closed_when_everyone_leaves() {
+        let room = Room::new(4);
+        let (sender, mut receiver) = room.join();
+        drop(room);
+        drop(sender);
+        assert_eq!(receiver.recv().await, None);
+    }
+}
@@ -0,0 +1,96 @@
+//! 并发控制辅助函数
+//!
+//! 示例3和示例8多次用`stream::iter(..).map(..).buffer_unordered(n)`限制并发数，
+//! 但`buffer_unordered`按完成顺序产生结果，调用者拿到结果后还要自己重新排序。
+
+use std::future::Future;
+
+use futures::{stream, StreamExt};
+
+/// 对每个元素调用`f`，最多同时运行`limit`个future，结果按输入顺序返回
+///
+/// 内部使用`buffered`（基于`FuturesOrdered`）：先完成的结果会暂存，
+/// 直到排在它前面的结果都完成后才输出。`limit`为0时按1处理。
+///
+/// ```
+/// use std::time::Duration;
+/// use rust_tutor_async::concurrency::map_concurrent;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let doubled = map_concurrent(vec![3u64, 1, 2], 2, |n| async move {
+///     tokio::time::sleep(Duration::from_millis(n * 10)).await;
+///     n * 2
+/// })
+/// .await;
+/// assert_eq!(doubled, vec![6, 2, 4]);
+/// # }
+/// ```
+pub async fn map_concurrent<I, F, Fut, T>(items: I, limit: usize, f: F) -> Vec<T>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = T>,
+{
+    stream::iter(items)
+        .map(f)
+        .buffered(limit.max(1))
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::time::sleep;
+
+    #[tokio::test]
+    async fn test_map_concurrent_preserves_order_and_limit() {
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+
+        // 前面的元素耗时更长，按完成顺序的话结果会是倒序
+        let delays = vec![60u64, 50, 40, 30, 20, 10];
+        let results = map_concurrent(delays.clone(), 3, |delay| {
+            let running = Arc::clone(&running);
+            let max_running = Arc::clone(&max_running);
+            async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(now, Ordering::SeqCst);
+                sleep(Duration::from_millis(delay)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                delay
+            }
+        })
+        .await;
+
+        assert_eq!(results, delays);
+        assert_eq!(max_running.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_map_concurrent_runs_in_parallel() {
+        let start = tokio::time::Instant::now();
+        let results = map_concurrent(0..4, 4, |i| async move {
+            sleep(Duration::from_millis(50)).await;
+            i
+        })
+        .await;
+
+        assert_eq!(results, vec![0, 1, 2, 3]);
+        // 4个50ms的任务并发执行，总耗时是50ms而不是200ms
+        assert_eq!(start.elapsed(), Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn test_map_concurrent_zero_limit_and_empty_input() {
+        let results = map_concurrent(vec![1, 2], 0, |n| async move { n + 1 }).await;
+        assert_eq!(results, vec![2, 3]);
+
+        let empty: Vec<i32> = map_concurrent(Vec::<i32>::new(), 2, |n| async move { n }).await;
+        assert!(empty.is_empty());
+    }
+}
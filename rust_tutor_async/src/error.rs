@@ -0,0 +1,251 @@
+//! 统一的错误类型
+//!
+//! 示例7定义了自己的`CustomError`，文件操作和HTTP请求的示例又各自返回
+//! `std::io::Error`、`reqwest::Error`或`Box<dyn Error>`。[`AppError`]把这些错误合并成一个枚举，
+//! 并实现了相应的`From`，同一个函数里的文件操作和HTTP请求都可以直接用`?`传播错误。
+//!
+//! [`DetailedError`]在错误外面附加操作名称、重试次数等上下文，
+//! 原来的错误通过`source()`取得，可以沿着错误链一直找到最底层的原因。
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::time::SystemTime;
+
+/// 异步示例中会出现的各种错误
+#[derive(Debug)]
+pub enum AppError {
+    /// 网络错误（连接失败、服务不可用等）
+    Network(String),
+    /// 数据验证失败
+    Validation(String),
+    /// 操作超时
+    Timeout,
+    /// 数据库错误
+    Database(String),
+    /// HTTP请求失败
+    Http(reqwest::Error),
+    /// 文件等I/O操作失败
+    Io(io::Error),
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::Network(msg) => write!(f, "网络错误: {}", msg),
+            AppError::Validation(msg) => write!(f, "验证错误: {}", msg),
+            AppError::Timeout => write!(f, "超时错误"),
+            AppError::Database(msg) => write!(f, "数据库错误: {}", msg),
+            AppError::Http(e) => write!(f, "HTTP错误: {}", e),
+            AppError::Io(e) => write!(f, "I/O错误: {}", e),
+        }
+    }
+}
+
+impl Error for AppError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AppError::Http(e) => Some(e),
+            AppError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for AppError {
+    fn from(e: reqwest::Error) -> Self {
+        AppError::Http(e)
+    }
+}
+
+/// 所有I/O错误都归为[`AppError::Io`]，包括`ErrorKind::TimedOut`，
+/// 这样原来的`io::Error`总能通过`source()`取得
+///
+/// ```
+/// use std::error::Error;
+/// use std::io;
+/// use rust_tutor_async::error::AppError;
+///
+/// fn read_config() -> Result<String, AppError> {
+///     // `?`通过`From<io::Error>`把错误转换成`AppError`
+///     Err(io::Error::new(io::ErrorKind::TimedOut, "读取配置超时"))?
+/// }
+///
+/// let err = read_config().unwrap_err();
+/// assert!(matches!(&err, AppError::Io(e) if e.kind() == io::ErrorKind::TimedOut));
+/// assert_eq!(err.to_string(), "I/O错误: 读取配置超时");
+/// assert_eq!(err.source().unwrap().to_string(), "读取配置超时");
+/// ```
+impl From<io::Error> for AppError {
+    fn from(e: io::Error) -> Self {
+        AppError::Io(e)
+    }
+}
+
+impl From<tokio::time::error::Elapsed> for AppError {
+    fn from(_: tokio::time::error::Elapsed) -> Self {
+        AppError::Timeout
+    }
+}
+
+/// 错误发生时的上下文
+#[derive(Debug, Clone)]
+pub struct ErrorContext {
+    /// 失败的操作名称
+    pub operation: String,
+    /// 错误发生的时间
+    pub timestamp: SystemTime,
+    /// 失败之前已经重试的次数
+    pub retry_count: u32,
+}
+
+/// 带上下文的错误
+///
+/// `Display`只显示上下文，被包装的错误由`source()`返回，
+/// 这样逐层打印错误链时每条信息只出现一次。
+///
+/// ```
+/// use std::error::Error;
+/// use rust_tutor_async::error::{AppError, DetailedError};
+///
+/// let err = DetailedError::new(AppError::Database("连接超时".to_string()), "保存订单", 2);
+/// assert_eq!(err.to_string(), "操作失败: 保存订单 (重试: 2次)");
+/// assert_eq!(err.source().unwrap().to_string(), "数据库错误: 连接超时");
+/// ```
+#[derive(Debug)]
+pub struct DetailedError {
+    pub error: AppError,
+    pub context: ErrorContext,
+}
+
+impl DetailedError {
+    /// 包装错误，时间戳取当前时间
+    pub fn new(error: AppError, operation: impl Into<String>, retry_count: u32) -> Self {
+        DetailedError {
+            error,
+            context: ErrorContext {
+                operation: operation.into(),
+                timestamp: SystemTime::now(),
+                retry_count,
+            },
+        }
+    }
+}
+
+impl fmt::Display for DetailedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "操作失败: {} (重试: {}次)",
+            self.context.operation, self.context.retry_count
+        )
+    }
+}
+
+impl Error for DetailedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_display() {
+        let cases = [
+            (
+                AppError::Network("连接失败".to_string()),
+                "网络错误: 连接失败",
+            ),
+            (
+                AppError::Validation("年龄为负数".to_string()),
+                "验证错误: 年龄为负数",
+            ),
+            (AppError::Timeout, "超时错误"),
+            (
+                AppError::Database("连接超时".to_string()),
+                "数据库错误: 连接超时",
+            ),
+            (
+                AppError::Io(io::Error::new(io::ErrorKind::NotFound, "找不到文件")),
+                "I/O错误: 找不到文件",
+            ),
+        ];
+        for (error, expected) in cases {
+            assert_eq!(error.to_string(), expected);
+            if !matches!(error, AppError::Io(_)) {
+                assert!(error.source().is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_io_error() {
+        fn open() -> Result<(), AppError> {
+            Err(io::Error::new(io::ErrorKind::PermissionDenied, "没有权限"))?
+        }
+        let err = open().unwrap_err();
+        assert!(matches!(&err, AppError::Io(e) if e.kind() == io::ErrorKind::PermissionDenied));
+        assert_eq!(err.source().unwrap().to_string(), "没有权限");
+
+        // 超时的I/O错误也保留原来的错误作为source
+        let timed_out: AppError = io::Error::new(io::ErrorKind::TimedOut, "太慢").into();
+        assert!(matches!(&timed_out, AppError::Io(e) if e.kind() == io::ErrorKind::TimedOut));
+        assert_eq!(timed_out.source().unwrap().to_string(), "太慢");
+    }
+
+    #[test]
+    fn test_from_reqwest_error() {
+        // 无效的URL在构建请求时就会失败，不需要真正访问网络
+        fn build() -> Result<reqwest::Request, AppError> {
+            Ok(reqwest::Client::new().get("不是URL").build()?)
+        }
+        let err = build().unwrap_err();
+        assert!(matches!(err, AppError::Http(_)));
+        assert!(err.to_string().starts_with("HTTP错误: "));
+        assert!(err.source().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_from_elapsed() {
+        async fn slow() -> Result<(), AppError> {
+            tokio::time::timeout(Duration::from_millis(10), std::future::pending::<()>()).await?;
+            Ok(())
+        }
+        assert!(matches!(slow().await, Err(AppError::Timeout)));
+    }
+
+    #[test]
+    fn test_detailed_error_source_chain() {
+        let io_error = io::Error::new(io::ErrorKind::NotFound, "orders.json不存在");
+        let err = DetailedError::new(AppError::from(io_error), "加载订单", 3);
+        assert_eq!(err.context.operation, "加载订单");
+        assert_eq!(err.context.retry_count, 3);
+        assert!(err.context.timestamp <= SystemTime::now());
+
+        // 沿着source()走完整条错误链：DetailedError -> AppError::Io -> io::Error
+        let mut chain = vec![err.to_string()];
+        let mut current: &dyn Error = &err;
+        while let Some(source) = current.source() {
+            chain.push(source.to_string());
+            current = source;
+        }
+        assert_eq!(
+            chain,
+            [
+                "操作失败: 加载订单 (重试: 3次)",
+                "I/O错误: orders.json不存在",
+                "orders.json不存在",
+            ]
+        );
+        let root = current.downcast_ref::<io::Error>().unwrap();
+        assert_eq!(root.kind(), io::ErrorKind::NotFound);
+
+        // 没有底层原因的错误，链只有两层
+        let err = DetailedError::new(AppError::Timeout, "查询库存", 0);
+        assert!(err.source().unwrap().source().is_none());
+    }
+}
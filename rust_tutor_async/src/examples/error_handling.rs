@@ -0,0 +1,437 @@
+//! 示例7: 异步错误处理
+//!
+//! 这个示例展示在异步代码中处理错误的各种模式和最佳实践
+
+use std::time::Duration;
+use tokio::time::sleep;
+use std::error::Error;
+use crate::resilience::{CircuitBreaker, CircuitError};
+use crate::util::retry_with_backoff;
+use crate::error::{AppError, DetailedError};
+
+// 自定义错误类型（AppError定义在库中，error模块）：
+// Network、Validation、Timeout、Database，以及包装reqwest::Error和std::io::Error的Http、Io
+
+// 基本的错误处理
+async fn basic_error_handling() {
+    println!("=== 基本错误处理 ===\n");
+    
+    // 可能失败的异步函数
+    async fn risky_operation(should_fail: bool) -> Result<String, AppError> {
+        println!("执行风险操作...");
+        sleep(Duration::from_millis(100)).await;
+        
+        if should_fail {
+            Err(AppError::Network("连接失败".to_string()))
+        } else {
+            Ok("操作成功".to_string())
+        }
+    }
+    
+    // 处理成功的情况
+    println!("1. 处理成功的操作:");
+    match risky_operation(false).await {
+        Ok(result) => println!("成功: {}", result),
+        Err(e) => println!("失败: {}", e),
+    }
+    
+    // 处理失败的情况
+    println!("\n2. 处理失败的操作:");
+    match risky_operation(true).await {
+        Ok(result) => println!("成功: {}", result),
+        Err(e) => println!("失败: {}", e),
+    }
+    
+    println!();
+}
+
+// 使用?操作符进行错误传播
+async fn error_propagation() -> Result<(), AppError> {
+    println!("=== 错误传播 ===\n");
+    
+    async fn step1() -> Result<String, AppError> {
+        println!("执行步骤1");
+        sleep(Duration::from_millis(50)).await;
+        Ok("步骤1完成".to_string())
+    }
+    
+    async fn step2() -> Result<String, AppError> {
+        println!("执行步骤2");
+        sleep(Duration::from_millis(50)).await;
+        Err(AppError::Validation("数据验证失败".to_string()))
+    }
+    
+    async fn step3() -> Result<String, AppError> {
+        println!("执行步骤3");
+        sleep(Duration::from_millis(50)).await;
+        Ok("步骤3完成".to_string())
+    }
+    
+    // 使用?操作符，任何步骤失败都会立即返回错误
+    let result1 = step1().await?;
+    println!("步骤1结果: {}", result1);
+    
+    let result2 = step2().await?; // 这里会失败并返回错误
+    println!("步骤2结果: {}", result2);
+    
+    let result3 = step3().await?; // 这行不会执行
+    println!("步骤3结果: {}", result3);
+    
+    Ok(())
+}
+
+// 并发操作的错误处理
+async fn concurrent_error_handling() {
+    println!("=== 并发操作错误处理 ===\n");
+    
+    async fn task(id: u32, should_fail: bool) -> Result<String, AppError> {
+        println!("任务{}开始", id);
+        sleep(Duration::from_millis(100 * id as u64)).await;
+        
+        if should_fail {
+            Err(AppError::Network(format!("任务{}失败", id)))
+        } else {
+            Ok(format!("任务{}成功", id))
+        }
+    }
+    
+    // 使用join_all处理多个可能失败的任务
+    println!("1. 使用join_all处理并发任务:");
+    let tasks = vec![
+        task(1, false),
+        task(2, true),  // 这个会失败
+        task(3, false),
+    ];
+    
+    let results = futures::future::join_all(tasks).await;
+    
+    for (i, result) in results.into_iter().enumerate() {
+        match result {
+            Ok(value) => println!("  任务{}: 成功 - {}", i + 1, value),
+            Err(e) => println!("  任务{}: 失败 - {}", i + 1, e),
+        }
+    }
+    
+    // 使用try_join!，任何一个失败就全部失败
+    println!("\n2. 使用try_join!，一个失败全部失败:");
+    match tokio::try_join!(
+        task(4, false),
+        task(5, true),  // 这个会失败
+        task(6, false)
+    ) {
+        Ok((r1, r2, r3)) => {
+            println!("  全部成功: {}, {}, {}", r1, r2, r3);
+        }
+        Err(e) => {
+            println!("  有任务失败: {}", e);
+        }
+    }
+    
+    println!();
+}
+
+// 超时错误处理
+async fn timeout_error_handling() {
+    println!("=== 超时错误处理 ===\n");
+    
+    async fn slow_operation(delay_ms: u64) -> Result<String, AppError> {
+        println!("开始慢操作 ({}ms)", delay_ms);
+        sleep(Duration::from_millis(delay_ms)).await;
+        Ok(format!("慢操作完成 ({}ms)", delay_ms))
+    }
+    
+    // 使用tokio::time::timeout处理超时
+    println!("1. 正常完成的操作:");
+    match tokio::time::timeout(Duration::from_millis(500), slow_operation(200)).await {
+        Ok(Ok(result)) => println!("  成功: {}", result),
+        Ok(Err(e)) => println!("  操作失败: {}", e),
+        Err(_) => println!("  超时"),
+    }
+    
+    println!("\n2. 超时的操作:");
+    match tokio::time::timeout(Duration::from_millis(300), slow_operation(500)).await {
+        Ok(Ok(result)) => println!("  成功: {}", result),
+        Ok(Err(e)) => println!("  操作失败: {}", e),
+        Err(_) => println!("  超时"),
+    }
+    
+    println!();
+}
+
+// 重试机制
+async fn retry_mechanism() {
+    println!("=== 重试机制 ===\n");
+    
+    async fn unreliable_operation(attempt: u32) -> Result<String, AppError> {
+        println!("  尝试第{}次", attempt);
+        sleep(Duration::from_millis(100)).await;
+        
+        // 前两次尝试失败，第三次成功
+        if attempt < 3 {
+            Err(AppError::Network(format!("第{}次尝试失败", attempt)))
+        } else {
+            Ok("操作最终成功".to_string())
+        }
+    }
+    
+    println!("开始重试操作 (最多3次):");
+    // 最多尝试3次，每次失败后等待时间翻倍，并加上最多50ms的随机抖动
+    match retry_with_backoff(
+        |attempt| async move {
+            let result = unreliable_operation(attempt).await;
+            if let Err(e) = &result {
+                println!("  第{}次尝试失败: {}", attempt, e);
+            }
+            result
+        },
+        3,
+        Duration::from_millis(100),
+        Some(Duration::from_millis(50)),
+    ).await {
+        Ok(result) => println!("最终成功: {}", result),
+        Err(e) => println!("最终失败: {}", e),
+    }
+    
+    println!();
+}
+
+// 错误恢复和降级
+async fn error_recovery_and_fallback() {
+    println!("=== 错误恢复和降级 ===\n");
+    
+    async fn primary_service() -> Result<String, AppError> {
+        println!("尝试主服务");
+        sleep(Duration::from_millis(100)).await;
+        Err(AppError::Network("主服务不可用".to_string()))
+    }
+    
+    async fn backup_service() -> Result<String, AppError> {
+        println!("尝试备用服务");
+        sleep(Duration::from_millis(150)).await;
+        Ok("备用服务响应".to_string())
+    }
+    
+    async fn cache_service() -> Result<String, AppError> {
+        println!("尝试缓存服务");
+        sleep(Duration::from_millis(50)).await;
+        Ok("缓存数据".to_string())
+    }
+    
+    // 实现降级策略
+    async fn get_data_with_fallback() -> Result<String, AppError> {
+        // 首先尝试主服务
+        match primary_service().await {
+            Ok(data) => return Ok(data),
+            Err(e) => println!("主服务失败: {}", e),
+        }
+        
+        // 主服务失败，尝试备用服务
+        match backup_service().await {
+            Ok(data) => return Ok(data),
+            Err(e) => println!("备用服务失败: {}", e),
+        }
+        
+        // 备用服务也失败，使用缓存
+        match cache_service().await {
+            Ok(data) => {
+                println!("使用缓存数据");
+                Ok(data)
+            }
+            Err(e) => {
+                println!("缓存服务也失败: {}", e);
+                Err(AppError::Network("所有服务都不可用".to_string()))
+            }
+        }
+    }
+    
+    match get_data_with_fallback().await {
+        Ok(data) => println!("获取数据成功: {}", data),
+        Err(e) => println!("获取数据失败: {}", e),
+    }
+    
+    println!();
+}
+
+// 熔断器
+async fn circuit_breaker_usage() {
+    println!("=== 熔断器 ===\n");
+    
+    async fn unstable_service(call: u32) -> Result<String, AppError> {
+        sleep(Duration::from_millis(50)).await;
+        // 前3次调用失败，之后服务恢复
+        if call <= 3 {
+            Err(AppError::Network(format!("第{}次调用失败", call)))
+        } else {
+            Ok(format!("第{}次调用成功", call))
+        }
+    }
+    
+    // 连续失败3次后熔断，熔断300ms后放行一次试探请求
+    let breaker = CircuitBreaker::new(3, Duration::from_millis(300));
+    let mut calls = 0;
+    
+    for request in 1..=8 {
+        let result = breaker.call(|| {
+            calls += 1;
+            unstable_service(calls)
+        }).await;
+        
+        match result {
+            Ok(data) => println!("  请求{}: 成功 - {}", request, data),
+            Err(CircuitError::Open) => println!("  请求{}: 熔断中，直接拒绝", request),
+            Err(CircuitError::Inner(e)) => println!("  请求{}: 失败 - {}", request, e),
+        }
+        println!("    熔断器状态: {:?}", breaker.state());
+        
+        if request == 5 {
+            println!("  等待冷却...");
+            sleep(Duration::from_millis(350)).await;
+        }
+    }
+    
+    println!();
+}
+
+// 结构化错误处理
+async fn structured_error_handling() {
+    println!("=== 结构化错误处理 ===\n");
+    
+    // ErrorContext和DetailedError定义在库中（error模块）：
+    // DetailedError的Display只显示上下文，被包装的AppError通过source()取得
+    
+    async fn complex_operation() -> Result<String, DetailedError> {
+        sleep(Duration::from_millis(100)).await;
+        
+        // 模拟失败
+        Err(DetailedError::new(
+            AppError::Database("连接超时".to_string()),
+            "复杂业务操作",
+            1,
+        ))
+    }
+    
+    match complex_operation().await {
+        Ok(result) => println!("操作成功: {}", result),
+        Err(e) => {
+            println!("{}", e);
+            // 沿着source()逐层打印错误链
+            let mut source = e.source();
+            while let Some(cause) = source {
+                println!("  原因: {}", cause);
+                source = cause.source();
+            }
+            println!("错误详情: {:?}", e);
+        }
+    }
+    
+    println!();
+}
+
+// 异步闭包中的错误处理
+async fn async_closure_error_handling() {
+    println!("=== 异步闭包错误处理 ===\n");
+    
+    let numbers = vec![1, 2, 3, 4, 5];
+    
+    // 使用map和collect处理可能失败的异步操作
+    async fn process_number(n: i32) -> Result<i32, AppError> {
+        sleep(Duration::from_millis(50)).await;
+        
+        if n == 3 {
+            Err(AppError::Validation(format!("数字{}无效", n)))
+        } else {
+            Ok(n * 2)
+        }
+    }
+    
+    println!("处理数字列表:");
+    let tasks: Vec<_> = numbers.into_iter()
+        .map(|n| async move {
+            println!("  处理数字: {}", n);
+            process_number(n).await
+        })
+        .collect();
+    
+    let results = futures::future::join_all(tasks).await;
+    
+    for (i, result) in results.into_iter().enumerate() {
+        match result {
+            Ok(value) => println!("  结果{}: {}", i + 1, value),
+            Err(e) => println!("  错误{}: {}", i + 1, e),
+        }
+    }
+    
+    println!();
+}
+
+/// 示例的全部内容，可执行程序`example_07_error_handling`和统一的示例运行器（`src/main.rs`）都通过它运行这个示例
+pub async fn run() {
+    println!("=== Rust 异步编程示例7: 错误处理 ===\n");
+    
+    // 1. 基本错误处理
+    basic_error_handling().await;
+    
+    // 2. 错误传播
+    if let Err(e) = error_propagation().await {
+        println!("错误传播示例失败: {}\n", e);
+    }
+    
+    // 3. 并发操作错误处理
+    concurrent_error_handling().await;
+    
+    // 4. 超时错误处理
+    timeout_error_handling().await;
+    
+    // 5. 重试机制
+    retry_mechanism().await;
+    
+    // 6. 错误恢复和降级
+    error_recovery_and_fallback().await;
+    
+    // 7. 熔断器
+    circuit_breaker_usage().await;
+    
+    // 8. 结构化错误处理
+    structured_error_handling().await;
+    
+    // 9. 异步闭包错误处理
+    async_closure_error_handling().await;
+    
+    println!("=== 示例完成 ===");
+}
+
+/*
+运行这个示例：
+cargo run --bin example_07_error_handling
+
+关键学习点：
+1. 自定义错误类型和Error trait实现
+2. 使用?操作符进行错误传播
+3. 并发操作中的错误处理策略
+4. 超时处理避免无限等待
+5. 重试机制和指数退避
+6. 错误恢复和服务降级
+7. 熔断器避免反复调用不可用的服务
+8. 结构化错误信息
+9. 异步闭包中的错误处理
+
+错误处理模式：
+- Result<T, E>: 标准的错误处理类型
+- match表达式: 显式处理成功和失败情况
+- ?操作符: 简化错误传播
+- try_join!: 任一失败则全部失败
+- join_all: 收集所有结果，包括错误
+
+最佳实践：
+- 定义清晰的错误类型
+- 提供有用的错误信息
+- 实现适当的重试策略
+- 考虑降级和恢复机制
+- 记录错误上下文信息
+- 避免忽略错误
+
+性能考虑：
+- 错误处理不应该成为性能瓶颈
+- 合理使用重试，避免雪崩效应
+- 超时设置要平衡响应性和成功率
+*/ 
\ No newline at end of file
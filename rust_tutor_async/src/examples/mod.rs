@@ -0,0 +1,106 @@
+//! 所有异步示例的正文和统一入口
+//!
+//! 每个示例的内容都在这里的一个子模块中，通过`run()`运行。
+//! `Cargo.toml`中的`[[bin]]`可执行程序（`src/examples/NN_*.rs`）只是调用对应的`run()`，
+//! 菜单程序（`src/main.rs`）则通过[`run_by_name`]按名字调用。
+
+pub mod advanced_patterns;
+pub mod async_await;
+pub mod basic_async;
+pub mod channels;
+pub mod concurrent_tasks;
+pub mod error_handling;
+pub mod file_operations;
+pub mod http_client;
+pub mod select_macro;
+pub mod streams;
+
+/// 所有示例的名字和标题，按编号顺序排列
+pub const EXAMPLES: [(&str, &str); 10] = [
+    ("basic_async", "基础异步函数"),
+    ("async_await", "深入理解async/await"),
+    ("concurrent_tasks", "并发任务"),
+    ("channels", "通道通信"),
+    ("http_client", "HTTP客户端"),
+    ("file_operations", "文件操作"),
+    ("error_handling", "错误处理"),
+    ("streams", "流处理"),
+    ("select_macro", "select!宏"),
+    ("advanced_patterns", "高级模式"),
+];
+
+/// 按名字运行示例，名字不存在时返回`None`
+///
+/// 示例内部的错误（例如网络请求失败）只会打印出来，不影响返回值。
+pub async fn run_by_name(name: &str) -> Option<()> {
+    let result = match name {
+        "basic_async" => {
+            basic_async::run().await;
+            Ok(())
+        }
+        "async_await" => {
+            async_await::run().await;
+            Ok(())
+        }
+        "concurrent_tasks" => {
+            concurrent_tasks::run().await;
+            Ok(())
+        }
+        "channels" => {
+            channels::run().await;
+            Ok(())
+        }
+        "http_client" => http_client::run().await,
+        "file_operations" => file_operations::run().await,
+        "error_handling" => {
+            error_handling::run().await;
+            Ok(())
+        }
+        "streams" => {
+            streams::run().await;
+            Ok(())
+        }
+        "select_macro" => {
+            select_macro::run().await;
+            Ok(())
+        }
+        "advanced_patterns" => {
+            advanced_patterns::run().await;
+            Ok(())
+        }
+        _ => return None,
+    };
+    if let Err(e) = result {
+        println!("❌ 示例{}运行出错: {}", name, e);
+    }
+    Some(())
+}
+
+/// 按编号（从1开始）查找示例的名字
+pub fn name_by_number(number: usize) -> Option<&'static str> {
+    let index = number.checked_sub(1)?;
+    EXAMPLES.get(index).map(|(name, _)| *name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_basic_async_to_completion() {
+        assert_eq!(run_by_name("basic_async").await, Some(()));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_name() {
+        assert_eq!(run_by_name("no_such_example").await, None);
+    }
+
+    #[test]
+    fn test_name_by_number() {
+        assert_eq!(name_by_number(1), Some("basic_async"));
+        assert_eq!(name_by_number(10), Some("advanced_patterns"));
+        assert_eq!(name_by_number(0), None);
+        assert_eq!(name_by_number(11), None);
+    }
+}
@@ -0,0 +1,467 @@
+//! 示例8: 异步流处理
+//!
+//! 这个示例展示如何使用Streams处理异步数据流
+
+use std::time::Duration;
+use tokio::time::sleep;
+use futures::{Stream, StreamExt, TryStreamExt, stream};
+use tokio::sync::mpsc;
+use crate::concurrency::map_concurrent;
+use crate::sim::{sensor_stream, Anomaly, SensorConfig};
+use crate::stream::{batch_timeout, debounce, dedup, merge_all, throttle, timeout_each, windows, CounterStream};
+
+// 基本的流操作
+async fn basic_stream_operations() {
+    println!("=== 基本流操作 ===\n");
+    
+    // 从向量创建流
+    println!("1. 从向量创建流:");
+    let numbers = vec![1, 2, 3, 4, 5];
+    let mut stream = stream::iter(numbers);
+    
+    while let Some(number) = stream.next().await {
+        println!("  处理数字: {}", number);
+    }
+    
+    // 使用map转换流中的元素
+    println!("\n2. 使用map转换流:");
+    let numbers = vec![1, 2, 3, 4, 5];
+    let mut doubled_stream = stream::iter(numbers)
+        .map(|x| x * 2);
+    
+    while let Some(doubled) = doubled_stream.next().await {
+        println!("  双倍数字: {}", doubled);
+    }
+    
+    // 使用filter过滤流
+    println!("\n3. 使用filter过滤流:");
+    let numbers = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    let mut even_stream = Box::pin(stream::iter(numbers)
+        .filter(|&x| async move { x % 2 == 0 }));
+    
+    while let Some(even) = even_stream.next().await {
+        println!("  偶数: {}", even);
+    }
+    
+    println!();
+}
+
+// 异步流生成器
+async fn async_stream_generators() {
+    println!("=== 异步流生成器 ===\n");
+    
+    // 创建一个定时器流
+    fn timer_stream(count: u32, interval_ms: u64) -> impl Stream<Item = u32> {
+        stream::unfold((0, count, interval_ms), |(current, max, delay)| async move {
+            if current >= max {
+                None
+            } else {
+                sleep(Duration::from_millis(delay)).await;
+                Some((current, (current + 1, max, delay)))
+            }
+        })
+    }
+    
+    println!("1. 定时器流 (每500ms产生一个数字):");
+    let mut timer = Box::pin(timer_stream(5, 500));
+    while let Some(tick) = timer.next().await {
+        println!("  定时器tick: {}", tick);
+    }
+    
+    // 创建一个异步数据生成流
+    fn data_generator_stream() -> impl Stream<Item = String> {
+        stream::unfold(0, |state| async move {
+            if state >= 3 {
+                None
+            } else {
+                // 模拟异步数据获取
+                sleep(Duration::from_millis(200)).await;
+                let data = format!("数据项_{}", state);
+                Some((data, state + 1))
+            }
+        })
+    }
+    
+    println!("\n2. 异步数据生成流:");
+    let mut data_stream = Box::pin(data_generator_stream());
+    while let Some(data) = data_stream.next().await {
+        println!("  生成的数据: {}", data);
+    }
+    
+    println!();
+}
+
+// 自定义流实现
+async fn custom_stream_implementation() {
+    println!("=== 自定义流实现 ===\n");
+    
+    // 使用库中的CounterStream：它在内部保存一个Sleep计时器，
+    // 计时未到时poll_next返回Poll::Pending，到期后才产生下一个值
+    println!("自定义计数器流:");
+    let mut counter = CounterStream::new(5, Duration::from_millis(100));
+    while let Some(count) = counter.next().await {
+        // 不需要再手动sleep，流本身就会在每个值之间等待
+        println!("  计数: {}", count);
+    }
+    
+    println!();
+}
+
+// 流的组合和链式操作
+async fn stream_composition_and_chaining() {
+    println!("=== 流的组合和链式操作 ===\n");
+    
+    // 复杂的流处理管道
+    println!("1. 复杂的流处理管道:");
+    let numbers = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    
+    let result: Vec<String> = stream::iter(numbers)
+        .filter(|&x| async move { x % 2 == 0 })  // 过滤偶数
+        .map(|x| async move {                     // 异步转换
+            sleep(Duration::from_millis(50)).await;
+            x * x
+        })
+        .buffer_unordered(3)                      // 并发处理，最多3个
+        .filter(|&x| async move { x > 10 })       // 过滤大于10的数
+        .map(|x| format!("结果: {}", x))          // 转换为字符串
+        .collect()                                // 收集所有结果
+        .await;
+    
+    for item in result {
+        println!("  {}", item);
+    }
+    
+    // 流的合并
+    println!("\n2. 流的合并:");
+    let stream1 = stream::iter(vec!["A", "B", "C"]);
+    let stream2 = stream::iter(vec!["1", "2", "3"]);
+    
+    let mut merged = stream1.chain(stream2);
+    while let Some(item) = merged.next().await {
+        println!("  合并的项: {}", item);
+    }
+    
+    // chain是顺序连接，merge_all则并发推进多个流，谁先就绪先产生谁
+    println!("  并发合并 (merge_all):");
+    let ticks = |name: &'static str, interval: u64, count: usize| {
+        stream::iter(1..=count).then(move |i| async move {
+            sleep(Duration::from_millis(interval)).await;
+            format!("{}{}", name, i)
+        }).boxed()
+    };
+    let mut interleaved = merge_all(vec![ticks("快", 50, 4), ticks("慢", 120, 2)]);
+    while let Some(item) = interleaved.next().await {
+        println!("  合并的项: {}", item);
+    }
+    
+    // 滑动窗口：每个新元素到来时产生最近3个元素组成的窗口
+    println!("\n3. 滑动窗口 (计算移动平均):");
+    let mut windowed = Box::pin(windows(stream::iter(vec![10, 20, 30, 40, 50]), 3));
+    while let Some(window) = windowed.next().await {
+        let avg = window.iter().sum::<i32>() as f64 / window.len() as f64;
+        println!("  窗口 {:?} 平均值: {:.1}", window, avg);
+    }
+    
+    // 按数量或时间分批：最多4个一批，第一个元素等待超过150ms也会提交
+    println!("\n4. 按数量/超时分批:");
+    let bursty = stream::iter(vec![(0u64, 1), (10, 2), (10, 3), (10, 4), (10, 5), (300, 6), (10, 7)])
+        .then(|(delay, item)| async move {
+            sleep(Duration::from_millis(delay)).await;
+            item
+        });
+    let mut batches = batch_timeout(bursty, 4, Duration::from_millis(150));
+    while let Some(batch) = batches.next().await {
+        println!("  提交批次: {:?}", batch);
+    }
+    
+    // 传感器读数经常连续重复，只在数值变化时才继续处理
+    println!("\n5. 去除连续重复:");
+    let readings = stream::iter(vec![20, 20, 21, 21, 21, 20, 22]);
+    let changes: Vec<i32> = dedup(readings).collect().await;
+    println!("  数值变化: {:?}", changes);
+    
+    // 输入框每敲一个字符产生一个事件，只在停顿之后才用最终的内容去搜索
+    println!("\n6. 防抖（debounce定义在库中，stream模块）:");
+    let keystrokes = stream::iter(vec![(0u64, "r"), (30, "ru"), (30, "rus"), (30, "rust"), (300, "rust async")])
+        .then(|(delay, text)| async move {
+            sleep(Duration::from_millis(delay)).await;
+            text
+        });
+    let mut searches = debounce(keystrokes, Duration::from_millis(100));
+    while let Some(query) = searches.next().await {
+        println!("  搜索: {}", query);
+    }
+    
+    // 防抖会丢弃元素；节流保留所有元素，只是把太快的元素推迟到间隔满足之后
+    println!("\n7. 节流（throttle定义在库中，stream模块）:");
+    let start = std::time::Instant::now();
+    let mut requests = throttle(stream::iter(1..=4), Duration::from_millis(100));
+    while let Some(request) = requests.next().await {
+        println!("  {:>4}ms 发送请求{}", start.elapsed().as_millis(), request);
+    }
+    
+    println!();
+}
+
+// 并发流处理
+async fn concurrent_stream_processing() {
+    println!("=== 并发流处理 ===\n");
+    
+    // 模拟异步处理函数
+    async fn process_item(item: i32) -> String {
+        let delay = (item % 3 + 1) * 100; // 不同的延迟
+        sleep(Duration::from_millis(delay as u64)).await;
+        format!("处理完成: {}", item)
+    }
+    
+    println!("1. 顺序处理 vs 并发处理:");
+    
+    // 顺序处理
+    let start = std::time::Instant::now();
+    let items = vec![1, 2, 3, 4, 5];
+    
+    println!("  顺序处理:");
+    let mut sequential_results = Vec::new();
+    for item in items.clone() {
+        let result = process_item(item).await;
+        sequential_results.push(result);
+        println!("    {}", sequential_results.last().unwrap());
+    }
+    let sequential_time = start.elapsed();
+    
+    // 并发处理
+    let start = std::time::Instant::now();
+    println!("  并发处理:");
+    
+    let concurrent_results: Vec<String> = stream::iter(items)
+        .map(process_item)
+        .buffer_unordered(3)  // 最多3个并发任务
+        .collect()
+        .await;
+    
+    for result in concurrent_results {
+        println!("    {}", result);
+    }
+    let concurrent_time = start.elapsed();
+    
+    println!("  顺序处理耗时: {:?}", sequential_time);
+    println!("  并发处理耗时: {:?}", concurrent_time);
+    println!("  性能提升: {:.2}x", 
+             sequential_time.as_millis() as f64 / concurrent_time.as_millis() as f64);
+    
+    // buffer_unordered按完成顺序返回；map_concurrent同样限制并发数，但保持输入顺序
+    println!("\n2. 限制并发数并保持顺序 (map_concurrent):");
+    let ordered_results = map_concurrent(vec![1, 2, 3, 4, 5], 3, process_item).await;
+    for result in ordered_results {
+        println!("    {}", result);
+    }
+    
+    println!();
+}
+
+// 流与通道的结合
+async fn streams_with_channels() {
+    println!("=== 流与通道的结合 ===\n");
+    
+    let (tx, rx) = mpsc::channel::<i32>(10);
+    
+    // 生产者任务
+    let producer = tokio::spawn(async move {
+        for i in 1..=10 {
+            println!("  发送: {}", i);
+            if let Err(e) = tx.send(i).await {
+                println!("  发送失败: {}", e);
+                break;
+            }
+            sleep(Duration::from_millis(100)).await;
+        }
+        println!("  生产者完成");
+    });
+    
+    // 将接收器转换为流并处理
+    let stream_processor = tokio::spawn(async move {
+        let mut stream = tokio_stream::wrappers::ReceiverStream::new(rx);
+        
+        while let Some(value) = stream.next().await {
+            println!("  从流接收: {}", value);
+            
+            // 模拟处理时间
+            sleep(Duration::from_millis(50)).await;
+            
+            println!("  处理完成: {}", value * 2);
+        }
+        println!("  流处理器完成");
+    });
+    
+    // 等待两个任务完成
+    let _ = tokio::join!(producer, stream_processor);
+    
+    println!();
+}
+
+// 流的错误处理
+async fn stream_error_handling() {
+    println!("=== 流的错误处理 ===\n");
+    
+    // 创建一个可能产生错误的流
+    fn fallible_stream() -> impl Stream<Item = Result<i32, String>> {
+        stream::iter(vec![
+            Ok(1),
+            Ok(2),
+            Err("处理错误".to_string()),
+            Ok(4),
+            Ok(5),
+        ])
+    }
+    
+    println!("1. 处理流中的错误:");
+    let mut error_stream = fallible_stream();
+    
+    while let Some(result) = error_stream.next().await {
+        match result {
+            Ok(value) => println!("  成功: {}", value),
+            Err(e) => println!("  错误: {}", e),
+        }
+    }
+    
+    // 使用try_collect收集成功的结果
+    println!("\n2. 使用try_collect (遇到错误会停止):");
+    let success_stream = stream::iter(vec![
+        Ok::<i32, String>(1),
+        Ok::<i32, String>(2),
+        Ok::<i32, String>(3),
+    ]);
+    
+    match success_stream.try_collect::<Vec<i32>>().await {
+        Ok(results) => println!("  收集的结果: {:?}", results),
+        Err(e) => println!("  收集失败: {}", e),
+    }
+    
+    // 上游某个元素太慢时不要一直卡住：timeout_each定义在库中（stream模块）
+    println!("\n3. 为每个元素设置超时:");
+    let responses = stream::iter(vec![(50u64, "响应1"), (400, "响应2"), (50, "响应3")])
+        .then(|(delay, item)| async move {
+            sleep(Duration::from_millis(delay)).await;
+            item
+        });
+    let mut guarded = timeout_each(responses, Duration::from_millis(150));
+    while let Some(result) = guarded.next().await {
+        match result {
+            Ok(response) => println!("  收到: {}", response),
+            Err(_) => println!("  ⏰ 150ms内没有新元素，继续等待"),
+        }
+    }
+    
+    println!();
+}
+
+// 实时数据流模拟
+async fn real_time_data_stream() {
+    println!("=== 实时数据流模拟 ===\n");
+    
+    // sensor_stream定义在库中（sim模块）：
+    // 读数带有rand产生的随机抖动，报警阈值放在配置中
+    let config = SensorConfig::default();
+    println!("模拟传感器数据流 (每{}ms一个读数):", config.interval.as_millis());
+    let mut sensor_stream = Box::pin(sensor_stream(config.clone()));
+    
+    // 处理传感器数据
+    let mut readings = Vec::new();
+    while let Some((timestamp, reading)) = sensor_stream.next().await {
+        readings.push(reading);
+        println!("  [{}] 传感器读数: {:.2}°C", timestamp.format("%H:%M:%S%.3f"), reading);
+        
+        // 异常检测
+        match config.check(reading) {
+            Some(Anomaly::High) => println!("    ⚠️  高温警告!"),
+            Some(Anomaly::Low) => println!("    ❄️  低温警告!"),
+            None => {}
+        }
+    }
+    
+    // 计算统计信息
+    let avg = readings.iter().sum::<f64>() / readings.len() as f64;
+    let max = readings.iter().fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+    let min = readings.iter().fold(f64::INFINITY, |a, &b| a.min(b));
+    
+    println!("\n传感器数据统计:");
+    println!("  平均值: {:.2}°C", avg);
+    println!("  最高值: {:.2}°C", max);
+    println!("  最低值: {:.2}°C", min);
+    
+    println!();
+}
+
+/// 示例的全部内容，可执行程序`example_08_streams`和统一的示例运行器（`src/main.rs`）都通过它运行这个示例
+pub async fn run() {
+    println!("=== Rust 异步编程示例8: 流处理 ===\n");
+    
+    // 1. 基本流操作
+    basic_stream_operations().await;
+    
+    // 2. 异步流生成器
+    async_stream_generators().await;
+    
+    // 3. 自定义流实现
+    custom_stream_implementation().await;
+    
+    // 4. 流的组合和链式操作
+    stream_composition_and_chaining().await;
+    
+    // 5. 并发流处理
+    concurrent_stream_processing().await;
+    
+    // 6. 流与通道的结合
+    streams_with_channels().await;
+    
+    // 7. 流的错误处理
+    stream_error_handling().await;
+    
+    // 8. 实时数据流模拟
+    real_time_data_stream().await;
+    
+    println!("=== 示例完成 ===");
+}
+
+/*
+运行这个示例：
+cargo run --bin example_08_streams
+
+关键学习点：
+1. Stream trait - 异步迭代器的抽象
+2. 流的创建方法 - iter, unfold, 自定义实现
+3. 流的转换操作 - map, filter, chain, 滑动窗口, 分批, 去重
+4. 并发流处理 - buffer_unordered提高性能
+5. 流与通道的结合使用
+6. 流中的错误处理模式
+7. 实时数据流的处理
+
+流操作方法：
+- next(): 获取下一个元素
+- map(): 转换流中的元素
+- filter(): 过滤流中的元素
+- collect(): 收集所有元素到集合
+- buffer_unordered(): 并发处理多个元素
+- chain(): 连接多个流
+- merge_all(): 并发合并多个流
+- try_collect(): 收集Result流的成功元素
+
+性能优化：
+- 使用buffer_unordered进行并发处理
+- 合理设置并发度避免资源耗尽
+- 流式处理避免内存积累
+- 惰性求值减少不必要的计算
+
+应用场景：
+- 实时数据处理
+- 网络数据流
+- 文件流处理
+- 事件流处理
+- 传感器数据采集
+
+注意事项：
+- 流是惰性的，只有在被消费时才会执行
+- 注意流的生命周期和所有权
+- 合理处理流中的错误
+- 考虑背压(backpressure)问题
+*/ 
\ No newline at end of file
@@ -0,0 +1,79 @@
+//! 标准类型的扩展特征
+//!
+//! 示例5、示例6的`run`里反复出现`if let Err(e) = ... { println!("...失败: {}", e); }`，
+//! 只是为了打印错误然后继续执行下一步。[`ResultExt`]把这种写法变成一次方法调用。
+
+use std::fmt::Display;
+
+/// 为`Result`添加打印错误的方法
+///
+/// 错误输出到标准错误（stderr），不影响`Result`本身。
+///
+/// ```
+/// use rust_tutor_async::ext::ResultExt;
+///
+/// let port: u16 = "8080".parse::<u16>().log_err("解析端口失败").unwrap();
+/// assert_eq!(port, 8080);
+///
+/// // 解析失败时打印错误并使用默认值0
+/// let port: u16 = "八零八零".parse::<u16>().or_default_logged();
+/// assert_eq!(port, 0);
+/// ```
+pub trait ResultExt<T, E> {
+    /// 出错时打印`上下文: 错误`，原样返回`Result`
+    fn log_err(self, context: &str) -> Self;
+
+    /// 成功时返回值；出错时打印错误并返回`T::default()`
+    fn or_default_logged(self) -> T
+    where
+        T: Default;
+}
+
+impl<T, E: Display> ResultExt<T, E> for Result<T, E> {
+    fn log_err(self, context: &str) -> Self {
+        if let Err(e) = &self {
+            eprintln!("❌ {}: {}", context, e);
+        }
+        self
+    }
+
+    fn or_default_logged(self) -> T
+    where
+        T: Default,
+    {
+        self.unwrap_or_else(|e| {
+            eprintln!("❌ {}（使用默认值）", e);
+            T::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_err_passes_result_through() {
+        let ok: Result<i32, String> = Ok(5);
+        assert_eq!(ok.log_err("不会打印"), Ok(5));
+
+        let err: Result<i32, String> = Err("磁盘已满".to_string());
+        assert_eq!(err.log_err("写入失败"), Err("磁盘已满".to_string()));
+    }
+
+    #[test]
+    fn test_or_default_logged() {
+        let ok: Result<Vec<u8>, std::io::Error> = Ok(vec![1, 2]);
+        assert_eq!(ok.or_default_logged(), vec![1, 2]);
+
+        let err: Result<Vec<u8>, std::io::Error> = Err(std::io::Error::other("读取失败"));
+        assert_eq!(err.or_default_logged(), Vec::<u8>::new());
+
+        // 两个方法可以连用：先带上下文打印，再取默认值
+        let count = "abc"
+            .parse::<usize>()
+            .log_err("解析数量失败")
+            .unwrap_or_default();
+        assert_eq!(count, 0);
+    }
+}
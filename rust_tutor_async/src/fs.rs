@@ -0,0 +1,175 @@
+//! 文件读写辅助函数
+//!
+//! 对应示例6。示例中用`while let Some(line) = lines.next_line().await?`逐行读取，
+//! 这里把它包装成`Stream`，这样就可以直接使用`filter`、`map`等流组合器。
+//! 写入方向则提供了通过同一个`BufWriter`批量写入多行的函数。
+
+use std::io;
+use std::path::Path;
+
+use futures::{stream, Stream};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter, Lines};
+
+/// 逐行读取文件的流，每个元素是一行内容（不含换行符）
+///
+/// 文件在第一次轮询时才打开，之后每次只读取一行，不会把整个文件读入内存。
+/// 打开或读取失败时产生一个`Err`，随后流结束。
+///
+/// ```no_run
+/// use futures::{StreamExt, TryStreamExt};
+/// use rust_tutor_async::fs::line_stream;
+///
+/// # #[tokio::main]
+/// # async fn main() -> std::io::Result<()> {
+/// let errors: Vec<String> = line_stream("app.log")
+///     .try_filter(|line| std::future::ready(line.contains("ERROR")))
+///     .try_collect()
+///     .await?;
+/// println!("{} 条错误日志", errors.len());
+/// # Ok(())
+/// # }
+/// ```
+pub fn line_stream<P: AsRef<Path>>(path: P) -> impl Stream<Item = io::Result<String>> {
+    enum State {
+        Unopened(std::path::PathBuf),
+        Reading(Lines<BufReader<File>>),
+        Done,
+    }
+
+    let initial = State::Unopened(path.as_ref().to_path_buf());
+    stream::unfold(initial, |state| async move {
+        let mut lines = match state {
+            State::Unopened(path) => match File::open(&path).await {
+                Ok(file) => BufReader::new(file).lines(),
+                Err(e) => return Some((Err(e), State::Done)),
+            },
+            State::Reading(lines) => lines,
+            State::Done => return None,
+        };
+
+        match lines.next_line().await {
+            Ok(Some(line)) => Some((Ok(line), State::Reading(lines))),
+            Ok(None) => None,
+            Err(e) => Some((Err(e), State::Done)),
+        }
+    })
+}
+
+/// 把多行内容写入文件（覆盖已有内容），每行末尾加上`\n`
+///
+/// 所有行都经过同一个`BufWriter`写出，不需要先拼接成一个大字符串，
+/// 写完后统一刷新缓冲区。
+///
+/// ```no_run
+/// use rust_tutor_async::fs::write_lines;
+///
+/// # #[tokio::main]
+/// # async fn main() -> std::io::Result<()> {
+/// write_lines("report.txt", (1..=1000).map(|i| format!("这是第{}行数据", i))).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn write_lines<P, I>(path: P, lines: I) -> io::Result<()>
+where
+    P: AsRef<Path>,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let file = File::create(path).await?;
+    write_all_lines(file, lines).await
+}
+
+/// 与[`write_lines`]相同，但以追加模式打开文件，文件不存在时会创建
+pub async fn append_lines<P, I>(path: P, lines: I) -> io::Result<()>
+where
+    P: AsRef<Path>,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+    write_all_lines(file, lines).await
+}
+
+async fn write_all_lines<I>(file: File, lines: I) -> io::Result<()>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut writer = BufWriter::new(file);
+    for line in lines {
+        writer.write_all(line.as_ref().as_bytes()).await?;
+        writer.write_all(b"\n").await?;
+    }
+    writer.flush().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{StreamExt, TryStreamExt};
+    use std::path::PathBuf;
+
+    /// 每个测试使用独立的临时文件，避免并行测试互相干扰
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rust_tutor_fs_{}_{}", std::process::id(), name))
+    }
+
+    #[tokio::test]
+    async fn test_line_stream_reads_all_lines() {
+        let path = temp_path("lines.txt");
+        tokio::fs::write(&path, "第一行\n第二行\n\n第四行")
+            .await
+            .unwrap();
+
+        let lines: Vec<String> = line_stream(&path).try_collect().await.unwrap();
+        assert_eq!(lines, vec!["第一行", "第二行", "", "第四行"]);
+
+        // 可以直接使用流组合器
+        let non_empty = line_stream(&path)
+            .filter_map(|line| async move { line.ok().filter(|l| !l.is_empty()) })
+            .count()
+            .await;
+        assert_eq!(non_empty, 3);
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_line_stream_missing_file_yields_error() {
+        let results: Vec<io::Result<String>> =
+            line_stream(temp_path("missing.txt")).collect().await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].as_ref().unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+
+    #[tokio::test]
+    async fn test_write_and_append_lines_round_trip() {
+        let path = temp_path("write_lines.txt");
+        let first: Vec<String> = (1..=1000).map(|i| format!("这是第{}行数据", i)).collect();
+        write_lines(&path, &first).await.unwrap();
+
+        let read_back: Vec<String> = line_stream(&path).try_collect().await.unwrap();
+        assert_eq!(read_back, first);
+
+        append_lines(&path, ["追加1", "追加2"]).await.unwrap();
+        let read_back: Vec<String> = line_stream(&path).try_collect().await.unwrap();
+        assert_eq!(read_back.len(), 1002);
+        assert_eq!(read_back[999], "这是第1000行数据");
+        assert_eq!(&read_back[1000..], ["追加1", "追加2"]);
+
+        // 覆盖模式会清空原有内容
+        write_lines(&path, ["只有一行"]).await.unwrap();
+        let read_back: Vec<String> = line_stream(&path).try_collect().await.unwrap();
+        assert_eq!(read_back, vec!["只有一行"]);
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+}
@@ -0,0 +1,228 @@
+//! 通用状态机
+//!
+//! 示例10中的`AsyncStateMachine`把连接状态和转换规则写死在一起，
+//! 并且用`None`表示无效转换，调用者无法区分“转换失败”和“状态没有变化”。
+//! [`StateMachine`]把转换规则抽象成一个函数，无效转换返回[`InvalidTransition`]，
+//! 同时记录每一次成功转换，便于调试和回放。
+
+use std::fmt;
+
+/// 一次成功的状态转换记录
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition<S, E> {
+    pub from: S,
+    pub event: E,
+    pub to: S,
+}
+
+/// 当前状态不接受该事件
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidTransition<S, E> {
+    pub state: S,
+    pub event: E,
+}
+
+impl<S: fmt::Debug, E: fmt::Debug> fmt::Display for InvalidTransition<S, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "状态{:?}不接受事件{:?}", self.state, self.event)
+    }
+}
+
+impl<S: fmt::Debug, E: fmt::Debug> std::error::Error for InvalidTransition<S, E> {}
+
+/// 转换函数：根据当前状态和事件计算新状态
+type TransitionFn<S, E> = Box<dyn Fn(&S, E) -> Option<S> + Send + Sync>;
+
+/// 由转换函数驱动的有限状态机
+///
+/// 转换函数根据当前状态和事件返回新状态，返回`None`表示该事件在当前状态下无效。
+///
+/// ```
+/// use rust_tutor_async::fsm::StateMachine;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Door { Open, Closed }
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Action { Push, Pull }
+///
+/// let mut door = StateMachine::new(Door::Closed, |state: &Door, action: Action| {
+///     match (state, action) {
+///         (Door::Closed, Action::Push) => Some(Door::Open),
+///         (Door::Open, Action::Pull) => Some(Door::Closed),
+///         _ => None,
+///     }
+/// });
+///
+/// assert_eq!(door.handle(Action::Push), Ok(&Door::Open));
+/// assert!(door.handle(Action::Push).is_err());
+/// assert_eq!(door.state(), &Door::Open);
+/// ```
+pub struct StateMachine<S, E> {
+    state: S,
+    transition: TransitionFn<S, E>,
+    log: Vec<Transition<S, E>>,
+}
+
+impl<S: Clone, E: Clone> StateMachine<S, E> {
+    /// 以`initial`为初始状态，使用`transition`作为转换规则
+    pub fn new<F>(initial: S, transition: F) -> Self
+    where
+        F: Fn(&S, E) -> Option<S> + Send + Sync + 'static,
+    {
+        Self {
+            state: initial,
+            transition: Box::new(transition),
+            log: Vec::new(),
+        }
+    }
+
+    /// 处理一个事件，成功时返回新状态
+    ///
+    /// 无效转换不会改变当前状态，也不会写入转换记录。
+    pub fn handle(&mut self, event: E) -> Result<&S, InvalidTransition<S, E>> {
+        match (self.transition)(&self.state, event.clone()) {
+            Some(next) => {
+                let from = std::mem::replace(&mut self.state, next.clone());
+                self.log.push(Transition {
+                    from,
+                    event,
+                    to: next,
+                });
+                Ok(&self.state)
+            }
+            None => Err(InvalidTransition {
+                state: self.state.clone(),
+                event,
+            }),
+        }
+    }
+
+    /// 当前状态
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// 按发生顺序排列的转换记录
+    pub fn log(&self) -> &[Transition<S, E>] {
+        &self.log
+    }
+}
+
+impl<S: fmt::Debug, E: fmt::Debug> fmt::Debug for StateMachine<S, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StateMachine")
+            .field("state", &self.state)
+            .field("log", &self.log)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX_RETRIES: u32 = 2;
+
+    // 用通用状态机重建示例10中的连接状态机，重试次数保存在状态里
+    #[derive(Debug, Clone, PartialEq)]
+    enum ConnectionState {
+        Disconnected,
+        Connecting { retries: u32 },
+        Connected,
+        Reconnecting { retries: u32 },
+        Failed(String),
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum ConnectionEvent {
+        Connect,
+        Connected,
+        Disconnect,
+        ConnectionLost,
+        Retry,
+        Error(String),
+    }
+
+    fn connection_machine() -> StateMachine<ConnectionState, ConnectionEvent> {
+        use ConnectionEvent as E;
+        use ConnectionState as S;
+
+        StateMachine::new(S::Disconnected, |state: &S, event: E| {
+            match (state, event) {
+                (S::Disconnected, E::Connect) => Some(S::Connecting { retries: 0 }),
+                (S::Connecting { .. }, E::Connected) => Some(S::Connected),
+                (S::Connecting { retries }, E::Error(_)) if *retries < MAX_RETRIES => {
+                    Some(S::Reconnecting { retries: *retries })
+                }
+                (S::Connecting { .. }, E::Error(msg)) => Some(S::Failed(msg)),
+                (S::Connected, E::Disconnect) => Some(S::Disconnected),
+                (S::Connected, E::ConnectionLost) => Some(S::Reconnecting { retries: 0 }),
+                (S::Reconnecting { retries }, E::Retry) => Some(S::Connecting {
+                    retries: retries + 1,
+                }),
+                (S::Failed(_), E::Connect) => Some(S::Connecting { retries: 0 }),
+                _ => None,
+            }
+        })
+    }
+
+    #[test]
+    fn test_connection_happy_path_and_log() {
+        let mut machine = connection_machine();
+        machine.handle(ConnectionEvent::Connect).unwrap();
+        machine.handle(ConnectionEvent::Connected).unwrap();
+        machine.handle(ConnectionEvent::ConnectionLost).unwrap();
+        machine.handle(ConnectionEvent::Retry).unwrap();
+        assert_eq!(
+            machine.handle(ConnectionEvent::Connected),
+            Ok(&ConnectionState::Connected)
+        );
+
+        let log = machine.log();
+        assert_eq!(log.len(), 5);
+        assert_eq!(
+            log[2],
+            Transition {
+                from: ConnectionState::Connected,
+                event: ConnectionEvent::ConnectionLost,
+                to: ConnectionState::Reconnecting { retries: 0 },
+            }
+        );
+        assert_eq!(log[3].to, ConnectionState::Connecting { retries: 1 });
+    }
+
+    #[test]
+    fn test_invalid_transition_is_an_error() {
+        let mut machine = connection_machine();
+        let err = machine.handle(ConnectionEvent::Disconnect).unwrap_err();
+
+        assert_eq!(err.state, ConnectionState::Disconnected);
+        assert_eq!(err.event, ConnectionEvent::Disconnect);
+        // 状态和记录都没有变化
+        assert_eq!(machine.state(), &ConnectionState::Disconnected);
+        assert!(machine.log().is_empty());
+    }
+
+    #[test]
+    fn test_retries_exhausted_fails() {
+        let mut machine = connection_machine();
+        machine.handle(ConnectionEvent::Connect).unwrap();
+        for _ in 0..MAX_RETRIES {
+            machine
+                .handle(ConnectionEvent::Error("超时".to_string()))
+                .unwrap();
+            machine.handle(ConnectionEvent::Retry).unwrap();
+        }
+        assert_eq!(
+            machine.handle(ConnectionEvent::Error("超时".to_string())),
+            Ok(&ConnectionState::Failed("超时".to_string()))
+        );
+
+        // 失败后可以重新连接
+        assert_eq!(
+            machine.handle(ConnectionEvent::Connect),
+            Ok(&ConnectionState::Connecting { retries: 0 })
+        );
+    }
+}
@@ -0,0 +1,613 @@
+//! HTTP客户端辅助函数
+//!
+//! 对应示例5。示例中的`fetch_with_retry`在重试用尽后把500响应当作`Ok`返回，
+//! 调用者无法区分成功和失败；这里的[`get_with_retry`]只把2xx响应当作成功，
+//! 其余情况都通过[`HttpError`]明确地告诉调用者失败的原因。
+//!
+//! 示例中的`streaming_download`先用`bytes()`把整个响应体读进内存再“分块”，
+//! [`download_to_writer`]则边接收边写出，内存中只保留当前这一块数据。
+//!
+//! 示例中的`concurrent_requests`用`join_all`一次发出所有请求，URL很多时会同时占用大量连接；
+//! [`fetch_all`]限制同时进行的请求数量。
+
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, Response, StatusCode};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::time::sleep;
+
+use crate::concurrency::map_concurrent;
+
+/// HTTP请求失败的原因
+#[derive(Debug)]
+pub enum HttpError {
+    /// 服务器返回了非成功的状态码
+    Status(u16),
+    /// 网络层错误（连接失败、超时等），请求没有得到HTTP响应
+    Transport(reqwest::Error),
+    /// 把响应体写入本地目标时发生的I/O错误
+    Io(io::Error),
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::Status(code) => write!(f, "HTTP状态错误: {}", code),
+            HttpError::Transport(e) => write!(f, "网络错误: {}", e),
+            HttpError::Io(e) => write!(f, "写入错误: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HttpError::Status(_) => None,
+            HttpError::Transport(e) => Some(e),
+            HttpError::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<reqwest::Error> for HttpError {
+    fn from(e: reqwest::Error) -> Self {
+        HttpError::Transport(e)
+    }
+}
+
+impl From<io::Error> for HttpError {
+    fn from(e: io::Error) -> Self {
+        HttpError::Io(e)
+    }
+}
+
+/// 重试策略：最多尝试次数和指数退避的等待时间
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// 总尝试次数（包括第一次请求），至少为1
+    pub max_attempts: u32,
+    /// 第一次重试前的等待时间，之后每次翻倍
+    pub initial_backoff: Duration,
+    /// 单次等待时间的上限，服务器通过`Retry-After`要求的等待时间也不会超过它
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// 第`attempt`次（从1开始）失败后应等待的时间
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+/// 发送GET请求，按照`policy`重试临时性的失败
+///
+/// - 2xx：成功，返回响应
+/// - 5xx、429（请求过多）、网络错误：可重试，等待退避时间后再次请求
+/// - 其他状态码（如404）：请求本身有问题，重试也没有意义，立即返回错误
+///
+/// 429和503响应带有`Retry-After`头（秒数或HTTP日期）时，按服务器要求的时间等待，
+/// 而不是使用退避时间；头的格式无效时仍然使用退避时间。
+/// 无论哪种情况，单次等待都不超过`policy.max_backoff`，避免服务器返回很大的值时一直挂起。
+/// 重试次数用尽时返回最后一次失败的原因。
+pub async fn get_with_retry(
+    client: &Client,
+    url: &str,
+    policy: &RetryPolicy,
+) -> Result<Response, HttpError> {
+    let max_attempts = policy.max_attempts.max(1);
+    let mut attempt = 1;
+
+    loop {
+        let (error, retry_after) = match client.get(url).send().await {
+            Ok(response) if response.status().is_success() => return Ok(response),
+            Ok(response) if is_retryable(response.status()) => (
+                HttpError::Status(response.status().as_u16()),
+                retry_after(&response),
+            ),
+            Ok(response) => return Err(HttpError::Status(response.status().as_u16())),
+            Err(e) => (HttpError::Transport(e), None),
+        };
+
+        if attempt >= max_attempts {
+            return Err(error);
+        }
+        let wait = retry_after.unwrap_or_else(|| policy.backoff(attempt));
+        sleep(wait.min(policy.max_backoff)).await;
+        attempt += 1;
+    }
+}
+
+/// 把响应体以流的方式写入`writer`，返回写入的总字节数
+///
+/// 通过`bytes_stream()`逐块接收数据，每收到一块就立即写出，
+/// 不会把整个响应体保存在内存中，适合下载大文件。
+/// 非2xx的响应不会写入任何数据，直接返回[`HttpError::Status`]。
+pub async fn download_to_writer<W>(response: Response, writer: &mut W) -> Result<u64, HttpError>
+where
+    W: AsyncWrite + Unpin,
+{
+    if !response.status().is_success() {
+        return Err(HttpError::Status(response.status().as_u16()));
+    }
+
+    let mut downloaded = 0u64;
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        writer.write_all(&chunk).await?;
+        downloaded += chunk.len() as u64;
+    }
+    writer.flush().await?;
+    Ok(downloaded)
+}
+
+/// 并发请求所有URL，同时进行的请求最多`concurrency`个（为0时按1处理）
+///
+/// 结果按`urls`的顺序返回：收到HTTP响应时是`(URL, 状态码)`，无论状态码是否成功；
+/// 请求没有得到响应（连接失败等）时是[`HttpError::Transport`]。
+/// 只读取状态码，不读取响应体。
+pub async fn fetch_all<I>(
+    client: &Client,
+    urls: I,
+    concurrency: usize,
+) -> Vec<Result<(String, u16), HttpError>>
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+{
+    fetch_all_with(urls, concurrency, |url| async move {
+        let response = client.get(&url).send().await?;
+        Ok(response.status().as_u16())
+    })
+    .await
+}
+
+/// 与[`fetch_all`]相同，但由`fetch`完成单个请求并返回状态码
+///
+/// 可以替换成带重试的请求，或者在测试中替换成不访问网络的模拟函数。
+///
+/// ```
+/// use rust_tutor_async::http::fetch_all_with;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let results = fetch_all_with(["/a", "/b"], 2, |url| async move {
+///     Ok(if url == "/a" { 200 } else { 404 })
+/// })
+/// .await;
+/// assert_eq!(results[0].as_ref().unwrap(), &("/a".to_string(), 200));
+/// assert_eq!(results[1].as_ref().unwrap(), &("/b".to_string(), 404));
+/// # }
+/// ```
+pub async fn fetch_all_with<I, F, Fut>(
+    urls: I,
+    concurrency: usize,
+    mut fetch: F,
+) -> Vec<Result<(String, u16), HttpError>>
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<u16, HttpError>>,
+{
+    map_concurrent(
+        urls.into_iter().map(Into::into),
+        concurrency,
+        |url: String| {
+            let request = fetch(url.clone());
+            async move { request.await.map(|status| (url, status)) }
+        },
+    )
+    .await
+}
+
+/// 服务器端错误（5xx）和请求过多（429）通常是暂时的，值得重试
+fn is_retryable(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+/// 429和503响应中`Retry-After`头要求的等待时间
+fn retry_after(response: &Response) -> Option<Duration> {
+    let status = response.status();
+    if status != StatusCode::TOO_MANY_REQUESTS && status != StatusCode::SERVICE_UNAVAILABLE {
+        return None;
+    }
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    parse_retry_after(value, Utc::now())
+}
+
+/// 解析`Retry-After`的值：非负整数秒，或者HTTP日期（例如`Wed, 21 Oct 2015 07:28:00 GMT`）
+///
+/// 日期已经过去时等待时间为0；无法解析时返回`None`。
+fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll};
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+
+    /// 模拟服务器的一次响应
+    struct MockResponse {
+        status: u16,
+        headers: Vec<(&'static str, String)>,
+        body: Vec<u8>,
+    }
+
+    impl MockResponse {
+        fn status(status: u16) -> Self {
+            Self {
+                status,
+                headers: Vec::new(),
+                body: Vec::new(),
+            }
+        }
+    }
+
+    /// 启动一个本地HTTP服务器，按顺序返回`responses`（用完后重复最后一个），
+    /// 返回服务器地址和已处理的请求计数
+    async fn spawn_mock_server(responses: Vec<MockResponse>) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+
+        tokio::spawn(async move {
+            let responses = Arc::new(responses);
+            loop {
+                let (mut socket, _) = match listener.accept().await {
+                    Ok(conn) => conn,
+                    Err(_) => return,
+                };
+                let index = counter.fetch_add(1, Ordering::SeqCst);
+                let responses = responses.clone();
+                tokio::spawn(async move {
+                    // 读到请求头结束即可，GET请求没有请求体
+                    let mut request = Vec::new();
+                    let mut buf = [0u8; 1024];
+                    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        match socket.read(&mut buf).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => request.extend_from_slice(&buf[..n]),
+                        }
+                    }
+
+                    let response = &responses[index.min(responses.len() - 1)];
+                    let mut head = format!(
+                        "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n",
+                        response.status,
+                        response.body.len()
+                    );
+                    for (name, value) in &response.headers {
+                        head.push_str(&format!("{}: {}\r\n", name, value));
+                    }
+                    head.push_str("\r\n");
+                    let _ = socket.write_all(head.as_bytes()).await;
+                    let _ = socket.write_all(&response.body).await;
+                    let _ = socket.shutdown().await;
+                });
+            }
+        });
+
+        (url, hits)
+    }
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            initial_backoff: Duration::from_millis(5),
+            max_backoff: Duration::from_millis(20),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_success_returns_response() {
+        let (url, hits) = spawn_mock_server(vec![MockResponse::status(200)]).await;
+        let response = get_with_retry(&Client::new(), &url, &fast_policy(3))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_server_error_is_retried_until_success() {
+        let (url, hits) = spawn_mock_server(vec![
+            MockResponse::status(500),
+            MockResponse::status(503),
+            MockResponse::status(200),
+        ])
+        .await;
+        let response = get_with_retry(&Client::new(), &url, &fast_policy(3))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_server_error_exhausts_retries() {
+        let (url, hits) = spawn_mock_server(vec![MockResponse::status(500)]).await;
+        let result = get_with_retry(&Client::new(), &url, &fast_policy(3)).await;
+        assert!(matches!(result, Err(HttpError::Status(500))));
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_client_error_is_not_retried() {
+        let (url, hits) = spawn_mock_server(vec![MockResponse::status(404)]).await;
+        let result = get_with_retry(&Client::new(), &url, &fast_policy(3)).await;
+        assert!(matches!(result, Err(HttpError::Status(404))));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_transport_error() {
+        // 绑定后立即释放端口，之后的连接会被拒绝
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let result = get_with_retry(&Client::new(), &url, &fast_policy(2)).await;
+        match result {
+            Err(e @ HttpError::Transport(_)) => {
+                assert!(std::error::Error::source(&e).is_some());
+            }
+            other => panic!("期望网络错误，实际得到 {:?}", other),
+        }
+    }
+
+    /// 第一次返回带`Retry-After`头的429，之后返回200
+    fn retry_after_then_ok(value: &str) -> Vec<MockResponse> {
+        vec![
+            MockResponse {
+                status: 429,
+                headers: vec![("Retry-After", value.to_string())],
+                body: Vec::new(),
+            },
+            MockResponse::status(200),
+        ]
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_after_seconds_overrides_backoff() {
+        let (url, hits) = spawn_mock_server(retry_after_then_ok("1")).await;
+        let policy = RetryPolicy {
+            max_backoff: Duration::from_secs(5),
+            ..fast_policy(3)
+        };
+
+        // 时钟是暂停的，1秒的等待不会真的花掉1秒
+        let start = tokio::time::Instant::now();
+        let response = get_with_retry(&Client::new(), &url, &policy).await.unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        // 退避时间只有5毫秒，等待了1秒说明使用的是Retry-After
+        assert!(start.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_oversized_retry_after_is_capped() {
+        // 服务器要求等待一整天，实际只等待max_backoff
+        let (url, hits) = spawn_mock_server(retry_after_then_ok("86400")).await;
+
+        let start = tokio::time::Instant::now();
+        let response = get_with_retry(&Client::new(), &url, &fast_policy(3))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        let elapsed = start.elapsed();
+        assert!(elapsed < Duration::from_secs(60), "等待过长: {:?}", elapsed);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_invalid_retry_after_falls_back_to_backoff() {
+        let (url, hits) = spawn_mock_server(vec![
+            MockResponse {
+                status: 503,
+                headers: vec![("Retry-After", "等一会儿".to_string())],
+                body: Vec::new(),
+            },
+            MockResponse::status(503),
+            MockResponse::status(200),
+        ])
+        .await;
+
+        let start = tokio::time::Instant::now();
+        let response = get_with_retry(&Client::new(), &url, &fast_policy(3))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        assert!(start.elapsed() < Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = DateTime::parse_from_rfc2822("Wed, 21 Oct 2015 07:28:00 GMT")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(parse_retry_after(" 0 ", now), Some(Duration::ZERO));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        // 已经过去的日期不需要等待
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("-5", now), None);
+        assert_eq!(parse_retry_after("1.5", now), None);
+        assert_eq!(parse_retry_after("", now), None);
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_max() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(500));
+        assert_eq!(policy.backoff(40), Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_respects_concurrency_limit() {
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+        let urls: Vec<String> = (0..10)
+            .map(|i| format!("http://example.com/{}", i))
+            .collect();
+
+        let results = fetch_all_with(urls.clone(), 3, |url| {
+            let running = Arc::clone(&running);
+            let max_running = Arc::clone(&max_running);
+            async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(now, Ordering::SeqCst);
+                sleep(Duration::from_millis(20)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                if url.ends_with('7') {
+                    Err(HttpError::Io(io::Error::other("模拟失败")))
+                } else {
+                    Ok(200)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(max_running.load(Ordering::SeqCst), 3);
+        assert_eq!(results.len(), urls.len());
+        for (i, (result, url)) in results.iter().zip(&urls).enumerate() {
+            match result {
+                Ok((fetched, status)) => {
+                    assert_eq!(fetched, url);
+                    assert_eq!(*status, 200);
+                }
+                Err(_) => assert_eq!(i, 7),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_reports_status_codes() {
+        let (ok_url, _) = spawn_mock_server(vec![MockResponse::status(200)]).await;
+        let (missing_url, _) = spawn_mock_server(vec![MockResponse::status(404)]).await;
+        let closed = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let closed_url = format!("http://{}", closed.local_addr().unwrap());
+        drop(closed);
+
+        let urls = vec![ok_url.clone(), missing_url.clone(), closed_url];
+        let results = fetch_all(&Client::new(), urls, 0).await;
+        assert_eq!(results[0].as_ref().unwrap(), &(ok_url, 200));
+        assert_eq!(results[1].as_ref().unwrap(), &(missing_url, 404));
+        assert!(matches!(results[2], Err(HttpError::Transport(_))));
+    }
+
+    /// 只统计写入量、不保存数据的writer，记录单次写入的最大字节数
+    #[derive(Default)]
+    struct CountingWriter {
+        total: usize,
+        largest_write: usize,
+    }
+
+    impl AsyncWrite for CountingWriter {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            self.total += buf.len();
+            self.largest_write = self.largest_write.max(buf.len());
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_streams_in_small_chunks() {
+        const BODY_SIZE: usize = 4 * 1024 * 1024;
+        let (url, _) = spawn_mock_server(vec![MockResponse {
+            status: 200,
+            headers: Vec::new(),
+            body: vec![7u8; BODY_SIZE],
+        }])
+        .await;
+
+        let response = Client::new().get(&url).send().await.unwrap();
+        let mut writer = CountingWriter::default();
+        let downloaded = download_to_writer(response, &mut writer).await.unwrap();
+
+        assert_eq!(downloaded, BODY_SIZE as u64);
+        assert_eq!(writer.total, BODY_SIZE);
+        // 每次写出的只是网络上收到的一块（受hyper读缓冲区大小限制），而不是整个响应体
+        assert!(writer.largest_write <= 512 * 1024);
+    }
+
+    #[tokio::test]
+    async fn test_download_rejects_error_status() {
+        let (url, _) = spawn_mock_server(vec![MockResponse {
+            status: 500,
+            headers: Vec::new(),
+            body: b"error".to_vec(),
+        }])
+        .await;
+
+        let response = Client::new().get(&url).send().await.unwrap();
+        let mut writer = CountingWriter::default();
+        let result = download_to_writer(response, &mut writer).await;
+        assert!(matches!(result, Err(HttpError::Status(500))));
+        assert_eq!(writer.total, 0);
+    }
+}
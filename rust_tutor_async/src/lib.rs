@@ -0,0 +1,46 @@
+//! # Rust 异步编程教程 - 可复用组件
+//!
+//! 十个示例的正文在[`examples`]模块中，`src/examples/NN_*.rs`下的可执行程序只是调用它们；
+//! 其余模块把示例中反复出现的模式整理成可以直接复用和测试的组件。
+//!
+//! ## 模块
+//!
+//! - [`cache`] - 同一个key只加载一次的异步缓存、带命中统计的读写锁缓存
+//! - [`chat`] - 基于广播通道、能容忍慢速成员的聊天室
+//! - [`concurrency`] - 限制并发数且保持顺序的批量执行
+//! - [`error`] - 合并网络、验证、超时、HTTP、I/O等错误的统一错误类型
+//! - [`examples`] - 十个示例的正文，以及按名字运行示例的统一入口
+//! - [`ext`] - `Result`的扩展方法（打印错误、出错时取默认值）
+//! - [`fs`] - 文件按行读取的流和批量写入
+//! - [`fsm`] - 由转换函数驱动的通用状态机
+//! - [`http`] - 带重试和状态码处理的HTTP请求
+//! - [`metrics`] - 请求耗时的滑动窗口和百分位统计
+//! - [`net`] - 逐行回显、支持优雅关闭的TCP服务器
+//! - [`pool`] - 连接池、按优先级调度的工作队列和字节缓冲区池
+//! - [`rate`] - 令牌桶限流器
+//! - [`resilience`] - 熔断器
+//! - [`select`] - 总是先取高优先级通道的双通道接收
+//! - [`shutdown`] - 通知所有任务退出的优雅关闭协调器
+//! - [`sim`] - 带随机噪声和异常阈值的模拟传感器数据流
+//! - [`stream`] - 自定义流和流组合器
+//! - [`util`] - 通用辅助函数（带指数退避的重试等）
+
+pub mod cache;
+pub mod chat;
+pub mod concurrency;
+pub mod error;
+pub mod examples;
+pub mod ext;
+pub mod fs;
+pub mod fsm;
+pub mod http;
+pub mod metrics;
+pub mod net;
+pub mod pool;
+pub mod rate;
+pub mod resilience;
+pub mod select;
+pub mod shutdown;
+pub mod sim;
+pub mod stream;
+pub mod util;
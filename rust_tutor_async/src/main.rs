@@ -1,3 +1,76 @@
-fn main() {
-    println!("Hello, world!");
+use std::io::{self, Write};
+
+use rust_tutor_async::examples;
+
+/// 用法：
+/// - `cargo run` 进入交互式菜单
+/// - `cargo run -- 3` 或 `cargo run -- concurrent_tasks` 运行单个示例后退出
+/// - `cargo run -- all` 按顺序运行所有示例后退出
+#[tokio::main]
+async fn main() {
+    match std::env::args().nth(1) {
+        None => interactive().await,
+        Some(arg) if arg == "all" => {
+            for (name, _) in examples::EXAMPLES {
+                examples::run_by_name(name).await;
+            }
+        }
+        Some(arg) => {
+            if run_choice(&arg).await.is_none() {
+                eprintln!(
+                    "❌ 无效的示例: {}（可选 1-{}、示例名或 all）",
+                    arg,
+                    examples::EXAMPLES.len()
+                );
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// 按编号或名字运行示例
+async fn run_choice(choice: &str) -> Option<()> {
+    let name = match choice.parse() {
+        Ok(number) => examples::name_by_number(number)?,
+        Err(_) => choice,
+    };
+    examples::run_by_name(name).await
+}
+
+async fn interactive() {
+    println!("🦀 Rust 异步编程教程 - 交互式学习系统");
+    println!("=====================================");
+
+    loop {
+        print_menu();
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("读取输入失败");
+
+        match input.trim() {
+            "0" | "" => {
+                println!("感谢使用Rust异步编程教程！再见！👋");
+                break;
+            }
+            choice => {
+                if run_choice(choice).await.is_none() {
+                    println!("❌ 无效选择，请重新输入");
+                }
+            }
+        }
+
+        println!("\n按回车键继续...");
+        let mut _temp = String::new();
+        io::stdin().read_line(&mut _temp).ok();
+    }
+}
+
+fn print_menu() {
+    println!("\n📚 请选择要运行的异步示例：");
+    for (number, (name, title)) in examples::EXAMPLES.iter().enumerate() {
+        println!("{:<3} {} ({})", format!("{}.", number + 1), title, name);
+    }
+    println!("0.  退出");
+    print!("请输入选择 (0-{}): ", examples::EXAMPLES.len());
+    io::stdout().flush().ok();
 }
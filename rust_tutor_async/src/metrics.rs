@@ -0,0 +1,128 @@
+//! 指标收集
+//!
+//! 对应示例10中的“异步监控和指标收集”。示例用`Vec`保存响应时间，
+//! 超过上限时调用`remove(0)`丢弃最旧的记录，每次都要移动整个数组；
+//! [`LatencyRecorder`]改用`VecDeque`，在两端增删都是O(1)。
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// 记录最近若干次请求耗时的滑动窗口
+///
+/// ```
+/// use std::time::Duration;
+/// use rust_tutor_async::metrics::LatencyRecorder;
+///
+/// let mut recorder = LatencyRecorder::new(100);
+/// for ms in 1..=100 {
+///     recorder.record(Duration::from_millis(ms));
+/// }
+/// assert_eq!(recorder.percentile(50.0), Some(Duration::from_millis(50)));
+/// assert_eq!(recorder.percentile(99.0), Some(Duration::from_millis(99)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LatencyRecorder {
+    samples: VecDeque<Duration>,
+    capacity: usize,
+}
+
+impl LatencyRecorder {
+    /// 创建最多保留`capacity`条记录的窗口
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// 记录一次耗时，窗口已满时丢弃最旧的记录
+    pub fn record(&mut self, duration: Duration) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(duration);
+    }
+
+    /// 窗口中的记录数
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// 窗口是否为空
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// 窗口内的平均耗时
+    pub fn average(&self) -> Option<Duration> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let total: Duration = self.samples.iter().sum();
+        Some(total / self.samples.len() as u32)
+    }
+
+    /// 计算第`p`百分位的耗时（`p`取值0到100，如50、95、99）
+    ///
+    /// 使用最近秩（nearest-rank）方法：对窗口的快照排序后，
+    /// 取第`ceil(p / 100 * n)`个值。窗口为空或`p`不在范围内时返回None。
+    pub fn percentile(&self, p: f64) -> Option<Duration> {
+        if self.samples.is_empty() || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        let mut sorted: Vec<Duration> = self.samples.iter().copied().collect();
+        sorted.sort_unstable();
+
+        let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+        Some(sorted[rank.clamp(1, sorted.len()) - 1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_is_capped() {
+        let mut recorder = LatencyRecorder::new(3);
+        for ms in 1..=5 {
+            recorder.record(Duration::from_millis(ms));
+        }
+        assert_eq!(recorder.len(), 3);
+        // 只保留最近的3条：3ms、4ms、5ms
+        assert_eq!(recorder.average(), Some(Duration::from_millis(4)));
+        assert_eq!(recorder.percentile(0.0), Some(Duration::from_millis(3)));
+    }
+
+    #[test]
+    fn test_known_percentiles() {
+        let mut recorder = LatencyRecorder::new(100);
+        // 乱序写入，百分位基于排序后的快照
+        for ms in (1..=100).rev() {
+            recorder.record(Duration::from_millis(ms));
+        }
+        assert_eq!(recorder.percentile(50.0), Some(Duration::from_millis(50)));
+        assert_eq!(recorder.percentile(95.0), Some(Duration::from_millis(95)));
+        assert_eq!(recorder.percentile(99.0), Some(Duration::from_millis(99)));
+        assert_eq!(recorder.percentile(100.0), Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_empty_and_invalid_input() {
+        let mut recorder = LatencyRecorder::new(10);
+        assert_eq!(recorder.percentile(50.0), None);
+        assert_eq!(recorder.average(), None);
+
+        recorder.record(Duration::from_millis(1));
+        assert_eq!(recorder.percentile(-1.0), None);
+        assert_eq!(recorder.percentile(100.5), None);
+        assert_eq!(recorder.percentile(f64::NAN), None);
+
+        let mut disabled = LatencyRecorder::new(0);
+        disabled.record(Duration::from_millis(1));
+        assert!(disabled.is_empty());
+    }
+}
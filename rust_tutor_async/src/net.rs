@@ -0,0 +1,185 @@
+//! 基于`TcpListener`的异步服务器
+//!
+//! 示例9中的“多路复用服务器模拟”用通道代替了真正的网络连接。
+//! [`echo_server`]是一个真实的TCP服务器：每个连接由单独的任务处理，
+//! 主循环在`select!`中同时等待新连接和关闭信号（[`crate::shutdown`]）。
+
+use std::io;
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream, ToSocketAddrs};
+use tokio::task::JoinSet;
+use tokio::time::sleep;
+
+use crate::shutdown::{wait_for_signal, Shutdown, ShutdownListener};
+
+/// 接受连接失败后，等待多久再接受下一个连接
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// 在`addr`上运行回声服务器，直到收到Ctrl-C
+///
+/// 收到Ctrl-C后不再接受新连接，等所有已有连接关闭后返回。
+/// 需要自己控制关闭时机（例如在测试中）时使用[`serve_echo`]。
+pub async fn echo_server(addr: impl ToSocketAddrs) -> io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    let shutdown = Shutdown::new();
+    let (_, result) = tokio::join!(
+        wait_for_signal(&shutdown),
+        serve_echo(listener, shutdown.listener())
+    );
+    result
+}
+
+/// 在已经绑定的`listener`上运行回声服务器，直到`shutdown`收到关闭信号
+///
+/// 每个连接把收到的每一行原样发回。收到关闭信号后停止接受新连接，
+/// 通知所有连接在处理完当前这一行后断开，等它们全部结束后返回。
+/// 接受连接时的错误只会打印出来，服务器继续运行。
+///
+/// ```
+/// use rust_tutor_async::net::serve_echo;
+/// use rust_tutor_async::shutdown::Shutdown;
+/// use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+/// use tokio::net::{TcpListener, TcpStream};
+///
+/// # #[tokio::main]
+/// # async fn main() -> std::io::Result<()> {
+/// // 端口0表示由系统分配一个空闲端口
+/// let listener = TcpListener::bind("127.0.0.1:0").await?;
+/// let addr = listener.local_addr()?;
+/// let shutdown = Shutdown::new();
+/// let server = tokio::spawn(serve_echo(listener, shutdown.listener()));
+///
+/// let mut client = BufReader::new(TcpStream::connect(addr).await?);
+/// client.get_mut().write_all(b"hello\n").await?;
+/// let mut reply = String::new();
+/// client.read_line(&mut reply).await?;
+/// assert_eq!(reply, "hello\n");
+///
+/// shutdown.trigger();
+/// server.await.unwrap()?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn serve_echo(listener: TcpListener, shutdown: ShutdownListener) -> io::Result<()> {
+    let mut connections = JoinSet::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    connections.spawn(echo_lines(stream, shutdown.clone()));
+                }
+                // accept失败通常是暂时的（文件描述符用尽、客户端在握手后立即断开等），
+                // 不应该让整个服务器退出；稍等一下再继续，避免在同一个错误上空转
+                Err(e) => {
+                    eprintln!("❌ 接受连接失败: {}", e);
+                    sleep(ACCEPT_RETRY_DELAY).await;
+                }
+            },
+            // 顺便回收已经结束的连接任务，避免JoinSet无限增长
+            Some(_) = connections.join_next(), if !connections.is_empty() => {}
+            _ = shutdown.recv() => break,
+        }
+    }
+    // 单个连接的错误（例如客户端中途断开）不影响服务器本身
+    while connections.join_next().await.is_some() {}
+    Ok(())
+}
+
+/// 处理一个连接：逐行读取并原样写回，直到客户端关闭连接或收到关闭信号
+async fn echo_lines(stream: TcpStream, shutdown: ShutdownListener) -> io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+    let mut line = String::new();
+    loop {
+        line.clear();
+        let read = tokio::select! {
+            read = reader.read_line(&mut line) => read?,
+            _ = shutdown.recv() => return Ok(()),
+        };
+        if read == 0 {
+            return Ok(());
+        }
+        writer.write_all(line.as_bytes()).await?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::SocketAddr;
+    use std::time::Duration;
+    use tokio::io::AsyncReadExt;
+    use tokio::task::JoinHandle;
+    use tokio::time::timeout;
+
+    async fn start() -> (SocketAddr, Shutdown, JoinHandle<io::Result<()>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let shutdown = Shutdown::new();
+        let server = tokio::spawn(serve_echo(listener, shutdown.listener()));
+        (addr, shutdown, server)
+    }
+
+    async fn echo(client: &mut BufReader<TcpStream>, line: &str) -> String {
+        client.get_mut().write_all(line.as_bytes()).await.unwrap();
+        let mut reply = String::new();
+        client.read_line(&mut reply).await.unwrap();
+        reply
+    }
+
+    #[tokio::test]
+    async fn test_echoes_each_line() {
+        let (addr, shutdown, server) = start().await;
+
+        let mut client = BufReader::new(TcpStream::connect(addr).await.unwrap());
+        assert_eq!(echo(&mut client, "你好，服务器\n").await, "你好，服务器\n");
+        assert_eq!(echo(&mut client, "第二行\n").await, "第二行\n");
+
+        shutdown.trigger();
+        timeout(Duration::from_secs(1), server)
+            .await
+            .expect("服务器应该在关闭信号后退出")
+            .unwrap()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_many_clients_concurrently() {
+        let (addr, shutdown, server) = start().await;
+
+        let clients: Vec<_> = (0..20)
+            .map(|i| {
+                tokio::spawn(async move {
+                    let mut client = BufReader::new(TcpStream::connect(addr).await.unwrap());
+                    let line = format!("客户端{}\n", i);
+                    assert_eq!(echo(&mut client, &line).await, line);
+                })
+            })
+            .collect();
+        for client in clients {
+            client.await.unwrap();
+        }
+
+        shutdown.trigger();
+        server.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_closes_open_connections() {
+        let (addr, shutdown, server) = start().await;
+        let mut client = BufReader::new(TcpStream::connect(addr).await.unwrap());
+        assert_eq!(echo(&mut client, "ping\n").await, "ping\n");
+
+        // 客户端仍然连着，服务器也要能退出，并且断开这个连接
+        shutdown.trigger();
+        timeout(Duration::from_secs(1), server)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        let mut rest = Vec::new();
+        assert_eq!(client.read_to_end(&mut rest).await.unwrap(), 0);
+    }
+}
@@ -0,0 +1,552 @@
+//! 异步资源池
+//!
+//! 对应示例10中的“异步资源池模式”和“异步工作队列模式”：
+//!
+//! - [`ConnectionPool`]：示例里的实现在持有锁的同时`.await`查询，
+//!   导致所有查询被串行化；这里先把连接从池中取出、立即释放锁，
+//!   再使用连接，用完后自动归还。
+//! - [`PriorityWorkQueue`]：示例里的工作项带有优先级，但通过mpsc通道
+//!   按先进先出处理；这里用共享的二叉堆让工作者总是先取优先级最高的任务。
+//! - [`BufferPool`]：把连接池的“借出、自动归还”推广到字节缓冲区，
+//!   重复使用已经分配好的内存，避免频繁分配。
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::{Notify, Semaphore, SemaphorePermit};
+use tokio::task::JoinHandle;
+
+/// 通用的异步连接池
+///
+/// 信号量限制同时借出的连接数，连接列表只在取出和归还的瞬间加锁，
+/// 因此不同任务可以真正并行地使用各自借到的连接。
+///
+/// ```
+/// use rust_tutor_async::pool::ConnectionPool;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let pool = ConnectionPool::new(vec![String::from("conn-0"), String::from("conn-1")]);
+/// {
+///     let conn = pool.get().await;
+///     assert!(conn.starts_with("conn-"));
+///     assert_eq!(pool.available(), 1);
+/// } // 离开作用域时连接自动归还
+/// assert_eq!(pool.available(), 2);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ConnectionPool<C> {
+    connections: Mutex<Vec<C>>,
+    semaphore: Semaphore,
+}
+
+impl<C> ConnectionPool<C> {
+    /// 用一组已经建立好的连接创建连接池
+    pub fn new(connections: Vec<C>) -> Self {
+        let semaphore = Semaphore::new(connections.len());
+        Self {
+            connections: Mutex::new(connections),
+            semaphore,
+        }
+    }
+
+    /// 借出一个连接，没有空闲连接时异步等待
+    ///
+    /// 返回的[`PooledConnection`]在整个使用期间持有信号量许可，
+    /// 被丢弃时把连接放回池中。
+    pub async fn get(&self) -> PooledConnection<'_, C> {
+        let permit = self
+            .semaphore
+            .acquire()
+            .await
+            .expect("连接池的信号量不会被关闭");
+
+        // 拿到许可就一定有空闲连接；锁只在取出连接的这一刻持有
+        let conn = self
+            .connections
+            .lock()
+            .unwrap()
+            .pop()
+            .expect("持有许可时池中必然有空闲连接");
+
+        PooledConnection {
+            conn: Some(conn),
+            pool: self,
+            _permit: permit,
+        }
+    }
+
+    /// 当前空闲的连接数
+    pub fn available(&self) -> usize {
+        self.connections.lock().unwrap().len()
+    }
+}
+
+/// 从连接池借出的连接，通过`Deref`/`DerefMut`像普通连接一样使用
+#[derive(Debug)]
+pub struct PooledConnection<'a, C> {
+    conn: Option<C>,
+    pool: &'a ConnectionPool<C>,
+    // 字段在drop()之后才释放，保证许可归还前连接已经回到池中
+    _permit: SemaphorePermit<'a>,
+}
+
+impl<C> Deref for PooledConnection<'_, C> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        self.conn.as_ref().expect("连接只会在drop时取走")
+    }
+}
+
+impl<C> DerefMut for PooledConnection<'_, C> {
+    fn deref_mut(&mut self) -> &mut C {
+        self.conn.as_mut().expect("连接只会在drop时取走")
+    }
+}
+
+impl<C> Drop for PooledConnection<'_, C> {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            self.pool.connections.lock().unwrap().push(conn);
+        }
+    }
+}
+
+/// 可重复使用的字节缓冲区池
+///
+/// 借出的缓冲区被丢弃时会清空内容并放回池中，下次借出时不需要重新分配内存。
+/// 池中最多保留`max_pooled`个空闲缓冲区，超出的部分直接释放。
+///
+/// ```
+/// use rust_tutor_async::pool::BufferPool;
+///
+/// let pool = BufferPool::new(2, 1024);
+/// {
+///     let mut buf = pool.acquire();
+///     buf.extend_from_slice(b"hello");
+///     assert_eq!(&buf[..], b"hello");
+/// } // 离开作用域时缓冲区被清空并归还
+/// assert_eq!(pool.size(), 1);
+/// assert!(pool.acquire().is_empty());
+/// ```
+#[derive(Debug)]
+pub struct BufferPool {
+    buffers: Mutex<Vec<Vec<u8>>>,
+    max_pooled: usize,
+    buffer_capacity: usize,
+}
+
+impl BufferPool {
+    /// 创建空的缓冲区池：最多保留`max_pooled`个空闲缓冲区，新分配的缓冲区容量为`buffer_capacity`
+    pub fn new(max_pooled: usize, buffer_capacity: usize) -> Self {
+        Self {
+            buffers: Mutex::new(Vec::new()),
+            max_pooled,
+            buffer_capacity,
+        }
+    }
+
+    /// 借出一个空的缓冲区，池中没有空闲缓冲区时分配新的
+    pub fn acquire(&self) -> PooledBuffer<'_> {
+        let buffer = self
+            .buffers
+            .lock()
+            .unwrap()
+            .pop()
+            .unwrap_or_else(|| Vec::with_capacity(self.buffer_capacity));
+        PooledBuffer {
+            buffer: Some(buffer),
+            pool: self,
+        }
+    }
+
+    /// 池中空闲的缓冲区数量
+    pub fn size(&self) -> usize {
+        self.buffers.lock().unwrap().len()
+    }
+
+    /// 池中最多保留的空闲缓冲区数量
+    pub fn max_pooled(&self) -> usize {
+        self.max_pooled
+    }
+}
+
+/// 从缓冲区池借出的缓冲区，通过`Deref`/`DerefMut`像`Vec<u8>`一样使用
+#[derive(Debug)]
+pub struct PooledBuffer<'a> {
+    buffer: Option<Vec<u8>>,
+    pool: &'a BufferPool,
+}
+
+impl Deref for PooledBuffer<'_> {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Vec<u8> {
+        self.buffer.as_ref().expect("缓冲区只会在drop时取走")
+    }
+}
+
+impl DerefMut for PooledBuffer<'_> {
+    fn deref_mut(&mut self) -> &mut Vec<u8> {
+        self.buffer.as_mut().expect("缓冲区只会在drop时取走")
+    }
+}
+
+impl Drop for PooledBuffer<'_> {
+    fn drop(&mut self) {
+        if let Some(mut buffer) = self.buffer.take() {
+            buffer.clear();
+            let mut buffers = self.pool.buffers.lock().unwrap();
+            if buffers.len() < self.pool.max_pooled {
+                buffers.push(buffer);
+            }
+        }
+    }
+}
+
+/// 按优先级调度的异步工作队列
+///
+/// 优先级与示例保持一致：数字越小优先级越高，相同优先级按提交顺序处理。
+/// 所有工作者共享同一个二叉堆，空闲的工作者总是取走当前优先级最高的任务。
+///
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use rust_tutor_async::pool::PriorityWorkQueue;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let done = Arc::new(Mutex::new(Vec::new()));
+/// let record = done.clone();
+/// let queue = PriorityWorkQueue::new(1, move |_worker_id, task: &'static str| {
+///     let record = record.clone();
+///     async move { record.lock().unwrap().push(task) }
+/// });
+///
+/// queue.submit("普通任务", 5);
+/// queue.submit("紧急任务", 0);
+/// queue.shutdown().await;
+///
+/// assert_eq!(*done.lock().unwrap(), vec!["紧急任务", "普通任务"]);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct PriorityWorkQueue<T> {
+    shared: Arc<QueueShared<T>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+#[derive(Debug)]
+struct QueueShared<T> {
+    state: Mutex<QueueState<T>>,
+    notify: Notify,
+}
+
+#[derive(Debug)]
+struct QueueState<T> {
+    heap: BinaryHeap<QueueEntry<T>>,
+    next_seq: u64,
+    closed: bool,
+}
+
+/// 堆中的条目，`seq`用于在相同优先级下保持提交顺序
+#[derive(Debug)]
+struct QueueEntry<T> {
+    priority: u8,
+    seq: u64,
+    item: T,
+}
+
+impl<T> PartialEq for QueueEntry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority && self.seq == other.seq
+    }
+}
+
+impl<T> Eq for QueueEntry<T> {}
+
+impl<T> PartialOrd for QueueEntry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for QueueEntry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap是最大堆：优先级数字越小、提交越早的条目越“大”
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl<T: Send + 'static> PriorityWorkQueue<T> {
+    /// 启动`worker_count`个工作者，每个工作任务交给`handler(工作者编号, 任务)`处理
+    pub fn new<F, Fut>(worker_count: usize, handler: F) -> Self
+    where
+        F: Fn(usize, T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let shared = Arc::new(QueueShared {
+            state: Mutex::new(QueueState {
+                heap: BinaryHeap::new(),
+                next_seq: 0,
+                closed: false,
+            }),
+            notify: Notify::new(),
+        });
+        let handler = Arc::new(handler);
+
+        let workers = (0..worker_count)
+            .map(|worker_id| {
+                let shared = shared.clone();
+                let handler = handler.clone();
+                tokio::spawn(async move {
+                    while let Some(item) = shared.next_item().await {
+                        handler(worker_id, item).await;
+                    }
+                })
+            })
+            .collect();
+
+        Self { shared, workers }
+    }
+
+    /// 提交一个任务，`priority`越小越先被处理
+    pub fn submit(&self, item: T, priority: u8) {
+        {
+            let mut state = self.shared.state.lock().unwrap();
+            let seq = state.next_seq;
+            state.next_seq += 1;
+            state.heap.push(QueueEntry {
+                priority,
+                seq,
+                item,
+            });
+        }
+        self.shared.notify.notify_one();
+    }
+
+    /// 尚未被工作者取走的任务数
+    pub fn pending(&self) -> usize {
+        self.shared.state.lock().unwrap().heap.len()
+    }
+
+    /// 优雅关闭：不再接受新任务，等待工作者处理完队列中剩余的任务后退出
+    ///
+    /// 不调用`shutdown`直接丢弃队列时同样会关闭队列，只是不等待工作者退出。
+    pub async fn shutdown(mut self) {
+        self.shared.close();
+
+        for worker in std::mem::take(&mut self.workers) {
+            let _ = worker.await;
+        }
+    }
+}
+
+impl<T> Drop for PriorityWorkQueue<T> {
+    fn drop(&mut self) {
+        // 工作者持有共享状态的Arc，如果不关闭队列，它们会永远等待新任务；
+        // 关闭后工作者在后台处理完剩余的任务再退出
+        self.shared.close();
+    }
+}
+
+impl<T> QueueShared<T> {
+    /// 标记队列已关闭，并唤醒所有正在等待任务的工作者
+    fn close(&self) {
+        self.state.lock().unwrap().closed = true;
+        self.notify.notify_waiters();
+    }
+
+    /// 取出优先级最高的任务；队列已关闭且为空时返回None
+    async fn next_item(&self) -> Option<T> {
+        loop {
+            // 先注册通知再检查队列，避免在检查和等待之间错过唤醒
+            let notified = self.notify.notified();
+            {
+                let mut state = self.state.lock().unwrap();
+                if let Some(entry) = state.heap.pop() {
+                    return Some(entry.item);
+                }
+                if state.closed {
+                    return None;
+                }
+            }
+            notified.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+    use tokio::time::sleep;
+
+    struct FakeConnection;
+
+    impl FakeConnection {
+        /// 模拟一次耗时查询，返回查询的开始和结束时间
+        async fn query(&mut self) -> (Instant, Instant) {
+            let start = Instant::now();
+            sleep(Duration::from_millis(100)).await;
+            (start, Instant::now())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_queries_run_in_parallel() {
+        let pool = ConnectionPool::new(vec![FakeConnection, FakeConnection]);
+
+        let run_query = || async { pool.get().await.query().await };
+        let ((start1, end1), (start2, end2)) = tokio::join!(run_query(), run_query());
+
+        // 两次查询的时间区间互相重叠，说明没有被锁串行化
+        assert!(start1 < end2 && start2 < end1);
+        assert_eq!(pool.available(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_waits_for_returned_connection() {
+        let pool = ConnectionPool::new(vec![FakeConnection]);
+
+        let run_query = || async { pool.get().await.query().await };
+        let ((start1, end1), (start2, end2)) = tokio::join!(run_query(), run_query());
+
+        // 只有一个连接时，第二次查询必须等第一次归还连接
+        let (first_end, second_start) = if start1 < start2 {
+            (end1, start2)
+        } else {
+            (end2, start1)
+        };
+        assert!(second_start >= first_end);
+        assert_eq!(pool.available(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_work_queue_honors_priority() {
+        let completed = Arc::new(Mutex::new(Vec::new()));
+        let record = completed.clone();
+        let queue = PriorityWorkQueue::new(1, move |_, id: u32| {
+            let record = record.clone();
+            async move {
+                sleep(Duration::from_millis(5)).await;
+                record.lock().unwrap().push(id);
+            }
+        });
+
+        // 单线程运行时下提交期间工作者不会被调度，所有任务都先进入堆中
+        for (id, priority) in [(1, 2), (2, 0), (3, 1), (4, 0), (5, 2), (6, 1)] {
+            queue.submit(id, priority);
+        }
+        assert_eq!(queue.pending(), 6);
+        queue.shutdown().await;
+
+        // 高优先级先完成，相同优先级保持提交顺序
+        assert_eq!(*completed.lock().unwrap(), vec![2, 4, 3, 6, 1, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_work_queue_shutdown_drains_all_items() {
+        let completed = Arc::new(Mutex::new(Vec::new()));
+        let record = completed.clone();
+        let queue = PriorityWorkQueue::new(3, move |worker_id, id: u32| {
+            let record = record.clone();
+            async move {
+                sleep(Duration::from_millis(10)).await;
+                record.lock().unwrap().push((worker_id, id));
+            }
+        });
+
+        for id in 0..9 {
+            queue.submit(id, (id % 3) as u8);
+        }
+        queue.shutdown().await;
+
+        let completed = completed.lock().unwrap();
+        let mut ids: Vec<u32> = completed.iter().map(|&(_, id)| id).collect();
+        ids.sort();
+        assert_eq!(ids, (0..9).collect::<Vec<_>>());
+        // 多个工作者共同分担了任务
+        assert!(completed.iter().any(|&(worker_id, _)| worker_id != 0));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_dropping_work_queue_stops_workers() {
+        let completed = Arc::new(Mutex::new(Vec::new()));
+        let record = completed.clone();
+        let queue = PriorityWorkQueue::new(2, move |_, id: u32| {
+            let record = record.clone();
+            async move {
+                sleep(Duration::from_millis(10)).await;
+                record.lock().unwrap().push(id);
+            }
+        });
+        for id in 0..4 {
+            queue.submit(id, 0);
+        }
+        drop(queue);
+
+        // 工作者退出后处理函数被释放，它持有的completed引用也随之释放
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while Arc::strong_count(&completed) > 1 {
+                sleep(Duration::from_millis(1)).await;
+            }
+        })
+        .await
+        .expect("丢弃队列后工作者应该退出");
+        // 已经提交的任务仍然会被处理完
+        assert_eq!(completed.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_returned_buffer_is_reused() {
+        let pool = BufferPool::new(4, 64);
+        let (ptr, capacity) = {
+            let mut buf = pool.acquire();
+            // 写入超过初始容量的数据，让缓冲区扩容
+            buf.extend(std::iter::repeat_n(7u8, 1000));
+            (buf.as_ptr(), buf.capacity())
+        };
+        assert_eq!(pool.size(), 1);
+
+        let buf = pool.acquire();
+        assert!(buf.is_empty());
+        assert_eq!(buf.capacity(), capacity);
+        assert_eq!(buf.as_ptr(), ptr);
+        assert_eq!(pool.size(), 0);
+    }
+
+    #[test]
+    fn test_pool_never_exceeds_cap() {
+        let pool = BufferPool::new(2, 16);
+        {
+            let buffers: Vec<_> = (0..5).map(|_| pool.acquire()).collect();
+            assert_eq!(buffers.len(), 5);
+            assert_eq!(pool.size(), 0);
+        }
+        assert_eq!(pool.size(), 2);
+
+        for _ in 0..10 {
+            let _a = pool.acquire();
+            let _b = pool.acquire();
+            let _c = pool.acquire();
+        }
+        assert_eq!(pool.size(), pool.max_pooled());
+    }
+
+    #[test]
+    fn test_zero_cap_pool_keeps_nothing() {
+        let pool = BufferPool::new(0, 16);
+        let buf = pool.acquire();
+        assert!(buf.capacity() >= 16);
+        drop(buf);
+        assert_eq!(pool.size(), 0);
+    }
+}
@@ -0,0 +1,185 @@
+//! 限流
+//!
+//! 示例中经常用固定的`sleep`来控制请求速度，[`TokenBucket`]把这种节流
+//! 整理成可复用的令牌桶：桶中最多存放`capacity`个令牌，按固定速率补充，
+//! 每次操作前先取走相应数量的令牌，令牌不足时异步等待。
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::time::{sleep, Instant};
+
+/// 令牌桶限流器
+///
+/// 克隆得到的限流器共享同一个桶，可以在多个任务之间传递。
+///
+/// ```
+/// use rust_tutor_async::rate::TokenBucket;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// // 桶容量为2，每秒补充100个令牌
+/// let limiter = TokenBucket::new(2, 100.0);
+/// limiter.acquire(1).await;
+/// limiter.acquire(1).await;
+/// // 桶已空，下一次需要等待约10ms
+/// assert!(!limiter.try_acquire(1));
+/// limiter.acquire(1).await;
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    capacity: u32,
+    refill_per_sec: f64,
+    state: Arc<Mutex<BucketState>>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// 创建一个装满令牌的桶
+    ///
+    /// # Panics
+    ///
+    /// `refill_per_sec`不是正数时panic。
+    pub fn new(capacity: u32, refill_per_sec: f64) -> Self {
+        assert!(refill_per_sec > 0.0, "补充速率必须大于0");
+        Self {
+            capacity,
+            refill_per_sec,
+            state: Arc::new(Mutex::new(BucketState {
+                tokens: capacity as f64,
+                last_refill: Instant::now(),
+            })),
+        }
+    }
+
+    /// 取走`n`个令牌，令牌不足时异步等待补充
+    ///
+    /// # Panics
+    ///
+    /// `n`超过桶容量时永远无法满足，直接panic。
+    pub async fn acquire(&self, n: u32) {
+        assert!(n <= self.capacity, "一次请求的令牌数不能超过桶容量");
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                self.refill(&mut state);
+                if state.tokens >= n as f64 {
+                    state.tokens -= n as f64;
+                    return;
+                }
+                // 按补充速率计算还差多少时间能攒够令牌
+                Duration::from_secs_f64((n as f64 - state.tokens) / self.refill_per_sec)
+            };
+            sleep(wait).await;
+        }
+    }
+
+    /// 尝试立即取走`n`个令牌，令牌不足时返回false且不等待
+    pub fn try_acquire(&self, n: u32) -> bool {
+        let mut state = self.state.lock().unwrap();
+        self.refill(&mut state);
+        if state.tokens >= n as f64 {
+            state.tokens -= n as f64;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// 当前可用的令牌数（向下取整）
+    pub fn available(&self) -> u32 {
+        let mut state = self.state.lock().unwrap();
+        self.refill(&mut state);
+        state.tokens as u32
+    }
+
+    /// 根据距离上次补充经过的时间补充令牌，不超过容量
+    fn refill(&self, state: &mut BucketState) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.refill_per_sec).min(self.capacity as f64);
+        state.last_refill = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_acquisitions_follow_refill_rate() {
+        // 容量1，每秒20个令牌：第一次立即成功，之后每次约等待50ms
+        let limiter = TokenBucket::new(1, 20.0);
+        let start = Instant::now();
+        for _ in 0..5 {
+            limiter.acquire(1).await;
+        }
+        // 时钟是暂停的，等待时间不受机器负载影响：4次补充各50ms
+        let elapsed = start.elapsed();
+        assert!(
+            elapsed >= Duration::from_millis(200),
+            "耗时过短: {:?}",
+            elapsed
+        );
+        assert!(
+            elapsed < Duration::from_millis(210),
+            "耗时过长: {:?}",
+            elapsed
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_burst_up_to_capacity_is_immediate() {
+        let limiter = TokenBucket::new(10, 1.0);
+        let start = Instant::now();
+        limiter.acquire(4).await;
+        limiter.acquire(6).await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        assert!(!limiter.try_acquire(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_clones_share_bucket() {
+        let limiter = TokenBucket::new(50, 100.0);
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let limiter = limiter.clone();
+                tokio::spawn(async move {
+                    for _ in 0..25 {
+                        limiter.acquire(1).await;
+                    }
+                })
+            })
+            .collect();
+
+        let start = Instant::now();
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        // 共100个令牌：50个来自满桶，另外50个需要以100/s的速率补充约0.5秒
+        let elapsed = start.elapsed();
+        assert!(
+            elapsed >= Duration::from_millis(490),
+            "耗时过短: {:?}",
+            elapsed
+        );
+        assert!(
+            elapsed < Duration::from_millis(550),
+            "耗时过长: {:?}",
+            elapsed
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "不能超过桶容量")]
+    fn test_acquire_more_than_capacity_panics() {
+        let limiter = TokenBucket::new(1, 1.0);
+        futures::executor::block_on(limiter.acquire(2));
+    }
+}
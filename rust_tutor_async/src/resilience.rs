@@ -0,0 +1,348 @@
+//! 容错
+//!
+//! 示例7演示了失败后的降级逻辑，但每次请求仍然会先去调用已经不可用的服务。
+//! [`CircuitBreaker`]（熔断器）在连续失败达到阈值后直接拒绝请求，
+//! 冷却一段时间后再放行一次试探请求，根据结果决定恢复还是继续熔断。
+
+use std::fmt;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// 熔断器的状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// 正常状态，请求直接放行
+    Closed,
+    /// 熔断状态，请求立即被拒绝
+    Open,
+    /// 冷却结束，放行一次试探请求
+    HalfOpen,
+}
+
+/// 通过熔断器调用时的错误
+#[derive(Debug, PartialEq, Eq)]
+pub enum CircuitError<E> {
+    /// 熔断器处于打开状态，操作没有被执行
+    Open,
+    /// 操作被执行但返回了错误
+    Inner(E),
+}
+
+impl<E: fmt::Display> fmt::Display for CircuitError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitError::Open => write!(f, "熔断器已打开，请求被拒绝"),
+            CircuitError::Inner(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for CircuitError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CircuitError::Open => None,
+            CircuitError::Inner(e) => Some(e),
+        }
+    }
+}
+
+/// 熔断器
+///
+/// ```
+/// use std::time::Duration;
+/// use rust_tutor_async::resilience::{CircuitBreaker, CircuitError, CircuitState};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let breaker = CircuitBreaker::new(2, Duration::from_secs(30));
+/// for _ in 0..2 {
+///     let _ = breaker.call(|| async { Err::<(), _>("服务不可用") }).await;
+/// }
+/// assert_eq!(breaker.state(), CircuitState::Open);
+///
+/// // 打开期间操作不会被执行
+/// let result = breaker.call(|| async { Ok::<_, &str>("不会执行") }).await;
+/// assert_eq!(result, Err(CircuitError::Open));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    inner: Mutex<BreakerInner>,
+}
+
+#[derive(Debug)]
+struct BreakerInner {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    // 半开状态下是否已经有一个试探请求在执行
+    probe_in_flight: bool,
+}
+
+impl CircuitBreaker {
+    /// 连续失败`failure_threshold`次后熔断，熔断`cooldown`后进入半开状态
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            inner: Mutex::new(BreakerInner {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                opened_at: None,
+                probe_in_flight: false,
+            }),
+        }
+    }
+
+    /// 当前状态（冷却时间已过的打开状态会报告为半开）
+    pub fn state(&self) -> CircuitState {
+        let mut inner = self.inner.lock().unwrap();
+        self.advance(&mut inner);
+        inner.state
+    }
+
+    /// 通过熔断器执行异步操作
+    ///
+    /// - 关闭状态：执行操作，连续失败达到阈值后打开
+    /// - 打开状态：不执行操作，直接返回[`CircuitError::Open`]
+    /// - 半开状态：只放行一个试探请求，成功则关闭，失败则重新打开
+    pub async fn call<F, Fut, T, E>(&self, f: F) -> Result<T, CircuitError<E>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        // 试探请求的future可能在完成前被丢弃（例如外层超时），
+        // 守卫保证这种情况下也会清除试探标记，否则熔断器会永远拒绝请求
+        let mut probe = ProbeGuard {
+            inner: &self.inner,
+            active: false,
+        };
+        {
+            let mut inner = self.inner.lock().unwrap();
+            self.advance(&mut inner);
+            match inner.state {
+                CircuitState::Closed => {}
+                CircuitState::Open => return Err(CircuitError::Open),
+                CircuitState::HalfOpen if inner.probe_in_flight => {
+                    return Err(CircuitError::Open);
+                }
+                CircuitState::HalfOpen => {
+                    inner.probe_in_flight = true;
+                    probe.active = true;
+                }
+            }
+        }
+
+        let result = f().await;
+
+        let mut inner = self.inner.lock().unwrap();
+        // 操作已经完成，在持有锁的同时清除标记，守卫不需要再做什么
+        inner.probe_in_flight = false;
+        probe.active = false;
+        match result {
+            Ok(value) => {
+                inner.state = CircuitState::Closed;
+                inner.consecutive_failures = 0;
+                inner.opened_at = None;
+                Ok(value)
+            }
+            Err(e) => {
+                inner.consecutive_failures += 1;
+                if inner.state == CircuitState::HalfOpen
+                    || inner.consecutive_failures >= self.failure_threshold
+                {
+                    inner.state = CircuitState::Open;
+                    inner.opened_at = Some(Instant::now());
+                }
+                Err(CircuitError::Inner(e))
+            }
+        }
+    }
+
+    /// 打开状态下冷却时间已过，转入半开状态
+    fn advance(&self, inner: &mut BreakerInner) {
+        if inner.state == CircuitState::Open
+            && inner
+                .opened_at
+                .is_some_and(|opened_at| opened_at.elapsed() >= self.cooldown)
+        {
+            inner.state = CircuitState::HalfOpen;
+        }
+    }
+}
+
+/// 半开状态下的试探请求被取消时清除`probe_in_flight`
+struct ProbeGuard<'a> {
+    inner: &'a Mutex<BreakerInner>,
+    active: bool,
+}
+
+impl Drop for ProbeGuard<'_> {
+    fn drop(&mut self) {
+        if self.active {
+            // 状态保持半开，下一个请求可以重新试探
+            self.inner.lock().unwrap().probe_in_flight = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+    use tokio::time::{sleep, timeout};
+
+    /// 可以随时切换成功/失败的操作，并统计被调用的次数
+    struct Flaky {
+        failing: AtomicBool,
+        calls: AtomicU32,
+    }
+
+    impl Flaky {
+        fn new(failing: bool) -> Self {
+            Self {
+                failing: AtomicBool::new(failing),
+                calls: AtomicU32::new(0),
+            }
+        }
+
+        async fn run(&self) -> Result<&'static str, &'static str> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.failing.load(Ordering::SeqCst) {
+                Err("失败")
+            } else {
+                Ok("成功")
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_opens_after_threshold_and_short_circuits() {
+        let breaker = CircuitBreaker::new(3, Duration::from_secs(60));
+        let service = Flaky::new(true);
+
+        for _ in 0..2 {
+            assert_eq!(
+                breaker.call(|| service.run()).await,
+                Err(CircuitError::Inner("失败"))
+            );
+            assert_eq!(breaker.state(), CircuitState::Closed);
+        }
+        assert_eq!(
+            breaker.call(|| service.run()).await,
+            Err(CircuitError::Inner("失败"))
+        );
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        // 打开后不再调用底层服务
+        assert_eq!(
+            breaker.call(|| service.run()).await,
+            Err(CircuitError::Open)
+        );
+        assert_eq!(service.calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_half_open_success_closes() {
+        let breaker = CircuitBreaker::new(1, Duration::from_millis(30));
+        let service = Flaky::new(true);
+
+        let _ = breaker.call(|| service.run()).await;
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        // 时钟是暂停的，冷却时间的边界是精确的
+        sleep(Duration::from_millis(29)).await;
+        assert_eq!(breaker.state(), CircuitState::Open);
+        sleep(Duration::from_millis(1)).await;
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+        service.failing.store(false, Ordering::SeqCst);
+        assert_eq!(breaker.call(|| service.run()).await, Ok("成功"));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_half_open_failure_reopens() {
+        let breaker = CircuitBreaker::new(2, Duration::from_millis(30));
+        let service = Flaky::new(true);
+
+        let _ = breaker.call(|| service.run()).await;
+        let _ = breaker.call(|| service.run()).await;
+        sleep(Duration::from_millis(40)).await;
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+        // 半开状态下一次失败就重新打开，并重新开始冷却
+        assert_eq!(
+            breaker.call(|| service.run()).await,
+            Err(CircuitError::Inner("失败"))
+        );
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert_eq!(
+            breaker.call(|| service.run()).await,
+            Err(CircuitError::Open)
+        );
+        assert_eq!(service.calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_half_open_allows_single_probe() {
+        let breaker = CircuitBreaker::new(1, Duration::from_millis(10));
+        let _ = breaker.call(|| async { Err::<(), _>("失败") }).await;
+        sleep(Duration::from_millis(20)).await;
+
+        let probe = breaker.call(|| async {
+            sleep(Duration::from_millis(30)).await;
+            Ok::<_, &str>("试探成功")
+        });
+        let concurrent = async {
+            sleep(Duration::from_millis(5)).await;
+            breaker.call(|| async { Ok::<_, &str>("并发请求") }).await
+        };
+        let (probe, concurrent) = tokio::join!(probe, concurrent);
+
+        assert_eq!(probe, Ok("试探成功"));
+        assert_eq!(concurrent, Err(CircuitError::Open));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cancelled_probe_allows_another_probe() {
+        let breaker = CircuitBreaker::new(1, Duration::from_millis(10));
+        let _ = breaker.call(|| async { Err::<(), _>("失败") }).await;
+        sleep(Duration::from_millis(20)).await;
+
+        // 试探请求还没完成就因为超时被丢弃
+        let cancelled = timeout(
+            Duration::from_millis(10),
+            breaker.call(|| async {
+                sleep(Duration::from_secs(10)).await;
+                Ok::<_, &str>("不会完成")
+            }),
+        )
+        .await;
+        assert!(cancelled.is_err());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+        // 第二个试探请求仍然会被放行
+        assert_eq!(
+            breaker.call(|| async { Ok::<_, &str>("第二次试探") }).await,
+            Ok("第二次试探")
+        );
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_success_resets_failure_count() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+        let _ = breaker.call(|| async { Err::<(), _>("失败") }).await;
+        let _ = breaker.call(|| async { Ok::<_, &str>(()) }).await;
+        let _ = breaker.call(|| async { Err::<(), _>("失败") }).await;
+        // 失败不连续，没有达到阈值
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
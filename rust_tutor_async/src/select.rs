@@ -0,0 +1,105 @@
+//! 基于`select!`的通道组合
+//!
+//! 示例9的`biased_select_usage`在循环里直接写`biased`模式的`select!`，
+//! 而且任意一个通道关闭就退出循环，另一个通道中剩下的消息会被丢掉。
+//! [`priority_recv`]把这个模式封装起来：总是先取高优先级通道，两个通道都关闭后才结束。
+
+use tokio::sync::mpsc::Receiver;
+
+/// 从两个通道中接收下一条消息，高优先级通道中有消息时总是先返回它
+///
+/// 只有高优先级通道暂时为空时才会返回低优先级的消息。
+/// 一个通道关闭后继续从另一个通道接收，两个通道都关闭且取空后返回`None`。
+///
+/// ```
+/// use rust_tutor_async::select::priority_recv;
+/// use tokio::sync::mpsc;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let (high_tx, mut high) = mpsc::channel(4);
+/// let (low_tx, mut low) = mpsc::channel(4);
+/// low_tx.send("低").await.unwrap();
+/// high_tx.send("高").await.unwrap();
+/// drop((high_tx, low_tx));
+///
+/// assert_eq!(priority_recv(&mut high, &mut low).await, Some("高"));
+/// assert_eq!(priority_recv(&mut high, &mut low).await, Some("低"));
+/// assert_eq!(priority_recv(&mut high, &mut low).await, None);
+/// # }
+/// ```
+pub async fn priority_recv<T>(high: &mut Receiver<T>, low: &mut Receiver<T>) -> Option<T> {
+    tokio::select! {
+        // 按分支顺序检查，两个通道都有消息时一定先选中高优先级
+        biased;
+        // 模式不匹配（通道已关闭）的分支会被禁用，select!继续等待另一个分支
+        Some(message) = high.recv() => Some(message),
+        Some(message) = low.recv() => Some(message),
+        else => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+    use tokio::time::{sleep, timeout};
+
+    #[tokio::test]
+    async fn test_high_priority_first_when_both_ready() {
+        let (high_tx, mut high) = mpsc::channel(16);
+        let (low_tx, mut low) = mpsc::channel(16);
+        // 低优先级的消息先发送，并且数量更多
+        for i in 0..5 {
+            low_tx.send(format!("低{}", i)).await.unwrap();
+        }
+        for i in 0..3 {
+            high_tx.send(format!("高{}", i)).await.unwrap();
+        }
+
+        let mut received = Vec::new();
+        for _ in 0..8 {
+            received.push(priority_recv(&mut high, &mut low).await.unwrap());
+        }
+        assert_eq!(
+            received,
+            ["高0", "高1", "高2", "低0", "低1", "低2", "低3", "低4"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_keeps_receiving_after_one_channel_closes() {
+        let (high_tx, mut high) = mpsc::channel(4);
+        let (low_tx, mut low) = mpsc::channel(4);
+        drop(high_tx);
+
+        tokio::spawn(async move {
+            sleep(Duration::from_millis(20)).await;
+            low_tx.send(1).await.unwrap();
+            low_tx.send(2).await.unwrap();
+        });
+
+        assert_eq!(priority_recv(&mut high, &mut low).await, Some(1));
+        assert_eq!(priority_recv(&mut high, &mut low).await, Some(2));
+        // 两个通道都已关闭并取空
+        let last = timeout(Duration::from_secs(1), priority_recv(&mut high, &mut low)).await;
+        assert_eq!(last, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_waits_for_either_channel() {
+        let (high_tx, mut high) = mpsc::channel::<&str>(4);
+        let (_low_tx, mut low) = mpsc::channel::<&str>(4);
+
+        // 两个通道都为空时等待，先到达的消息先返回
+        tokio::spawn(async move {
+            sleep(Duration::from_millis(20)).await;
+            high_tx.send("迟到的高优先级").await.unwrap();
+        });
+        assert_eq!(
+            priority_recv(&mut high, &mut low).await,
+            Some("迟到的高优先级")
+        );
+    }
+}
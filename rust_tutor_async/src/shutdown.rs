@@ -0,0 +1,182 @@
+//! 优雅关闭协调器
+//!
+//! 示例9中的每个循环都各自创建`oneshot`通道来传递关闭信号，
+//! 而`oneshot`只能通知一个接收者。[`Shutdown`]可以发放任意多个
+//! [`ShutdownListener`]，调用一次[`Shutdown::trigger`]就能通知所有监听者。
+//!
+//! 关闭状态会一直保留：触发之后才创建或才开始等待的监听者也会立即返回，
+//! 不会像`broadcast`通道那样错过已经发出的消息。
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use tokio::sync::Notify;
+
+#[derive(Debug, Default)]
+struct ShutdownState {
+    triggered: AtomicBool,
+    notify: Notify,
+}
+
+/// 关闭信号的发起方
+///
+/// ```
+/// use rust_tutor_async::shutdown::Shutdown;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let shutdown = Shutdown::new();
+/// let listener = shutdown.listener();
+///
+/// let worker = tokio::spawn(async move {
+///     tokio::select! {
+///         _ = std::future::pending::<()>() => {}
+///         _ = listener.recv() => println!("收到关闭信号"),
+///     }
+/// });
+///
+/// shutdown.trigger();
+/// worker.await.unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Shutdown {
+    state: Arc<ShutdownState>,
+}
+
+impl Shutdown {
+    /// 创建尚未触发的关闭协调器
+    pub fn new() -> Self {
+        Shutdown::default()
+    }
+
+    /// 创建一个新的监听者
+    pub fn listener(&self) -> ShutdownListener {
+        ShutdownListener {
+            state: Arc::clone(&self.state),
+        }
+    }
+
+    /// 触发关闭，唤醒所有正在等待的监听者（重复调用没有额外效果）
+    pub fn trigger(&self) {
+        if !self.state.triggered.swap(true, Ordering::SeqCst) {
+            self.state.notify.notify_waiters();
+        }
+    }
+
+    /// 是否已经触发关闭
+    pub fn is_triggered(&self) -> bool {
+        self.state.triggered.load(Ordering::SeqCst)
+    }
+}
+
+/// 关闭信号的接收方，通常在`select!`中与正常工作一起等待
+#[derive(Debug, Clone)]
+pub struct ShutdownListener {
+    state: Arc<ShutdownState>,
+}
+
+impl ShutdownListener {
+    /// 等待关闭信号；如果已经触发则立即返回
+    pub async fn recv(&self) {
+        loop {
+            // 先创建Notified再检查标志：即使trigger恰好发生在两者之间，
+            // notify_waiters也会唤醒这个已经创建的Notified，不会丢失通知
+            let notified = self.state.notify.notified();
+            if self.is_shutdown() {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// 是否已经收到关闭信号（不等待）
+    pub fn is_shutdown(&self) -> bool {
+        self.state.triggered.load(Ordering::SeqCst)
+    }
+}
+
+/// 等待Ctrl-C或手动触发，两者先到者生效
+///
+/// 收到Ctrl-C时会调用[`Shutdown::trigger`]通知所有监听者。
+/// 如果无法注册信号处理器，则只等待手动触发。
+pub async fn wait_for_signal(shutdown: &Shutdown) {
+    let listener = shutdown.listener();
+    tokio::select! {
+        result = tokio::signal::ctrl_c() => match result {
+            Ok(()) => shutdown.trigger(),
+            Err(_) => listener.recv().await,
+        },
+        _ = listener.recv() => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::time::Duration;
+    use tokio::time::{interval, sleep, timeout};
+
+    #[tokio::test]
+    async fn test_trigger_stops_all_workers() {
+        let shutdown = Shutdown::new();
+        let ticks = Arc::new(AtomicUsize::new(0));
+
+        let workers: Vec<_> = (0..5)
+            .map(|_| {
+                let listener = shutdown.listener();
+                let ticks = Arc::clone(&ticks);
+                tokio::spawn(async move {
+                    let mut ticker = interval(Duration::from_millis(5));
+                    loop {
+                        tokio::select! {
+                            _ = ticker.tick() => {
+                                ticks.fetch_add(1, Ordering::SeqCst);
+                            }
+                            _ = listener.recv() => break,
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        sleep(Duration::from_millis(30)).await;
+        shutdown.trigger();
+
+        for worker in workers {
+            timeout(Duration::from_secs(1), worker)
+                .await
+                .expect("worker should exit after shutdown")
+                .unwrap();
+        }
+        assert!(ticks.load(Ordering::SeqCst) > 0);
+        assert!(shutdown.is_triggered());
+    }
+
+    #[tokio::test]
+    async fn test_listener_created_after_trigger_returns_immediately() {
+        let shutdown = Shutdown::new();
+        shutdown.trigger();
+
+        let listener = shutdown.listener();
+        assert!(listener.is_shutdown());
+        timeout(Duration::from_millis(100), listener.recv())
+            .await
+            .expect("already triggered");
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_signal_returns_on_manual_trigger() {
+        let shutdown = Shutdown::new();
+        let trigger = shutdown.clone();
+        tokio::spawn(async move {
+            sleep(Duration::from_millis(20)).await;
+            trigger.trigger();
+        });
+
+        timeout(Duration::from_secs(1), wait_for_signal(&shutdown))
+            .await
+            .expect("manual trigger should end the wait");
+    }
+}
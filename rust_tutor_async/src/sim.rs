@@ -0,0 +1,196 @@
+//! 模拟数据源
+//!
+//! 示例8中的`real_time_data_stream`用`sin`函数假装传感器噪声，读数每次运行都完全一样，
+//! 高温、低温的阈值也写死在处理循环里。[`sensor_stream`]用`rand`产生真正的随机抖动，
+//! 阈值放在[`SensorConfig`]中；固定随机种子时可以得到可重复的读数，方便测试。
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::stream::{self, Stream};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use tokio::time::sleep;
+
+/// 读数超出正常范围的类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anomaly {
+    /// 高于`high_threshold`
+    High,
+    /// 低于`low_threshold`
+    Low,
+}
+
+/// 模拟传感器的配置
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensorConfig {
+    /// 读数的基准值
+    pub base_value: f64,
+    /// 噪声幅度，读数在`base_value ± noise_amplitude`范围内均匀分布
+    pub noise_amplitude: f64,
+    /// 产生的读数数量
+    pub count: usize,
+    /// 每个读数之前等待的时间
+    pub interval: Duration,
+    /// 低于这个值的读数视为异常
+    pub low_threshold: f64,
+    /// 高于这个值的读数视为异常
+    pub high_threshold: f64,
+    /// 随机种子；为`None`时每次运行的读数都不同
+    pub seed: Option<u64>,
+}
+
+impl SensorConfig {
+    /// 检查读数是否超出阈值
+    pub fn check(&self, reading: f64) -> Option<Anomaly> {
+        if reading > self.high_threshold {
+            Some(Anomaly::High)
+        } else if reading < self.low_threshold {
+            Some(Anomaly::Low)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for SensorConfig {
+    /// 与示例8相同：基准20°C，每200ms一个读数，共10个，高于25°C或低于15°C报警
+    fn default() -> Self {
+        SensorConfig {
+            base_value: 20.0,
+            noise_amplitude: 6.0,
+            count: 10,
+            interval: Duration::from_millis(200),
+            low_threshold: 15.0,
+            high_threshold: 25.0,
+            seed: None,
+        }
+    }
+}
+
+/// 按配置产生`(时间戳, 读数)`的流，产生`config.count`个读数后结束
+///
+/// ```
+/// use std::time::Duration;
+/// use futures::StreamExt;
+/// use rust_tutor_async::sim::{sensor_stream, SensorConfig};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let config = SensorConfig {
+///     interval: Duration::ZERO,
+///     seed: Some(7),
+///     ..SensorConfig::default()
+/// };
+/// let readings: Vec<_> = sensor_stream(config.clone()).collect().await;
+/// assert_eq!(readings.len(), 10);
+/// for (_, reading) in readings {
+///     assert!((reading - 20.0).abs() <= 6.0);
+///     if reading > 25.0 {
+///         assert!(config.check(reading).is_some());
+///     }
+/// }
+/// # }
+/// ```
+pub fn sensor_stream(config: SensorConfig) -> impl Stream<Item = (DateTime<Utc>, f64)> {
+    let rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    stream::unfold((config, rng, 0), |(config, mut rng, emitted)| async move {
+        if emitted >= config.count {
+            return None;
+        }
+        sleep(config.interval).await;
+        let amplitude = config.noise_amplitude.abs();
+        let noise = if amplitude > 0.0 {
+            rng.gen_range(-amplitude..=amplitude)
+        } else {
+            0.0
+        };
+        let reading = (Utc::now(), config.base_value + noise);
+        Some((reading, (config, rng, emitted + 1)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    fn fast_config(seed: u64) -> SensorConfig {
+        SensorConfig {
+            base_value: 100.0,
+            noise_amplitude: 2.5,
+            count: 200,
+            interval: Duration::ZERO,
+            low_threshold: 98.0,
+            high_threshold: 102.0,
+            seed: Some(seed),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_seeded_readings_stay_in_bounds() {
+        let readings: Vec<_> = sensor_stream(fast_config(42)).collect().await;
+        assert_eq!(readings.len(), 200);
+        for (_, reading) in &readings {
+            assert!(
+                (97.5..=102.5).contains(reading),
+                "读数超出范围: {}",
+                reading
+            );
+        }
+        // 时间戳不会倒退
+        assert!(readings.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+
+        // 200个均匀分布的读数中，不可能全部落在同一个值上
+        let first = readings[0].1;
+        assert!(readings.iter().any(|(_, reading)| *reading != first));
+    }
+
+    #[tokio::test]
+    async fn test_same_seed_gives_same_readings() {
+        let values = |seed| async move {
+            sensor_stream(fast_config(seed))
+                .map(|(_, reading)| reading)
+                .collect::<Vec<_>>()
+                .await
+        };
+        assert_eq!(values(1).await, values(1).await);
+        assert_ne!(values(1).await, values(2).await);
+    }
+
+    #[tokio::test]
+    async fn test_anomaly_thresholds() {
+        let config = fast_config(3);
+        assert_eq!(config.check(100.0), None);
+        assert_eq!(config.check(102.1), Some(Anomaly::High));
+        assert_eq!(config.check(97.9), Some(Anomaly::Low));
+
+        // 噪声幅度超过阈值范围，200个读数中两种异常都会出现
+        let anomalies: Vec<_> = sensor_stream(config.clone())
+            .filter_map(|(_, reading)| futures::future::ready(config.check(reading)))
+            .collect()
+            .await;
+        assert!(anomalies.contains(&Anomaly::High));
+        assert!(anomalies.contains(&Anomaly::Low));
+    }
+
+    #[tokio::test]
+    async fn test_zero_noise_and_zero_count() {
+        let config = SensorConfig {
+            noise_amplitude: 0.0,
+            count: 3,
+            ..fast_config(0)
+        };
+        let readings: Vec<f64> = sensor_stream(config).map(|(_, r)| r).collect().await;
+        assert_eq!(readings, vec![100.0; 3]);
+
+        let empty = SensorConfig {
+            count: 0,
+            ..fast_config(0)
+        };
+        assert_eq!(sensor_stream(empty).count().await, 0);
+    }
+}
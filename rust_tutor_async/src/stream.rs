@@ -0,0 +1,716 @@
+//! 流（Stream）工具
+//!
+//! 对应示例8。这里的类型和组合器都直接实现`Stream`，
+//! 可以和`futures::StreamExt`提供的其他组合器自由搭配。
+//!
+//! 需要自己保存状态的组合器把上游流放在`Pin<Box<S>>`中，
+//! 这样组合器本身是`Unpin`的，实现`poll_next`时不需要处理pin投影。
+
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::future::{self, Future, Pending};
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
+
+use futures::{Stream, StreamExt};
+use tokio::time::error::Elapsed;
+use tokio::time::{sleep, timeout, Instant, Sleep, Timeout};
+
+/// 每隔`delay`产生一个递增数字的流，共产生`max`个（0到max-1）
+///
+/// 示例8中最初的实现忽略了`delay`字段，`poll_next`总是立即返回，
+/// 调用者只能在外面手动`sleep`。这里在流内部保存一个`Sleep`计时器：
+/// 计时未到时返回`Poll::Pending`（计时器会负责注册waker），
+/// 到期后产生下一个值并重置计时器。
+///
+/// ```
+/// use std::time::Duration;
+/// use futures::StreamExt;
+/// use rust_tutor_async::stream::CounterStream;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let counts: Vec<u32> = CounterStream::new(3, Duration::from_millis(1)).collect().await;
+/// assert_eq!(counts, vec![0, 1, 2]);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CounterStream {
+    current: u32,
+    max: u32,
+    delay: Duration,
+    sleep: Pin<Box<Sleep>>,
+}
+
+impl CounterStream {
+    /// 创建计数器流，第一个值在`delay`之后产生
+    pub fn new(max: u32, delay: Duration) -> Self {
+        CounterStream {
+            current: 0,
+            max,
+            delay,
+            sleep: Box::pin(sleep(delay)),
+        }
+    }
+}
+
+impl Stream for CounterStream {
+    type Item = u32;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.current >= self.max {
+            return Poll::Ready(None);
+        }
+
+        // 计时未到时返回Pending，计时器到期后会唤醒当前任务
+        ready!(self.sleep.as_mut().poll(cx));
+
+        let current = self.current;
+        self.current += 1;
+        let next_deadline = Instant::now() + self.delay;
+        self.sleep.as_mut().reset(next_deadline);
+        Poll::Ready(Some(current))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.max - self.current.min(self.max)) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+/// 滑动窗口：每收到一个新元素，产生最近`size`个元素组成的窗口
+///
+/// 这是示例`WindowIterator`（同步迭代器）的异步版本。缓冲区攒满`size`个元素后
+/// 产生第一个窗口，之后每来一个元素窗口向后滑动一位。
+/// 元素总数少于`size`或`size`为0时不产生任何窗口。
+///
+/// ```
+/// use futures::{stream, StreamExt};
+/// use rust_tutor_async::stream::windows;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let result: Vec<Vec<i32>> = windows(stream::iter(1..=4), 2).collect().await;
+/// assert_eq!(result, vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+/// # }
+/// ```
+pub fn windows<S>(stream: S, size: usize) -> impl Stream<Item = Vec<S::Item>>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    let mut buffer = VecDeque::with_capacity(size);
+    stream.filter_map(move |item| {
+        if size == 0 {
+            return future::ready(None);
+        }
+        if buffer.len() == size {
+            buffer.pop_front();
+        }
+        buffer.push_back(item);
+
+        let window = (buffer.len() == size).then(|| buffer.iter().cloned().collect());
+        future::ready(window)
+    })
+}
+
+/// 去掉连续重复的元素：与上一个产生的元素相等的元素会被丢弃
+///
+/// 这是`slice::dedup`的流式版本，只比较相邻元素，
+/// 不相邻的重复元素仍然会原样通过。
+///
+/// ```
+/// use futures::{stream, StreamExt};
+/// use rust_tutor_async::stream::dedup;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let result: Vec<char> = dedup(stream::iter("aabbba".chars())).collect().await;
+/// assert_eq!(result, vec!['a', 'b', 'a']);
+/// # }
+/// ```
+pub fn dedup<S>(stream: S) -> impl Stream<Item = S::Item>
+where
+    S: Stream,
+    S::Item: PartialEq + Clone,
+{
+    let mut last: Option<S::Item> = None;
+    stream.filter_map(move |item| {
+        if last.as_ref() == Some(&item) {
+            return future::ready(None);
+        }
+        last = Some(item.clone());
+        future::ready(Some(item))
+    })
+}
+
+/// 按数量或时间分批：攒够`max_items`个元素，或者距离本批第一个元素
+/// 到达已经过去`max_delay`，两者先满足哪个就产生一批
+///
+/// 上游结束时，缓冲区里剩余的元素会作为最后一批产生。
+/// `max_items`为0时按1处理。
+///
+/// ```
+/// use std::time::Duration;
+/// use futures::{stream, StreamExt};
+/// use rust_tutor_async::stream::batch_timeout;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let batches: Vec<Vec<i32>> = batch_timeout(stream::iter(1..=5), 2, Duration::from_secs(1))
+///     .collect()
+///     .await;
+/// assert_eq!(batches, vec![vec![1, 2], vec![3, 4], vec![5]]);
+/// # }
+/// ```
+pub fn batch_timeout<S: Stream>(
+    stream: S,
+    max_items: usize,
+    max_delay: Duration,
+) -> BatchTimeout<S> {
+    BatchTimeout {
+        stream: Box::pin(stream),
+        buffer: Vec::new(),
+        max_items: max_items.max(1),
+        max_delay,
+        deadline: None,
+        upstream_done: false,
+    }
+}
+
+/// [`batch_timeout`]返回的流
+#[derive(Debug)]
+pub struct BatchTimeout<S: Stream> {
+    stream: Pin<Box<S>>,
+    buffer: Vec<S::Item>,
+    max_items: usize,
+    max_delay: Duration,
+    // 本批第一个元素到达时启动的计时器
+    deadline: Option<Pin<Box<Sleep>>>,
+    upstream_done: bool,
+}
+
+// 上游流已经被装箱固定，缓冲区里的元素从不被pin住，所以整体可以安全地移动
+impl<S: Stream> Unpin for BatchTimeout<S> {}
+
+impl<S: Stream> BatchTimeout<S> {
+    fn take_batch(&mut self) -> Vec<S::Item> {
+        self.deadline = None;
+        std::mem::take(&mut self.buffer)
+    }
+}
+
+impl<S: Stream> Stream for BatchTimeout<S> {
+    type Item = Vec<S::Item>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // 先尽可能多地从上游取出已经就绪的元素
+        while !self.upstream_done {
+            match self.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if self.buffer.is_empty() {
+                        self.deadline = Some(Box::pin(sleep(self.max_delay)));
+                    }
+                    self.buffer.push(item);
+                    if self.buffer.len() >= self.max_items {
+                        return Poll::Ready(Some(self.take_batch()));
+                    }
+                }
+                Poll::Ready(None) => self.upstream_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        if self.upstream_done {
+            // 上游结束：把剩余元素作为最后一批
+            return if self.buffer.is_empty() {
+                Poll::Ready(None)
+            } else {
+                Poll::Ready(Some(self.take_batch()))
+            };
+        }
+
+        // 上游暂时没有新元素，检查本批是否已经等待太久
+        if let Some(deadline) = self.deadline.as_mut() {
+            if deadline.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(Some(self.take_batch()));
+            }
+        }
+        Poll::Pending
+    }
+}
+
+/// 同时轮询多个同类型的流，哪个流先有元素就先产生哪个
+///
+/// 与`chain`依次消费每个流不同，这里所有流并发推进，
+/// 单个流内部的顺序保持不变。所有流都结束后合并流才结束。
+/// 这相当于把`select!`的思路推广到任意数量的流。
+///
+/// ```
+/// use futures::{stream, StreamExt};
+/// use rust_tutor_async::stream::merge_all;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let mut items: Vec<i32> = merge_all(vec![stream::iter(vec![1, 2]), stream::iter(vec![3])])
+///     .collect()
+///     .await;
+/// items.sort();
+/// assert_eq!(items, vec![1, 2, 3]);
+/// # }
+/// ```
+pub fn merge_all<S: Stream>(streams: Vec<S>) -> impl Stream<Item = S::Item> {
+    // select_all要求每个流都是Unpin，装箱固定后即可接受任意流
+    futures::stream::select_all(streams.into_iter().map(Box::pin))
+}
+
+/// 防抖：上游安静`quiet_period`之后才产生最近收到的元素
+///
+/// 连续快速到达的一串元素（间隔都小于`quiet_period`）只产生最后一个，
+/// 前面的元素被丢弃。每来一个新元素计时器都会重新开始。
+/// 上游结束时，还在等待的最后一个元素会立即产生。
+///
+/// ```
+/// use std::time::Duration;
+/// use futures::{stream, StreamExt};
+/// use rust_tutor_async::stream::debounce;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// // 所有元素同时到达，只保留最后一个
+/// let result: Vec<i32> = debounce(stream::iter(1..=5), Duration::from_millis(10))
+///     .collect()
+///     .await;
+/// assert_eq!(result, vec![5]);
+/// # }
+/// ```
+pub fn debounce<S: Stream>(stream: S, quiet_period: Duration) -> Debounce<S> {
+    Debounce {
+        stream: Box::pin(stream),
+        pending: None,
+        quiet_period,
+        sleep: Box::pin(sleep(quiet_period)),
+        upstream_done: false,
+    }
+}
+
+/// [`debounce`]返回的流
+#[derive(Debug)]
+pub struct Debounce<S: Stream> {
+    stream: Pin<Box<S>>,
+    // 还在等待安静期结束的最新元素
+    pending: Option<S::Item>,
+    quiet_period: Duration,
+    // 最近一个元素到达时重置的计时器，只在pending有值时有意义
+    sleep: Pin<Box<Sleep>>,
+    upstream_done: bool,
+}
+
+// 与BatchTimeout相同：上游流已经装箱固定，等待中的元素从不被pin住
+impl<S: Stream> Unpin for Debounce<S> {}
+
+impl<S: Stream> Stream for Debounce<S> {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // 取出所有已经就绪的元素，只保留最新的一个
+        while !self.upstream_done {
+            match self.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    self.pending = Some(item);
+                    let deadline = Instant::now() + self.quiet_period;
+                    self.sleep.as_mut().reset(deadline);
+                }
+                Poll::Ready(None) => self.upstream_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        if self.upstream_done {
+            // 上游结束后不会再有新元素打断，等待中的元素可以立即产生
+            return Poll::Ready(self.pending.take());
+        }
+
+        if self.pending.is_some() && self.sleep.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(self.pending.take());
+        }
+        Poll::Pending
+    }
+}
+
+/// 为每个元素设置超时：上游超过`duration`还没有产生下一个元素时，产生一个`Err(Elapsed)`
+///
+/// 计时从等待某个元素时上游第一次返回`Pending`开始，消费者处理上一个元素花的时间不计入。
+/// 超时之后不会丢弃上游正在准备的元素，而是继续等待它直到以`Ok`到达，
+/// 每个元素最多产生一个超时错误。
+///
+/// ```
+/// use std::time::Duration;
+/// use futures::{stream, StreamExt};
+/// use rust_tutor_async::stream::timeout_each;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let slow = stream::iter(1..=2).then(|i| async move {
+///     tokio::time::sleep(Duration::from_millis(if i == 2 { 50 } else { 0 })).await;
+///     i
+/// });
+/// let results: Vec<_> = timeout_each(slow, Duration::from_millis(30)).collect().await;
+/// assert_eq!(results.len(), 3);
+/// assert_eq!(results[0], Ok(1));
+/// assert!(results[1].is_err());
+/// assert_eq!(results[2], Ok(2));
+/// # }
+/// ```
+pub fn timeout_each<S: Stream>(stream: S, duration: Duration) -> TimeoutEach<S> {
+    TimeoutEach {
+        stream: Box::pin(stream),
+        duration,
+        timer: None,
+        timed_out: false,
+    }
+}
+
+/// [`timeout_each`]返回的流
+#[derive(Debug)]
+pub struct TimeoutEach<S> {
+    stream: Pin<Box<S>>,
+    duration: Duration,
+    // Elapsed没有公开的构造方法，用一个永远不会完成的future的Timeout来得到它。
+    // 等待当前元素时上游第一次返回Pending才开始计时，之前为None
+    timer: Option<Pin<Box<Timeout<Pending<Infallible>>>>>,
+    // 当前元素是否已经产生过超时错误
+    timed_out: bool,
+}
+
+fn new_timer(duration: Duration) -> Pin<Box<Timeout<Pending<Infallible>>>> {
+    Box::pin(timeout(duration, future::pending()))
+}
+
+impl<S: Stream> Stream for TimeoutEach<S> {
+    type Item = Result<S::Item, Elapsed>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.stream.as_mut().poll_next(cx) {
+            Poll::Ready(Some(item)) => {
+                self.timer = None;
+                self.timed_out = false;
+                return Poll::Ready(Some(Ok(item)));
+            }
+            Poll::Ready(None) => return Poll::Ready(None),
+            Poll::Pending => {}
+        }
+
+        // 这个元素已经报告过超时，只需继续等待它
+        if self.timed_out {
+            return Poll::Pending;
+        }
+        let duration = self.duration;
+        let timer = self.timer.get_or_insert_with(|| new_timer(duration));
+        let elapsed = match ready!(timer.as_mut().poll(cx)) {
+            Ok(never) => match never {},
+            Err(elapsed) => elapsed,
+        };
+        self.timer = None;
+        self.timed_out = true;
+        Poll::Ready(Some(Err(elapsed)))
+    }
+}
+
+/// 节流：相邻两个产生的元素之间至少间隔`min_interval`
+///
+/// 与[`debounce`]不同，太快到达的元素不会被丢弃，而是推迟到间隔满足之后再产生。
+/// 第一个元素立即产生；上游本身就比`min_interval`慢时不会额外等待。
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use futures::{stream, StreamExt};
+/// use rust_tutor_async::stream::throttle;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let start = Instant::now();
+/// let items: Vec<i32> = throttle(stream::iter(1..=3), Duration::from_millis(10))
+///     .collect()
+///     .await;
+/// assert_eq!(items, vec![1, 2, 3]);
+/// assert!(start.elapsed() >= Duration::from_millis(20));
+/// # }
+/// ```
+pub fn throttle<S: Stream>(stream: S, min_interval: Duration) -> Throttle<S> {
+    Throttle {
+        stream: Box::pin(stream),
+        pending: None,
+        min_interval,
+        sleep: Box::pin(tokio::time::sleep_until(Instant::now())),
+    }
+}
+
+/// [`throttle`]返回的流
+#[derive(Debug)]
+pub struct Throttle<S: Stream> {
+    stream: Pin<Box<S>>,
+    // 已经从上游取到、但间隔还没满足的元素
+    pending: Option<S::Item>,
+    min_interval: Duration,
+    // 下一个元素最早可以产生的时间，每产生一个元素向后推`min_interval`
+    sleep: Pin<Box<Sleep>>,
+}
+
+// 与Debounce相同：上游流已经装箱固定，等待中的元素从不被pin住
+impl<S: Stream> Unpin for Throttle<S> {}
+
+impl<S: Stream> Stream for Throttle<S> {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // 先取上游的元素再等待间隔，上游生产元素的时间和节流的等待时间可以重叠，
+        // 上游本身足够慢时计时器早已到期，不会额外增加等待
+        if self.pending.is_none() {
+            match ready!(self.stream.as_mut().poll_next(cx)) {
+                Some(item) => self.pending = Some(item),
+                None => return Poll::Ready(None),
+            }
+        }
+
+        // 间隔未满足时元素留在pending中，推迟而不是丢弃
+        ready!(self.sleep.as_mut().poll(cx));
+        let next_allowed = Instant::now() + self.min_interval;
+        self.sleep.as_mut().reset(next_allowed);
+        Poll::Ready(self.pending.take())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream;
+
+    #[tokio::test]
+    async fn test_counter_stream_delays_between_items() {
+        let delay = Duration::from_millis(20);
+        let start = std::time::Instant::now();
+        let counts: Vec<u32> = CounterStream::new(5, delay).collect().await;
+
+        assert_eq!(counts, vec![0, 1, 2, 3, 4]);
+        assert!(
+            start.elapsed() >= delay * 5,
+            "耗时过短: {:?}",
+            start.elapsed()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_counter_stream_is_pending_before_delay() {
+        let mut counter = CounterStream::new(1, Duration::from_secs(60));
+        // 计时未到时不会立即产生值
+        assert_eq!(futures::poll!(counter.next()), Poll::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_empty_counter_stream_ends_immediately() {
+        let mut counter = CounterStream::new(0, Duration::from_secs(60));
+        assert_eq!(counter.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_windows_slide_by_one() {
+        let result: Vec<Vec<i32>> = windows(stream::iter(vec![1, 2, 3, 4, 5]), 3)
+            .collect()
+            .await;
+        assert_eq!(result, vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]]);
+    }
+
+    #[tokio::test]
+    async fn test_windows_matches_slice_windows() {
+        let data: Vec<String> = ["a", "b", "c", "d"].iter().map(|s| s.to_string()).collect();
+        for size in 1..=5 {
+            let expected: Vec<Vec<String>> = data.windows(size).map(|w| w.to_vec()).collect();
+            let actual: Vec<Vec<String>> =
+                windows(stream::iter(data.clone()), size).collect().await;
+            assert_eq!(actual, expected, "窗口大小: {}", size);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_windows_of_zero_size_is_empty() {
+        let result: Vec<Vec<i32>> = windows(stream::iter(1..=3), 0).collect().await;
+        assert!(result.is_empty());
+    }
+
+    /// 按(等待毫秒数, 值)的列表依次产生元素的流
+    fn delayed<T>(items: Vec<(u64, T)>) -> impl Stream<Item = T> {
+        stream::iter(items).then(|(delay_ms, item)| async move {
+            sleep(Duration::from_millis(delay_ms)).await;
+            item
+        })
+    }
+
+    #[tokio::test]
+    async fn test_batch_timeout_full_batches() {
+        let batches: Vec<Vec<i32>> = batch_timeout(stream::iter(1..=6), 3, Duration::from_secs(10))
+            .collect()
+            .await;
+        assert_eq!(batches, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_batch_timeout_flushes_partial_batch_on_timeout() {
+        // 1和2连续到达，3在100ms之后才到达，超过了30ms的等待上限
+        let source = delayed(vec![(0, 1), (0, 2), (100, 3), (0, 4)]);
+        let start = Instant::now();
+        let mut batches = Box::pin(batch_timeout(source, 10, Duration::from_millis(30)));
+
+        assert_eq!(batches.next().await, Some(vec![1, 2]));
+        // 第一批在超时后立即产生，而不是等到元素3到达
+        assert_eq!(start.elapsed(), Duration::from_millis(30));
+        assert_eq!(batches.next().await, Some(vec![3, 4]));
+        assert_eq!(batches.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_batch_timeout_flushes_remainder_when_upstream_ends() {
+        let batches: Vec<Vec<i32>> = batch_timeout(stream::iter(1..=5), 3, Duration::from_secs(10))
+            .collect()
+            .await;
+        assert_eq!(batches, vec![vec![1, 2, 3], vec![4, 5]]);
+
+        let empty: Vec<Vec<i32>> =
+            batch_timeout(stream::iter(Vec::new()), 3, Duration::from_secs(10))
+                .collect()
+                .await;
+        assert!(empty.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_merge_all_interleaves_fast_and_slow_streams() {
+        // 快流在30/60/90/120ms产生元素，慢流在75/150ms产生元素
+        let fast = delayed(vec![(30, "f1"), (30, "f2"), (30, "f3"), (30, "f4")]).boxed();
+        let slow = delayed(vec![(75, "s1"), (75, "s2")]).boxed();
+        let merged: Vec<&str> = merge_all(vec![slow, fast]).collect().await;
+
+        assert_eq!(merged.len(), 6);
+        let position = |item| merged.iter().position(|x| *x == item).unwrap();
+        // 慢流的第一个元素出现在快流结束之前，说明两个流是交错产生的
+        assert!(position("s1") < position("f4"));
+        assert!(position("f1") < position("s1"));
+        // 每个流内部的顺序保持不变
+        assert!(position("f1") < position("f2") && position("f2") < position("f3"));
+        assert!(position("s1") < position("s2"));
+        assert_eq!(merged.last(), Some(&"s2"));
+    }
+
+    #[tokio::test]
+    async fn test_merge_all_empty() {
+        let merged: Vec<i32> = merge_all(Vec::<stream::Iter<std::vec::IntoIter<i32>>>::new())
+            .collect()
+            .await;
+        assert!(merged.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dedup_drops_consecutive_duplicates_only() {
+        let result: Vec<i32> = dedup(stream::iter(vec![1, 1, 2, 2, 2, 1, 3]))
+            .collect()
+            .await;
+        assert_eq!(result, vec![1, 2, 1, 3]);
+
+        let empty: Vec<i32> = dedup(stream::iter(Vec::<i32>::new())).collect().await;
+        assert!(empty.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_debounce_emits_last_item_of_each_burst() {
+        // 1、2、3间隔10ms连续到达，然后安静100ms；4、5同样是一串
+        let source = delayed(vec![(0, 1), (10, 2), (10, 3), (100, 4), (10, 5)]);
+        let start = Instant::now();
+        let mut debounced = debounce(source, Duration::from_millis(40));
+
+        assert_eq!(debounced.next().await, Some(3));
+        // 3在20ms到达，安静期结束后就产生，不需要等到4到达
+        assert_eq!(start.elapsed(), Duration::from_millis(60));
+
+        assert_eq!(debounced.next().await, Some(5));
+        assert_eq!(debounced.next().await, None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_debounce_keeps_spaced_out_items() {
+        // 每个元素之后都有足够长的安静期，全部保留
+        let source = delayed(vec![(0, 'a'), (50, 'b'), (50, 'c')]);
+        let result: Vec<char> = debounce(source, Duration::from_millis(20)).collect().await;
+        assert_eq!(result, vec!['a', 'b', 'c']);
+
+        let empty: Vec<i32> = debounce(stream::iter(Vec::<i32>::new()), Duration::from_millis(20))
+            .collect()
+            .await;
+        assert!(empty.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_timeout_each_reports_slow_item_and_continues() {
+        // 第3个元素需要100ms，是30ms超时的3倍多，但只报告一次超时
+        let source = delayed(vec![(0, 1), (0, 2), (100, 3), (0, 4), (10, 5)]);
+        let results: Vec<Result<i32, Elapsed>> = timeout_each(source, Duration::from_millis(30))
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 6);
+        assert_eq!(results[..2], [Ok(1), Ok(2)]);
+        assert!(results[2].is_err());
+        assert_eq!(results[3..], [Ok(3), Ok(4), Ok(5)]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_timeout_each_ignores_consumer_think_time() {
+        // 上游每个元素只需10ms，消费者每个元素处理100ms，处理时间不应该算作上游超时
+        let source = delayed(vec![(10, 1), (10, 2), (10, 3)]);
+        let mut guarded = timeout_each(source, Duration::from_millis(30));
+        let mut results = Vec::new();
+        while let Some(result) = guarded.next().await {
+            results.push(result);
+            sleep(Duration::from_millis(100)).await;
+        }
+        assert_eq!(results, vec![Ok(1), Ok(2), Ok(3)]);
+    }
+
+    #[tokio::test]
+    async fn test_timeout_each_without_slow_items() {
+        let results: Vec<Result<i32, Elapsed>> =
+            timeout_each(stream::iter(1..=3), Duration::from_millis(10))
+                .collect()
+                .await;
+        assert_eq!(results, vec![Ok(1), Ok(2), Ok(3)]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_throttle_spaces_out_fast_items() {
+        let min_interval = Duration::from_millis(25);
+        let mut throttled = throttle(stream::iter(1..=5), min_interval);
+
+        let mut emitted = Vec::new();
+        while let Some(item) = throttled.next().await {
+            emitted.push((item, Instant::now()));
+        }
+
+        // 元素一个都没有丢失
+        let items: Vec<i32> = emitted.iter().map(|(item, _)| *item).collect();
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+        for pair in emitted.windows(2) {
+            let gap = pair[1].1 - pair[0].1;
+            assert!(gap >= min_interval, "间隔过短: {:?}", gap);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_throttle_does_not_delay_slow_upstream() {
+        // 上游本身每50ms一个元素，比10ms的最小间隔慢，节流不应该额外增加等待
+        let source = delayed(vec![(0, 1), (50, 2), (50, 3)]);
+        let start = Instant::now();
+        let items: Vec<i32> = throttle(source, Duration::from_millis(10)).collect().await;
+        assert_eq!(items, vec![1, 2, 3]);
+        // 时钟是暂停的，只在所有任务都在等待时才前进，耗时正好是上游的100ms
+        assert_eq!(start.elapsed(), Duration::from_millis(100));
+    }
+}
@@ -0,0 +1,148 @@
+//! 通用异步辅助函数
+
+use std::future::Future;
+use std::time::Duration;
+
+use rand::Rng;
+use tokio::time::sleep;
+
+/// 带指数退避的重试
+///
+/// 最多调用`operation`共`max_attempts`次（至少1次），每次传入从1开始的尝试序号。
+/// 两次尝试之间等待的时间从`initial_delay`开始，每次失败后翻倍；
+/// 如果提供了`jitter`，每次等待还会额外加上`[0, jitter)`之间的随机时长，
+/// 避免大量客户端在同一时刻集中重试。
+///
+/// 全部尝试失败时返回最后一次尝试得到的错误。
+///
+/// ```
+/// use std::time::Duration;
+/// use rust_tutor_async::util::retry_with_backoff;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let result: Result<u32, String> = retry_with_backoff(
+///     |attempt| async move {
+///         if attempt < 3 { Err(format!("第{}次失败", attempt)) } else { Ok(attempt) }
+///     },
+///     5,
+///     Duration::from_millis(1),
+///     None,
+/// )
+/// .await;
+/// assert_eq!(result, Ok(3));
+/// # }
+/// ```
+pub async fn retry_with_backoff<F, Fut, T, E>(
+    mut operation: F,
+    max_attempts: u32,
+    initial_delay: Duration,
+    jitter: Option<Duration>,
+) -> Result<T, E>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let max_attempts = max_attempts.max(1);
+    let mut delay = initial_delay;
+    let mut attempt = 1;
+
+    loop {
+        match operation(attempt).await {
+            Ok(result) => return Ok(result),
+            // 最后一次尝试也失败了，直接返回这次的错误
+            Err(e) if attempt == max_attempts => return Err(e),
+            Err(_) => {
+                sleep(delay + jitter.map_or(Duration::ZERO, random_jitter)).await;
+                delay *= 2; // 指数退避
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// 生成`[0, max)`之间的随机时长
+fn random_jitter(max: Duration) -> Duration {
+    let max_nanos = max.as_nanos() as u64;
+    // gen_range不接受空区间
+    if max_nanos == 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_nanos(rand::thread_rng().gen_range(0..max_nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[tokio::test]
+    async fn test_retry_runs_exactly_max_attempts() {
+        let calls = AtomicU32::new(0);
+        let result: Result<(), String> = retry_with_backoff(
+            |attempt| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async move { Err(format!("第{}次失败", attempt)) }
+            },
+            3,
+            Duration::from_millis(1),
+            None,
+        )
+        .await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        // 返回的是最后一次失败的错误，而不是额外多调用一次得到的错误
+        assert_eq!(result, Err("第3次失败".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_retry_stops_after_success() {
+        let calls = AtomicU32::new(0);
+        let result: Result<u32, String> = retry_with_backoff(
+            |attempt| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if attempt < 2 {
+                        Err("暂时失败".to_string())
+                    } else {
+                        Ok(attempt)
+                    }
+                }
+            },
+            5,
+            Duration::from_millis(1),
+            Some(Duration::from_millis(2)),
+        )
+        .await;
+
+        assert_eq!(result, Ok(2));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_zero_attempts_still_calls_once() {
+        let calls = AtomicU32::new(0);
+        let result: Result<(), &str> = retry_with_backoff(
+            |_| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async { Err("失败") }
+            },
+            0,
+            Duration::from_millis(1),
+            None,
+        )
+        .await;
+
+        assert_eq!(result, Err("失败"));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_random_jitter_within_bounds() {
+        let max = Duration::from_millis(10);
+        for _ in 0..100 {
+            assert!(random_jitter(max) < max);
+        }
+        assert_eq!(random_jitter(Duration::ZERO), Duration::ZERO);
+    }
+}
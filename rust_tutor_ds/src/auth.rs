@@ -0,0 +1,221 @@
+//! 基于集合的用户权限和角色
+//!
+//! 来自HashSet教程（`08_hashset.rs`）中的权限系统。示例中的用户只能逐个授予权限，
+//! 这里增加了把一组权限打包起来的[`Role`]：用户的有效权限是直接授予的权限
+//! 与所有角色权限的并集，撤销角色时只会失去其他来源都没有提供的权限。
+
+use std::collections::HashSet;
+
+/// 角色：有名字的一组权限
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Role {
+    pub name: String,
+    pub permissions: HashSet<String>,
+}
+
+impl Role {
+    /// 创建带有`permissions`的角色
+    pub fn new(name: &str, permissions: &[&str]) -> Self {
+        Role {
+            name: name.to_string(),
+            permissions: permissions.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    /// 角色是否包含权限`permission`
+    pub fn has_permission(&self, permission: &str) -> bool {
+        self.permissions.contains(permission)
+    }
+}
+
+/// 用户：可以直接授予权限，也可以授予角色
+///
+/// ```
+/// use rust_data_structures_tutorial::auth::{Role, User};
+///
+/// let editor = Role::new("editor", &["read", "write"]);
+/// let mut user = User::new("alice");
+/// user.grant_permission("read");
+/// user.grant_role(editor);
+/// assert!(user.has_permission("write"));
+///
+/// // 撤销角色后，直接授予的read仍然保留
+/// user.revoke_role("editor");
+/// assert!(user.has_permission("read"));
+/// assert!(!user.has_permission("write"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct User {
+    pub name: String,
+    permissions: HashSet<String>,
+    roles: Vec<Role>,
+}
+
+impl User {
+    /// 创建没有任何权限的用户
+    pub fn new(name: &str) -> Self {
+        User {
+            name: name.to_string(),
+            permissions: HashSet::new(),
+            roles: Vec::new(),
+        }
+    }
+
+    /// 直接授予权限
+    pub fn grant_permission(&mut self, permission: &str) {
+        self.permissions.insert(permission.to_string());
+    }
+
+    /// 撤销直接授予的权限，返回之前是否直接拥有它
+    ///
+    /// 角色提供的同名权限不受影响。
+    pub fn revoke_permission(&mut self, permission: &str) -> bool {
+        self.permissions.remove(permission)
+    }
+
+    /// 授予角色；已经拥有同名角色时用新的角色替换旧的
+    pub fn grant_role(&mut self, role: Role) {
+        self.roles.retain(|existing| existing.name != role.name);
+        self.roles.push(role);
+    }
+
+    /// 撤销名为`name`的角色，返回被撤销的角色
+    pub fn revoke_role(&mut self, name: &str) -> Option<Role> {
+        let index = self.roles.iter().position(|role| role.name == name)?;
+        Some(self.roles.remove(index))
+    }
+
+    /// 是否拥有名为`name`的角色
+    pub fn has_role(&self, name: &str) -> bool {
+        self.roles.iter().any(|role| role.name == name)
+    }
+
+    /// 当前拥有的角色，按授予顺序排列
+    pub fn roles(&self) -> &[Role] {
+        &self.roles
+    }
+
+    /// 直接授予的权限（不含角色提供的权限）
+    pub fn direct_permissions(&self) -> &HashSet<String> {
+        &self.permissions
+    }
+
+    /// 有效权限：直接授予的权限与所有角色权限的并集
+    pub fn effective_permissions(&self) -> HashSet<String> {
+        let mut effective = self.permissions.clone();
+        for role in &self.roles {
+            effective.extend(role.permissions.iter().cloned());
+        }
+        effective
+    }
+
+    /// 是否拥有权限`permission`（直接授予或来自任一角色）
+    pub fn has_permission(&self, permission: &str) -> bool {
+        self.permissions.contains(permission)
+            || self
+                .roles
+                .iter()
+                .any(|role| role.has_permission(permission))
+    }
+
+    /// 是否拥有`required`中的所有权限
+    pub fn has_all_permissions(&self, required: &HashSet<String>) -> bool {
+        required
+            .iter()
+            .all(|permission| self.has_permission(permission))
+    }
+
+    /// 两个用户共同的有效权限
+    pub fn common_permissions(&self, other: &User) -> HashSet<String> {
+        self.effective_permissions()
+            .intersection(&other.effective_permissions())
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(permissions: &[&str]) -> HashSet<String> {
+        permissions.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn test_direct_permissions() {
+        let mut user = User::new("bob");
+        user.grant_permission("read");
+        user.grant_permission("write");
+        assert!(user.has_permission("read"));
+        assert!(user.has_all_permissions(&set(&["read", "write"])));
+        assert!(!user.has_all_permissions(&set(&["read", "delete"])));
+
+        assert!(user.revoke_permission("write"));
+        assert!(!user.revoke_permission("write"));
+        assert_eq!(user.effective_permissions(), set(&["read"]));
+    }
+
+    #[test]
+    fn test_role_grant_adds_permissions() {
+        let mut user = User::new("carol");
+        user.grant_permission("read");
+        user.grant_role(Role::new("admin", &["read", "write", "delete"]));
+
+        assert!(user.has_role("admin"));
+        assert_eq!(user.direct_permissions(), &set(&["read"]));
+        assert_eq!(
+            user.effective_permissions(),
+            set(&["read", "write", "delete"])
+        );
+        assert!(user.has_all_permissions(&set(&["write", "delete"])));
+    }
+
+    #[test]
+    fn test_revoke_role_removes_only_unique_permissions() {
+        let mut user = User::new("dave");
+        user.grant_permission("read");
+        user.grant_role(Role::new("editor", &["read", "write", "publish"]));
+        user.grant_role(Role::new("reviewer", &["read", "comment", "publish"]));
+
+        let revoked = user.revoke_role("editor").unwrap();
+        assert_eq!(revoked.name, "editor");
+        assert!(!user.has_role("editor"));
+        assert_eq!(user.revoke_role("editor"), None);
+
+        // write只来自editor，被移除；read来自直接授予，publish来自reviewer，都保留
+        assert!(!user.has_permission("write"));
+        assert_eq!(
+            user.effective_permissions(),
+            set(&["read", "comment", "publish"])
+        );
+
+        user.revoke_role("reviewer");
+        assert_eq!(user.effective_permissions(), set(&["read"]));
+    }
+
+    #[test]
+    fn test_grant_same_role_replaces_it() {
+        let mut user = User::new("erin");
+        user.grant_role(Role::new("ops", &["deploy"]));
+        user.grant_role(Role::new("ops", &["restart"]));
+        assert_eq!(user.roles().len(), 1);
+        assert!(!user.has_permission("deploy"));
+        assert!(user.has_permission("restart"));
+
+        // 撤销直接权限不影响角色提供的同名权限
+        user.grant_permission("restart");
+        user.revoke_permission("restart");
+        assert!(user.has_permission("restart"));
+    }
+
+    #[test]
+    fn test_common_permissions_include_roles() {
+        let mut admin = User::new("admin");
+        admin.grant_role(Role::new("admin", &["read", "write", "delete"]));
+        let mut user = User::new("user");
+        user.grant_permission("read");
+        user.grant_permission("comment");
+        assert_eq!(admin.common_permissions(&user), set(&["read"]));
+    }
+}
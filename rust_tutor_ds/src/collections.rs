@@ -0,0 +1,1054 @@
+//! 通用集合类型
+//!
+//! 这些类型建立在标准库集合之上，用于演示如何为自定义集合
+//! 实现迭代器相关的trait（`IntoIterator`、`FromIterator`等）。
+//! 末尾的[`invert`]和[`merge_with`]是HashMap教程（`07_hashmap.rs`）中常用操作的辅助函数。
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// 基于Vec实现的栈（后进先出）
+///
+/// ```
+/// use rust_data_structures_tutorial::collections::Stack;
+///
+/// let mut stack: Stack<i32> = (1..=3).collect();
+/// stack.push(4);
+/// assert_eq!(stack.pop(), Some(4));
+///
+/// // for循环按照后进先出的顺序遍历
+/// let items: Vec<i32> = stack.into_iter().collect();
+/// assert_eq!(items, vec![3, 2, 1]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    /// 创建空栈
+    pub fn new() -> Self {
+        Stack { items: Vec::new() }
+    }
+
+    /// 压入元素到栈顶
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    /// 弹出栈顶元素，栈为空时返回None
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    /// 查看栈顶元素但不弹出
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    /// 栈中元素个数
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// 栈是否为空
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Stack::new()
+    }
+}
+
+/// 按值遍历栈的迭代器，从栈顶到栈底依次弹出元素
+#[derive(Debug)]
+pub struct IntoIter<T> {
+    stack: Stack<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.stack.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.stack.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// 消耗栈，按照后进先出（LIFO）的顺序产生元素
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { stack: self }
+    }
+}
+
+impl<T> FromIterator<T> for Stack<T> {
+    /// 依次压入迭代器中的元素，最后一个元素位于栈顶
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Stack {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// 固定容量的环形缓冲区（循环队列）
+///
+/// 底层是一段长度固定的存储空间，`head`指向最旧的元素，
+/// 写入位置到达末尾后会回绕到开头，因此入队和出队都是O(1)。
+///
+/// ```
+/// use rust_data_structures_tutorial::collections::RingBuffer;
+///
+/// let mut buffer = RingBuffer::with_capacity(2);
+/// assert_eq!(buffer.push(1), None);
+/// assert_eq!(buffer.push(2), None);
+/// // 已满时push会覆盖并返回最旧的元素
+/// assert_eq!(buffer.push(3), Some(1));
+/// // try_push则拒绝写入，把元素原样退回
+/// assert_eq!(buffer.try_push(4), Err(4));
+/// assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![&2, &3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RingBuffer<T> {
+    slots: Vec<Option<T>>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> RingBuffer<T> {
+    /// [`Default`]创建的缓冲区的容量
+    pub const DEFAULT_CAPACITY: usize = 16;
+
+    /// 创建容量为`capacity`的环形缓冲区
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut slots = Vec::with_capacity(capacity);
+        slots.resize_with(capacity, || None);
+        RingBuffer {
+            slots,
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// 在队尾写入元素（覆盖模式）
+    ///
+    /// 缓冲区已满时覆盖最旧的元素，并返回被淘汰的元素。
+    /// 容量为0时元素无法存放，直接原样返回。
+    pub fn push(&mut self, item: T) -> Option<T> {
+        if self.capacity() == 0 {
+            return Some(item);
+        }
+        if self.is_full() {
+            // 最旧元素所在的位置正好是下一个写入位置
+            let evicted = self.slots[self.head].replace(item);
+            self.head = (self.head + 1) % self.capacity();
+            evicted
+        } else {
+            let tail = self.physical_index(self.len);
+            self.slots[tail] = Some(item);
+            self.len += 1;
+            None
+        }
+    }
+
+    /// 在队尾写入元素（拒绝模式）
+    ///
+    /// 缓冲区已满时不做任何修改，通过`Err`把元素退回给调用者。
+    pub fn try_push(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+        self.push(item);
+        Ok(())
+    }
+
+    /// 取出最旧的元素
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let item = self.slots[self.head].take();
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+        item
+    }
+
+    /// 当前元素个数
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 是否已满
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// 最大容量
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// 按逻辑顺序（从最旧到最新）遍历元素
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        (0..self.len).filter_map(move |offset| self.slots[self.physical_index(offset)].as_ref())
+    }
+
+    /// 把逻辑位置（相对于head的偏移）换算成底层存储的下标
+    fn physical_index(&self, offset: usize) -> usize {
+        (self.head + offset) % self.capacity()
+    }
+}
+
+impl<T> Default for RingBuffer<T> {
+    fn default() -> Self {
+        RingBuffer::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+}
+
+/// 前缀树（字典树），用于按前缀查找单词
+///
+/// 每个节点用`HashMap<char, Node>`保存子节点，从根到某个节点的路径就是一个前缀，
+/// 节点上的标记表示这个前缀本身是否是一个完整的单词。
+///
+/// ```
+/// use rust_data_structures_tutorial::collections::Trie;
+///
+/// let mut trie = Trie::new();
+/// for word in ["car", "cart", "care", "dog"] {
+///     trie.insert(word);
+/// }
+/// assert!(trie.contains("car"));
+/// assert!(!trie.contains("ca"));
+/// assert!(trie.starts_with("ca"));
+/// assert_eq!(trie.words_with_prefix("car"), vec!["car", "care", "cart"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Trie {
+    root: TrieNode,
+    len: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    children: HashMap<char, TrieNode>,
+    is_word: bool,
+}
+
+impl Trie {
+    /// 创建空的前缀树
+    pub fn new() -> Self {
+        Trie::default()
+    }
+
+    /// 插入单词，返回它之前是否不存在
+    pub fn insert(&mut self, word: &str) -> bool {
+        let mut node = &mut self.root;
+        for ch in word.chars() {
+            node = node.children.entry(ch).or_default();
+        }
+        let inserted = !node.is_word;
+        node.is_word = true;
+        if inserted {
+            self.len += 1;
+        }
+        inserted
+    }
+
+    /// 是否包含完整的单词`word`
+    pub fn contains(&self, word: &str) -> bool {
+        self.find(word).is_some_and(|node| node.is_word)
+    }
+
+    /// 是否有单词以`prefix`开头（单词本身等于`prefix`也算）
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.find(prefix).is_some()
+    }
+
+    /// 所有以`prefix`开头的单词，按字典序排列
+    pub fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        if let Some(node) = self.find(prefix) {
+            let mut current = prefix.to_string();
+            node.collect_words(&mut current, &mut words);
+        }
+        // HashMap的遍历顺序不固定，排序后结果才稳定
+        words.sort();
+        words
+    }
+
+    /// 单词个数
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 沿着`prefix`的字符向下走，返回最后到达的节点
+    fn find(&self, prefix: &str) -> Option<&TrieNode> {
+        let mut node = &self.root;
+        for ch in prefix.chars() {
+            node = node.children.get(&ch)?;
+        }
+        Some(node)
+    }
+}
+
+impl TrieNode {
+    /// 深度优先收集当前节点下的所有单词，`current`是从根到当前节点的前缀
+    fn collect_words(&self, current: &mut String, words: &mut Vec<String>) {
+        if self.is_word {
+            words.push(current.clone());
+        }
+        for (&ch, child) in &self.children {
+            current.push(ch);
+            child.collect_words(current, words);
+            current.pop();
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Trie {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut trie = Trie::new();
+        for word in iter {
+            trie.insert(word);
+        }
+        trie
+    }
+}
+
+/// [`PriorityQueue`]的出队顺序
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// 优先级最高的先出队
+    Max,
+    /// 优先级最低的先出队
+    Min,
+}
+
+/// 按优先级出队的队列，底层是标准库的`BinaryHeap`
+///
+/// 元素本身不需要实现`Ord`，只比较单独传入的优先级；
+/// 优先级相同的元素按入队顺序出队。
+///
+/// ```
+/// use rust_data_structures_tutorial::collections::{Order, PriorityQueue};
+///
+/// let mut tasks = PriorityQueue::new(Order::Max);
+/// tasks.push("写文档", 1);
+/// tasks.push("修复线上故障", 10);
+/// tasks.push("代码评审", 5);
+/// assert_eq!(tasks.pop(), Some("修复线上故障"));
+///
+/// let mut timers = PriorityQueue::new(Order::Min);
+/// timers.push("30秒后", 30);
+/// timers.push("5秒后", 5);
+/// assert_eq!(timers.peek(), Some(&"5秒后"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PriorityQueue<T, P: Ord> {
+    heap: BinaryHeap<Entry<T, P>>,
+    order: Order,
+    next_seq: u64,
+}
+
+/// 堆中的元素，排序只看优先级和入队序号
+#[derive(Debug, Clone)]
+struct Entry<T, P> {
+    priority: P,
+    seq: u64,
+    order: Order,
+    item: T,
+}
+
+impl<T, P: Ord> PartialEq for Entry<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T, P: Ord> Eq for Entry<T, P> {}
+
+impl<T, P: Ord> PartialOrd for Entry<T, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, P: Ord> Ord for Entry<T, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap是最大堆：Min模式下把优先级的比较反过来
+        let by_priority = match self.order {
+            Order::Max => self.priority.cmp(&other.priority),
+            Order::Min => other.priority.cmp(&self.priority),
+        };
+        // 优先级相同时，序号小（先入队）的排在前面
+        by_priority.then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl<T, P: Ord> PriorityQueue<T, P> {
+    /// 创建空队列，`order`决定优先级高的还是低的先出队
+    pub fn new(order: Order) -> Self {
+        PriorityQueue {
+            heap: BinaryHeap::new(),
+            order,
+            next_seq: 0,
+        }
+    }
+
+    /// 以`priority`为优先级加入元素
+    pub fn push(&mut self, item: T, priority: P) {
+        self.heap.push(Entry {
+            priority,
+            seq: self.next_seq,
+            order: self.order,
+            item,
+        });
+        self.next_seq += 1;
+    }
+
+    /// 取出最先应该出队的元素
+    pub fn pop(&mut self) -> Option<T> {
+        self.heap.pop().map(|entry| entry.item)
+    }
+
+    /// 查看下一个出队的元素但不取出
+    pub fn peek(&self) -> Option<&T> {
+        self.heap.peek().map(|entry| &entry.item)
+    }
+
+    /// 元素个数
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// 出队顺序
+    pub fn order(&self) -> Order {
+        self.order
+    }
+}
+
+/// 双向映射：同时维护`L -> R`和`R -> L`，两个方向都能O(1)查找
+///
+/// 左值和右值一一对应（双射）。插入的新对与已有的对在任意一侧冲突时，
+/// 旧的对会被整体删除，保证两个方向的映射始终一致。
+///
+/// ```
+/// use rust_data_structures_tutorial::collections::BiMap;
+///
+/// let mut codes = BiMap::new();
+/// codes.insert("中国", "CN");
+/// codes.insert("日本", "JP");
+/// assert_eq!(codes.get_by_left(&"中国"), Some(&"CN"));
+/// assert_eq!(codes.get_by_right(&"JP"), Some(&"日本"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BiMap<L, R> {
+    left_to_right: HashMap<L, R>,
+    right_to_left: HashMap<R, L>,
+}
+
+impl<L, R> BiMap<L, R>
+where
+    L: Eq + Hash + Clone,
+    R: Eq + Hash + Clone,
+{
+    /// 创建空的双向映射
+    pub fn new() -> Self {
+        BiMap {
+            left_to_right: HashMap::new(),
+            right_to_left: HashMap::new(),
+        }
+    }
+
+    /// 插入`left <-> right`，返回因冲突被删除的旧对
+    ///
+    /// 如果`left`已经映射到别的右值，或`right`已经被别的左值映射，
+    /// 这些旧对都会被删除并返回（最多两对）；插入已经存在的同一对时什么也不删除。
+    pub fn insert(&mut self, left: L, right: R) -> Vec<(L, R)> {
+        let mut removed = Vec::new();
+        if self.left_to_right.get(&left) == Some(&right) {
+            return removed;
+        }
+        if let Some(old_right) = self.remove_by_left(&left) {
+            removed.push((left.clone(), old_right));
+        }
+        if let Some(old_left) = self.remove_by_right(&right) {
+            removed.push((old_left, right.clone()));
+        }
+        self.left_to_right.insert(left.clone(), right.clone());
+        self.right_to_left.insert(right, left);
+        removed
+    }
+
+    /// 按左值查找对应的右值
+    pub fn get_by_left(&self, left: &L) -> Option<&R> {
+        self.left_to_right.get(left)
+    }
+
+    /// 按右值查找对应的左值
+    pub fn get_by_right(&self, right: &R) -> Option<&L> {
+        self.right_to_left.get(right)
+    }
+
+    /// 按左值删除一对映射，返回对应的右值
+    pub fn remove_by_left(&mut self, left: &L) -> Option<R> {
+        let right = self.left_to_right.remove(left)?;
+        self.right_to_left.remove(&right);
+        Some(right)
+    }
+
+    /// 按右值删除一对映射，返回对应的左值
+    pub fn remove_by_right(&mut self, right: &R) -> Option<L> {
+        let left = self.right_to_left.remove(right)?;
+        self.left_to_right.remove(&left);
+        Some(left)
+    }
+
+    /// 映射的对数
+    pub fn len(&self) -> usize {
+        self.left_to_right.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.left_to_right.is_empty()
+    }
+
+    /// 遍历所有的对，顺序不固定
+    pub fn iter(&self) -> impl Iterator<Item = (&L, &R)> + '_ {
+        self.left_to_right.iter()
+    }
+}
+
+impl<L, R> Default for BiMap<L, R>
+where
+    L: Eq + Hash + Clone,
+    R: Eq + Hash + Clone,
+{
+    fn default() -> Self {
+        BiMap::new()
+    }
+}
+
+/// 保持插入顺序的集合
+///
+/// `HashSet`的遍历顺序不固定，打印出来的结果每次运行都可能不同。
+/// `OrderedSet`用`Vec`保存插入顺序，再用`HashMap`记录每个元素在`Vec`中的下标，
+/// 所以既能按插入顺序遍历，又能O(1)判断元素是否存在。
+///
+/// ```
+/// use rust_data_structures_tutorial::collections::OrderedSet;
+///
+/// let users: OrderedSet<&str> = ["user123", "user456", "user123", "user789"]
+///     .into_iter()
+///     .collect();
+/// assert_eq!(users.iter().collect::<Vec<_>>(), vec![&"user123", &"user456", &"user789"]);
+/// assert!(users.contains(&"user456"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrderedSet<T> {
+    items: Vec<T>,
+    indices: HashMap<T, usize>,
+}
+
+impl<T> OrderedSet<T>
+where
+    T: Eq + Hash + Clone,
+{
+    /// 创建空集合
+    pub fn new() -> Self {
+        OrderedSet {
+            items: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    /// 插入元素，返回它之前是否不存在；已存在的元素保持原来的位置
+    pub fn insert(&mut self, item: T) -> bool {
+        if self.indices.contains_key(&item) {
+            return false;
+        }
+        self.indices.insert(item.clone(), self.items.len());
+        self.items.push(item);
+        true
+    }
+
+    /// 是否包含`item`
+    pub fn contains(&self, item: &T) -> bool {
+        self.indices.contains_key(item)
+    }
+
+    /// 删除元素，返回它之前是否存在
+    ///
+    /// 其余元素保持原来的相对顺序，因此需要移动后面的元素，时间复杂度为O(n)。
+    pub fn remove(&mut self, item: &T) -> bool {
+        let Some(index) = self.indices.remove(item) else {
+            return false;
+        };
+        self.items.remove(index);
+        // 被删除元素之后的元素都向前移动了一位
+        for later in &self.items[index..] {
+            if let Some(position) = self.indices.get_mut(later) {
+                *position -= 1;
+            }
+        }
+        true
+    }
+
+    /// 元素个数
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// 按插入顺序遍历
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+}
+
+impl<T> Default for OrderedSet<T>
+where
+    T: Eq + Hash + Clone,
+{
+    fn default() -> Self {
+        OrderedSet::new()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a OrderedSet<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<T> IntoIterator for OrderedSet<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<T> FromIterator<T> for OrderedSet<T>
+where
+    T: Eq + Hash + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = OrderedSet::new();
+        for item in iter {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+/// 交换键和值，得到从值到键的映射
+///
+/// 多个键对应同一个值时，遍历时后出现的键覆盖先出现的键（后者胜出）。
+/// `HashMap`的遍历顺序是不确定的，所以这种情况下最终保留哪个键也是不确定的；
+/// 需要保留全部键时应该改用`HashMap<V, Vec<K>>`。
+///
+/// ```
+/// use std::collections::HashMap;
+/// use rust_data_structures_tutorial::collections::invert;
+///
+/// let codes = HashMap::from([("北京", 10), ("上海", 21)]);
+/// let cities = invert(codes);
+/// assert_eq!(cities[&21], "上海");
+/// ```
+pub fn invert<K, V>(map: HashMap<K, V>) -> HashMap<V, K>
+where
+    V: Eq + Hash,
+{
+    map.into_iter().map(|(key, value)| (value, key)).collect()
+}
+
+/// 合并两个映射，两边都有的键用`f(a中的值, b中的值)`计算合并后的值
+///
+/// ```
+/// use std::collections::HashMap;
+/// use rust_data_structures_tutorial::collections::merge_with;
+///
+/// let monday = HashMap::from([("苹果", 3), ("香蕉", 2)]);
+/// let tuesday = HashMap::from([("苹果", 4), ("橙子", 1)]);
+/// let total = merge_with(monday, tuesday, |a, b| a + b);
+/// assert_eq!(total, HashMap::from([("苹果", 7), ("香蕉", 2), ("橙子", 1)]));
+/// ```
+pub fn merge_with<K, V>(a: HashMap<K, V>, b: HashMap<K, V>, f: impl Fn(V, V) -> V) -> HashMap<K, V>
+where
+    K: Eq + Hash,
+{
+    let mut merged = a;
+    for (key, value) in b {
+        // 先取出a中的值才能按值传给f，合并后再放回去
+        let combined = match merged.remove(&key) {
+            Some(existing) => f(existing, value),
+            None => value,
+        };
+        merged.insert(key, combined);
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stack_push_pop_peek() {
+        let mut stack = Stack::new();
+        assert!(stack.is_empty());
+        stack.push(1);
+        stack.push(2);
+        assert_eq!(stack.peek(), Some(&2));
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn test_stack_iterates_lifo() {
+        let mut stack = Stack::new();
+        for i in 1..=4 {
+            stack.push(i);
+        }
+        let mut seen = Vec::new();
+        for item in stack {
+            seen.push(item);
+        }
+        assert_eq!(seen, vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_stack_collect_round_trip() {
+        let stack: Stack<char> = "abc".chars().collect();
+        assert_eq!(stack.peek(), Some(&'c'));
+
+        // 出栈顺序是逆序，再收集一次即恢复栈顶为最后压入的元素
+        let reversed: Vec<char> = stack.clone().into_iter().collect();
+        assert_eq!(reversed, vec!['c', 'b', 'a']);
+        let rebuilt: Stack<char> = reversed.into_iter().rev().collect();
+        assert_eq!(rebuilt, stack);
+    }
+
+    #[test]
+    fn test_default_collections_are_empty() {
+        let stack: Stack<i32> = Stack::default();
+        assert!(stack.is_empty());
+        assert_eq!(stack.peek(), None);
+
+        let buffer: RingBuffer<i32> = RingBuffer::default();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.capacity(), RingBuffer::<i32>::DEFAULT_CAPACITY);
+    }
+
+    #[test]
+    fn test_ring_buffer_wraparound() {
+        let mut buffer = RingBuffer::with_capacity(3);
+        buffer.push(1);
+        buffer.push(2);
+        buffer.push(3);
+        assert_eq!(buffer.pop_front(), Some(1));
+        assert_eq!(buffer.pop_front(), Some(2));
+
+        // 写入位置回绕到底层存储的开头
+        buffer.push(4);
+        buffer.push(5);
+        assert!(buffer.is_full());
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+
+        assert_eq!(buffer.pop_front(), Some(3));
+        assert_eq!(buffer.pop_front(), Some(4));
+        assert_eq!(buffer.pop_front(), Some(5));
+        assert_eq!(buffer.pop_front(), None);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_ring_buffer_overwrite_evicts_oldest() {
+        let mut buffer = RingBuffer::with_capacity(3);
+        for i in 1..=3 {
+            assert_eq!(buffer.push(i), None);
+        }
+        assert_eq!(buffer.push(4), Some(1));
+        assert_eq!(buffer.push(5), Some(2));
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_ring_buffer_try_push_rejects_when_full() {
+        let mut buffer = RingBuffer::with_capacity(2);
+        assert_eq!(buffer.try_push("a"), Ok(()));
+        assert_eq!(buffer.try_push("b"), Ok(()));
+        assert_eq!(buffer.try_push("c"), Err("c"));
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec!["a", "b"]);
+
+        let mut empty: RingBuffer<u8> = RingBuffer::with_capacity(0);
+        assert_eq!(empty.push(1), Some(1));
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_trie_insert_and_contains() {
+        let mut trie = Trie::new();
+        assert!(trie.is_empty());
+        assert!(trie.insert("apple"));
+        assert!(trie.insert("app"));
+        assert!(!trie.insert("apple"));
+        assert_eq!(trie.len(), 2);
+
+        assert!(trie.contains("apple"));
+        assert!(trie.contains("app"));
+        assert!(!trie.contains("ap"));
+        assert!(!trie.contains("apples"));
+        assert!(!trie.contains(""));
+    }
+
+    #[test]
+    fn test_trie_prefix_queries() {
+        let trie: Trie = ["tea", "ten", "team", "to", "inn", "in"]
+            .into_iter()
+            .collect();
+        assert!(trie.starts_with("te"));
+        assert!(trie.starts_with("tea"));
+        assert!(!trie.starts_with("tee"));
+        assert!(trie.starts_with(""));
+
+        assert_eq!(trie.words_with_prefix("te"), vec!["tea", "team", "ten"]);
+        // 前缀本身也是完整单词时同样包含在结果中
+        assert_eq!(trie.words_with_prefix("in"), vec!["in", "inn"]);
+        assert_eq!(trie.words_with_prefix("tea"), vec!["tea", "team"]);
+        assert!(trie.words_with_prefix("x").is_empty());
+        assert_eq!(trie.words_with_prefix("").len(), 6);
+    }
+
+    #[test]
+    fn test_trie_unicode_words() {
+        let trie: Trie = ["数据", "数据结构", "数学"].into_iter().collect();
+        assert_eq!(trie.words_with_prefix("数据"), vec!["数据", "数据结构"]);
+        assert!(trie.starts_with("数"));
+        assert!(!trie.contains("数"));
+    }
+
+    fn drain<T, P: Ord>(queue: &mut PriorityQueue<T, P>) -> Vec<T> {
+        std::iter::from_fn(|| queue.pop()).collect()
+    }
+
+    #[test]
+    fn test_priority_queue_max_order() {
+        let mut queue = PriorityQueue::new(Order::Max);
+        for (item, priority) in [("c", 3), ("a", 7), ("d", -2), ("b", 5), ("e", 0)] {
+            queue.push(item, priority);
+        }
+        assert_eq!(queue.len(), 5);
+        assert_eq!(queue.peek(), Some(&"a"));
+        assert_eq!(drain(&mut queue), vec!["a", "b", "c", "e", "d"]);
+        assert!(queue.is_empty());
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn test_priority_queue_min_order() {
+        let mut queue = PriorityQueue::new(Order::Min);
+        for (item, priority) in [("c", 3), ("a", 7), ("d", -2), ("b", 5), ("e", 0)] {
+            queue.push(item, priority);
+        }
+        assert_eq!(queue.order(), Order::Min);
+        assert_eq!(queue.peek(), Some(&"d"));
+        assert_eq!(drain(&mut queue), vec!["d", "e", "c", "b", "a"]);
+    }
+
+    #[test]
+    fn test_priority_queue_ties_are_fifo() {
+        for order in [Order::Max, Order::Min] {
+            let mut queue = PriorityQueue::new(order);
+            queue.push("first", 1);
+            queue.push("second", 1);
+            queue.push("third", 1);
+            assert_eq!(drain(&mut queue), vec!["first", "second", "third"]);
+        }
+
+        // 交替入队和出队时顺序依然正确
+        let mut queue = PriorityQueue::new(Order::Max);
+        queue.push(1, 'b');
+        queue.push(2, 'a');
+        assert_eq!(queue.pop(), Some(1));
+        queue.push(3, 'c');
+        queue.push(4, 'a');
+        assert_eq!(drain(&mut queue), vec![3, 2, 4]);
+    }
+
+    /// 检查两个方向的映射完全一致
+    fn assert_bimap_consistent<L, R>(map: &BiMap<L, R>)
+    where
+        L: Eq + Hash + Clone + std::fmt::Debug,
+        R: Eq + Hash + Clone + std::fmt::Debug,
+    {
+        assert_eq!(map.left_to_right.len(), map.right_to_left.len());
+        for (left, right) in map.iter() {
+            assert_eq!(map.get_by_right(right), Some(left));
+        }
+    }
+
+    #[test]
+    fn test_bimap_bidirectional_lookup() {
+        let mut map = BiMap::new();
+        assert!(map.insert(1, "one").is_empty());
+        assert!(map.insert(2, "two").is_empty());
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get_by_left(&1), Some(&"one"));
+        assert_eq!(map.get_by_right(&"two"), Some(&2));
+        assert_eq!(map.get_by_left(&3), None);
+        assert_eq!(map.get_by_right(&"three"), None);
+        assert_bimap_consistent(&map);
+    }
+
+    #[test]
+    fn test_bimap_insert_replaces_conflicts() {
+        let mut map = BiMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+
+        // 左值冲突："a"原来对应1，旧对被删除
+        assert_eq!(map.insert("a", 3), vec![("a", 1)]);
+        assert_eq!(map.get_by_right(&1), None);
+        assert_eq!(map.get_by_left(&"a"), Some(&3));
+
+        // 右值冲突：2原来属于"b"，旧对被删除
+        assert_eq!(map.insert("c", 2), vec![("b", 2)]);
+        assert_eq!(map.get_by_left(&"b"), None);
+
+        // 两侧同时冲突：("a", 3)和("c", 2)都被删除
+        assert_eq!(map.insert("a", 2), vec![("a", 3), ("c", 2)]);
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get_by_right(&2), Some(&"a"));
+
+        // 插入已存在的同一对不删除任何东西
+        assert!(map.insert("a", 2).is_empty());
+        assert_eq!(map.len(), 1);
+        assert_bimap_consistent(&map);
+    }
+
+    #[test]
+    fn test_bimap_remove_keeps_both_sides_in_sync() {
+        let mut map: BiMap<String, u32> = BiMap::default();
+        map.insert("x".to_string(), 10);
+        map.insert("y".to_string(), 20);
+
+        assert_eq!(map.remove_by_left(&"x".to_string()), Some(10));
+        assert_eq!(map.get_by_right(&10), None);
+        assert_eq!(map.remove_by_left(&"x".to_string()), None);
+
+        assert_eq!(map.remove_by_right(&20), Some("y".to_string()));
+        assert!(map.is_empty());
+        assert_bimap_consistent(&map);
+    }
+
+    fn ordered<T: Eq + Hash + Clone>(set: &OrderedSet<T>) -> Vec<T> {
+        set.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_ordered_set_keeps_insertion_order() {
+        let mut set = OrderedSet::new();
+        for word in ["pear", "apple", "fig", "banana"] {
+            assert!(set.insert(word));
+        }
+        assert_eq!(ordered(&set), vec!["pear", "apple", "fig", "banana"]);
+        assert_eq!(set.len(), 4);
+        assert!(set.contains(&"fig"));
+        assert!(!set.contains(&"kiwi"));
+    }
+
+    #[test]
+    fn test_ordered_set_duplicate_insert_does_not_reorder() {
+        let mut set: OrderedSet<i32> = [3, 1, 2].into_iter().collect();
+        assert!(!set.insert(3));
+        assert!(!set.insert(1));
+        assert_eq!(ordered(&set), vec![3, 1, 2]);
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn test_ordered_set_remove_keeps_order() {
+        let mut set: OrderedSet<char> = "abcde".chars().collect();
+        assert!(set.remove(&'b'));
+        assert!(!set.remove(&'b'));
+        assert_eq!(ordered(&set), vec!['a', 'c', 'd', 'e']);
+
+        // 删除后下标仍然正确：再删除后面的元素、重新插入被删除的元素
+        assert!(set.remove(&'d'));
+        assert!(set.insert('b'));
+        assert!(set.remove(&'e'));
+        assert_eq!(ordered(&set), vec!['a', 'c', 'b']);
+        for (index, item) in set.iter().enumerate() {
+            assert_eq!(set.indices[item], index);
+        }
+        assert_eq!(set.into_iter().collect::<String>(), "acb");
+    }
+
+    #[test]
+    fn test_invert_with_value_collision() {
+        let map = HashMap::from([("a", 1), ("b", 2), ("c", 1)]);
+        let inverted = invert(map);
+
+        assert_eq!(inverted.len(), 2);
+        assert_eq!(inverted[&2], "b");
+        // 值1对应两个键，只保留其中一个
+        assert!(["a", "c"].contains(&inverted[&1]));
+        assert!(invert(HashMap::<i32, i32>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_merge_with_sums_collisions() {
+        let a = HashMap::from([("x", 1), ("y", 10)]);
+        let b = HashMap::from([("y", 5), ("z", 100)]);
+        let merged = merge_with(a, b, |left, right| left + right);
+        assert_eq!(merged, HashMap::from([("x", 1), ("y", 15), ("z", 100)]));
+
+        // f的参数顺序是(a中的值, b中的值)
+        let a = HashMap::from([(1, String::from("旧"))]);
+        let b = HashMap::from([(1, String::from("新"))]);
+        let merged = merge_with(a, b, |old, new| format!("{}->{}", old, new));
+        assert_eq!(merged[&1], "旧->新");
+    }
+}
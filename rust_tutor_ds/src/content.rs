@@ -0,0 +1,236 @@
+//! 带标签的文章和按标签检索的文章索引
+//!
+//! 来自HashSet教程（`08_hashset.rs`）中的标签系统。示例只能比较两篇文章的共同标签，
+//! 这里增加了保存多篇文章的[`ArticleIndex`]，用集合运算按标签检索文章。
+
+use std::collections::HashSet;
+
+/// 一篇带标签的文章，标签用`HashSet`保存，不会重复
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Article {
+    pub title: String,
+    pub tags: HashSet<String>,
+}
+
+impl Article {
+    /// 创建没有标签的文章
+    pub fn new(title: &str) -> Self {
+        Article {
+            title: title.to_string(),
+            tags: HashSet::new(),
+        }
+    }
+
+    /// 创建文章并一次添加多个标签
+    pub fn with_tags(title: &str, tags: &[&str]) -> Self {
+        let mut article = Article::new(title);
+        for tag in tags {
+            article.add_tag(tag);
+        }
+        article
+    }
+
+    /// 添加标签，返回标签之前是否不存在
+    pub fn add_tag(&mut self, tag: &str) -> bool {
+        self.tags.insert(tag.to_string())
+    }
+
+    /// 删除标签，返回标签之前是否存在
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        self.tags.remove(tag)
+    }
+
+    /// 是否带有标签`tag`
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(tag)
+    }
+
+    /// 是否带有`tags`中的所有标签
+    pub fn has_all_tags(&self, tags: &[&str]) -> bool {
+        tags.iter().all(|tag| self.has_tag(tag))
+    }
+
+    /// 是否带有`tags`中的任意一个标签
+    pub fn has_any_tag(&self, tags: &[&str]) -> bool {
+        tags.iter().any(|tag| self.has_tag(tag))
+    }
+
+    /// 两篇文章的共同标签（交集）
+    pub fn common_tags(&self, other: &Article) -> HashSet<String> {
+        self.tags.intersection(&other.tags).cloned().collect()
+    }
+}
+
+/// 保存多篇文章，支持按标签检索
+///
+/// 检索结果按文章加入索引的顺序排列。
+///
+/// ```
+/// use rust_data_structures_tutorial::content::{Article, ArticleIndex};
+///
+/// let mut index = ArticleIndex::new();
+/// index.add(Article::with_tags("Rust编程入门", &["Rust", "初学者"]));
+/// index.add(Article::with_tags("高级Rust特性", &["Rust", "高级"]));
+/// index.add(Article::with_tags("Python入门", &["Python", "初学者"]));
+///
+/// let titles = |articles: Vec<&Article>| -> Vec<String> {
+///     articles.into_iter().map(|a| a.title.clone()).collect()
+/// };
+/// assert_eq!(titles(index.find_by_all_tags(&["Rust", "初学者"])), vec!["Rust编程入门"]);
+/// assert_eq!(titles(index.find_by_any_tag(&["高级", "Python"])), vec!["高级Rust特性", "Python入门"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ArticleIndex {
+    articles: Vec<Article>,
+}
+
+impl ArticleIndex {
+    /// 创建空索引
+    pub fn new() -> Self {
+        ArticleIndex::default()
+    }
+
+    /// 把文章加入索引
+    pub fn add(&mut self, article: Article) {
+        self.articles.push(article);
+    }
+
+    /// 文章数量
+    pub fn len(&self) -> usize {
+        self.articles.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.articles.is_empty()
+    }
+
+    /// 按加入顺序遍历所有文章
+    pub fn iter(&self) -> std::slice::Iter<'_, Article> {
+        self.articles.iter()
+    }
+
+    /// 带有标签`tag`的文章
+    pub fn find_by_tag(&self, tag: &str) -> Vec<&Article> {
+        self.filter(|article| article.has_tag(tag))
+    }
+
+    /// 同时带有`tags`中所有标签的文章；`tags`为空时返回所有文章
+    pub fn find_by_all_tags(&self, tags: &[&str]) -> Vec<&Article> {
+        self.filter(|article| article.has_all_tags(tags))
+    }
+
+    /// 至少带有`tags`中一个标签的文章；`tags`为空时返回空列表
+    pub fn find_by_any_tag(&self, tags: &[&str]) -> Vec<&Article> {
+        self.filter(|article| article.has_any_tag(tags))
+    }
+
+    /// 所有文章共有的标签；索引为空时返回空集合
+    pub fn common_tags(&self) -> HashSet<String> {
+        let mut articles = self.articles.iter();
+        let Some(first) = articles.next() else {
+            return HashSet::new();
+        };
+        articles.fold(first.tags.clone(), |common, article| {
+            common
+                .into_iter()
+                .filter(|tag| article.has_tag(tag))
+                .collect()
+        })
+    }
+
+    fn filter(&self, predicate: impl Fn(&Article) -> bool) -> Vec<&Article> {
+        self.articles
+            .iter()
+            .filter(|article| predicate(article))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_index() -> ArticleIndex {
+        let mut index = ArticleIndex::new();
+        index.add(Article::with_tags(
+            "Rust编程入门",
+            &["编程", "Rust", "初学者", "教程"],
+        ));
+        index.add(Article::with_tags(
+            "高级Rust特性",
+            &["编程", "Rust", "高级", "特性"],
+        ));
+        index.add(Article::with_tags(
+            "Python数据分析",
+            &["编程", "Python", "教程"],
+        ));
+        index
+    }
+
+    fn titles(articles: Vec<&Article>) -> Vec<&str> {
+        articles
+            .into_iter()
+            .map(|article| article.title.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_article_tags() {
+        let mut article = Article::new("草稿");
+        assert!(article.add_tag("Rust"));
+        assert!(!article.add_tag("Rust"));
+        assert!(article.has_tag("Rust"));
+        assert!(article.remove_tag("Rust"));
+        assert!(!article.has_tag("Rust"));
+
+        let a = Article::with_tags("a", &["x", "y", "z"]);
+        let b = Article::with_tags("b", &["y", "z", "w"]);
+        let expected: HashSet<String> = ["y", "z"].iter().map(|t| t.to_string()).collect();
+        assert_eq!(a.common_tags(&b), expected);
+    }
+
+    #[test]
+    fn test_find_by_single_tag() {
+        let index = sample_index();
+        assert_eq!(index.len(), 3);
+        assert_eq!(
+            titles(index.find_by_tag("Rust")),
+            vec!["Rust编程入门", "高级Rust特性"]
+        );
+        assert_eq!(
+            titles(index.find_by_tag("教程")),
+            vec!["Rust编程入门", "Python数据分析"]
+        );
+        assert!(index.find_by_tag("Go").is_empty());
+    }
+
+    #[test]
+    fn test_find_by_multiple_tags() {
+        let index = sample_index();
+        assert_eq!(
+            titles(index.find_by_all_tags(&["Rust", "教程"])),
+            vec!["Rust编程入门"]
+        );
+        assert!(index.find_by_all_tags(&["Rust", "Python"]).is_empty());
+        assert_eq!(index.find_by_all_tags(&[]).len(), 3);
+
+        assert_eq!(
+            titles(index.find_by_any_tag(&["高级", "Python"])),
+            vec!["高级Rust特性", "Python数据分析"]
+        );
+        assert!(index.find_by_any_tag(&["Go", "Java"]).is_empty());
+        assert!(index.find_by_any_tag(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_common_tags_across_index() {
+        let mut index = sample_index();
+        let expected: HashSet<String> = HashSet::from(["编程".to_string()]);
+        assert_eq!(index.common_tags(), expected);
+
+        index.add(Article::new("无标签"));
+        assert!(index.common_tags().is_empty());
+        assert!(ArticleIndex::new().common_tags().is_empty());
+    }
+}
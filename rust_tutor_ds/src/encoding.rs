@@ -0,0 +1,315 @@
+//! 字节与文本之间的编码
+//!
+//! 字符串教程（`03_string.rs`）演示了`bytes()`逐字节遍历字符串。
+//! 任意字节（图片、哈希值等）并不一定是合法的UTF-8，需要先编码成可打印的文本才能放进字符串。
+//! 这里手写标准的Base64编码和十六进制编码，不依赖外部crate，方便对照着理解位运算。
+
+use std::error::Error;
+use std::fmt;
+
+/// 解码失败的原因
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// 输入长度不合法（Base64必须是4的倍数，十六进制必须是偶数），附带实际长度
+    InvalidLength(usize),
+    /// 遇到不属于编码字符表的字符，`position`是它的字节下标
+    InvalidCharacter { character: char, position: usize },
+    /// 填充字符`=`出现在不该出现的位置，或者数量超过2个
+    InvalidPadding,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidLength(len) => write!(f, "输入长度{}不合法", len),
+            DecodeError::InvalidCharacter {
+                character,
+                position,
+            } => write!(f, "位置{}处的字符{:?}不合法", position, character),
+            DecodeError::InvalidPadding => write!(f, "填充字符'='的位置不正确"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Base64编码（标准字符表，末尾用`=`填充）
+///
+/// 每3个字节（24位）拆成4组6位，每组对应字符表中的一个字符。
+/// 最后不足3个字节时用`=`补足4个字符，所以输出长度总是4的倍数。
+///
+/// ```
+/// use rust_data_structures_tutorial::encoding::{base64_decode, base64_encode};
+///
+/// assert_eq!(base64_encode(b"Rust"), "UnVzdA==");
+/// assert_eq!(base64_decode("UnVzdA==").unwrap(), b"Rust");
+/// ```
+pub fn base64_encode(data: &[u8]) -> String {
+    let mut output = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        // 把最多3个字节拼成一个24位的数，缺少的字节按0处理
+        let b0 = chunk[0] as u32;
+        let b1 = chunk.get(1).copied().unwrap_or(0) as u32;
+        let b2 = chunk.get(2).copied().unwrap_or(0) as u32;
+        let group = (b0 << 16) | (b1 << 8) | b2;
+
+        // n个字节需要n + 1个字符才能放下全部的位，其余用'='填充
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (group >> (18 - 6 * i)) & 0b11_1111;
+                output.push(BASE64_ALPHABET[index as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
+/// Base64解码，[`base64_encode`]的逆操作
+///
+/// 输入必须带填充（长度是4的倍数），不接受空白和换行。
+///
+/// ```
+/// use rust_data_structures_tutorial::encoding::{base64_decode, DecodeError};
+///
+/// assert_eq!(base64_decode("5L2g5aW9").unwrap(), "你好".as_bytes());
+/// assert_eq!(base64_decode("abc"), Err(DecodeError::InvalidLength(3)));
+/// assert!(matches!(
+///     base64_decode("ab!d"),
+///     Err(DecodeError::InvalidCharacter { character: '!', position: 2 })
+/// ));
+/// ```
+pub fn base64_decode(s: &str) -> Result<Vec<u8>, DecodeError> {
+    // 先检查字符，这样非ASCII字符会被报告为非法字符，而不是令人困惑的长度错误
+    let mut values = Vec::with_capacity(s.len());
+    for (position, character) in s.char_indices() {
+        match base64_value(character) {
+            Some(value) => values.push(value),
+            None if character == '=' => values.push(PADDING),
+            None => {
+                return Err(DecodeError::InvalidCharacter {
+                    character,
+                    position,
+                })
+            }
+        }
+    }
+    if values.len() % 4 != 0 {
+        return Err(DecodeError::InvalidLength(values.len()));
+    }
+
+    // '='只能出现在末尾，最多2个
+    let padding = values.iter().rev().take_while(|&&v| v == PADDING).count();
+    if padding > 2 || values[..values.len() - padding].contains(&PADDING) {
+        return Err(DecodeError::InvalidPadding);
+    }
+
+    let mut output = Vec::with_capacity(values.len() / 4 * 3);
+    for chunk in values.chunks(4) {
+        let group = chunk.iter().fold(0u32, |group, &value| {
+            (group << 6) | (value & 0b11_1111) as u32
+        });
+        let bytes = [(group >> 16) as u8, (group >> 8) as u8, group as u8];
+        // 最后一组中每个'='表示少一个字节
+        let real = chunk.iter().filter(|&&v| v != PADDING).count() - 1;
+        output.extend_from_slice(&bytes[..real]);
+    }
+    Ok(output)
+}
+
+/// 十六进制编码，每个字节对应两个小写字符
+///
+/// 需要大写字符时使用[`hex_encode_with`]。
+///
+/// ```
+/// use rust_data_structures_tutorial::encoding::{hex_decode, hex_encode};
+///
+/// assert_eq!(hex_encode(&[0xde, 0xad, 0xbe, 0xef]), "deadbeef");
+/// assert_eq!(hex_decode("DeadBeef").unwrap(), vec![0xde, 0xad, 0xbe, 0xef]);
+/// ```
+pub fn hex_encode(data: &[u8]) -> String {
+    hex_encode_with(data, false)
+}
+
+/// 十六进制编码，`uppercase`为`true`时使用大写字符`A-F`
+pub fn hex_encode_with(data: &[u8], uppercase: bool) -> String {
+    let digits: &[u8; 16] = if uppercase {
+        b"0123456789ABCDEF"
+    } else {
+        b"0123456789abcdef"
+    };
+    let mut output = String::with_capacity(data.len() * 2);
+    for &byte in data {
+        // 高4位和低4位各对应一个字符
+        output.push(digits[(byte >> 4) as usize] as char);
+        output.push(digits[(byte & 0x0f) as usize] as char);
+    }
+    output
+}
+
+/// 十六进制解码，[`hex_encode`]的逆操作，大小写字符都接受
+///
+/// ```
+/// use rust_data_structures_tutorial::encoding::{hex_decode, DecodeError};
+///
+/// assert_eq!(hex_decode("abc"), Err(DecodeError::InvalidLength(3)));
+/// ```
+pub fn hex_decode(s: &str) -> Result<Vec<u8>, DecodeError> {
+    // 与Base64相同，先检查字符再检查长度
+    let mut nibbles = Vec::with_capacity(s.len());
+    for (position, character) in s.char_indices() {
+        match character.to_digit(16) {
+            Some(value) => nibbles.push(value as u8),
+            None => {
+                return Err(DecodeError::InvalidCharacter {
+                    character,
+                    position,
+                })
+            }
+        }
+    }
+    if nibbles.len() % 2 != 0 {
+        return Err(DecodeError::InvalidLength(nibbles.len()));
+    }
+    Ok(nibbles
+        .chunks(2)
+        .map(|pair| (pair[0] << 4) | pair[1])
+        .collect())
+}
+
+/// 解码时用来标记'='的值，不会与0..64的字符值冲突
+const PADDING: u8 = 64;
+
+fn base64_value(character: char) -> Option<u8> {
+    match character {
+        'A'..='Z' => Some(character as u8 - b'A'),
+        'a'..='z' => Some(character as u8 - b'a' + 26),
+        '0'..='9' => Some(character as u8 - b'0' + 52),
+        '+' => Some(62),
+        '/' => Some(63),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_rfc4648_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+        for (plain, encoded) in vectors {
+            assert_eq!(base64_encode(plain.as_bytes()), encoded);
+            assert_eq!(base64_decode(encoded).unwrap(), plain.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_base64_round_trip_all_lengths() {
+        // 长度覆盖除以3余0、1、2的所有情况，字节覆盖0到255
+        let data: Vec<u8> = (0..=255).rev().collect();
+        for len in 0..=data.len() {
+            let encoded = base64_encode(&data[..len]);
+            assert_eq!(encoded.len(), len.div_ceil(3) * 4);
+            assert_eq!(
+                base64_decode(&encoded).unwrap(),
+                &data[..len],
+                "长度 {}",
+                len
+            );
+        }
+        // 用到字符表的最后两个字符
+        assert_eq!(base64_encode(&[0xfb, 0xff]), "+/8=");
+    }
+
+    #[test]
+    fn test_base64_invalid_length() {
+        assert_eq!(base64_decode("Zg="), Err(DecodeError::InvalidLength(3)));
+        assert_eq!(base64_decode("Zm9vY"), Err(DecodeError::InvalidLength(5)));
+    }
+
+    #[test]
+    fn test_base64_invalid_character() {
+        assert_eq!(
+            base64_decode("Zm9v-g=="),
+            Err(DecodeError::InvalidCharacter {
+                character: '-',
+                position: 4
+            })
+        );
+        // 位置是字节下标，非ASCII字符报告为非法字符
+        assert_eq!(
+            base64_decode("Zg你=="),
+            Err(DecodeError::InvalidCharacter {
+                character: '你',
+                position: 2
+            })
+        );
+        assert!(base64_decode("Zm9v\nYmFy").is_err());
+    }
+
+    #[test]
+    fn test_base64_invalid_padding() {
+        assert_eq!(base64_decode("Z==="), Err(DecodeError::InvalidPadding));
+        assert_eq!(base64_decode("Zg==Zm8="), Err(DecodeError::InvalidPadding));
+        assert_eq!(base64_decode("Z=g="), Err(DecodeError::InvalidPadding));
+        assert_eq!(
+            DecodeError::InvalidPadding.to_string(),
+            "填充字符'='的位置不正确"
+        );
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let data: Vec<u8> = (0..=255).collect();
+        let lower = hex_encode(&data);
+        let upper = hex_encode_with(&data, true);
+        assert_eq!(lower.len(), 512);
+        assert!(lower.starts_with("000102") && lower.ends_with("fdfeff"));
+        assert_eq!(upper, lower.to_uppercase());
+        assert_eq!(hex_decode(&lower).unwrap(), data);
+        assert_eq!(hex_decode(&upper).unwrap(), data);
+
+        assert_eq!(hex_encode(&[]), "");
+        assert_eq!(hex_decode("").unwrap(), Vec::<u8>::new());
+        // 与格式化输出{:02x}的结果一致
+        let bytes = "你好".as_bytes();
+        let formatted: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(hex_encode(bytes), formatted);
+    }
+
+    #[test]
+    fn test_hex_decode_errors() {
+        assert_eq!(
+            hex_decode("xyz"),
+            Err(DecodeError::InvalidCharacter {
+                character: 'x',
+                position: 0
+            })
+        );
+        assert_eq!(hex_decode("abc"), Err(DecodeError::InvalidLength(3)));
+        assert_eq!(
+            hex_decode("0g"),
+            Err(DecodeError::InvalidCharacter {
+                character: 'g',
+                position: 1
+            })
+        );
+        assert_eq!(
+            hex_decode("0x1f").unwrap_err().to_string(),
+            "位置1处的字符'x'不合法"
+        );
+    }
+}
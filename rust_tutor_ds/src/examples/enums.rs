@@ -0,0 +1,374 @@
+//! Rust基础数据结构教程 - 枚举 (Enum)
+//!
+//! 枚举是定义一组相关值的类型
+//! 特点：
+//! - 可以有不同的变体 (variants)
+//! - 每个变体可以携带不同类型和数量的数据
+//! - 非常适合模式匹配
+
+use crate::geometry::{Color, Direction, Shape};
+use crate::math::{self, MathError};
+use crate::tasks::{Board, Task};
+use crate::traffic::TrafficLight;
+use crate::units::Temperature;
+
+// 1. 简单枚举
+// Direction定义在库中（geometry模块），除了比较和打印还支持解析、反向和转向
+
+// 2. 带数据的枚举
+#[derive(Debug)]
+enum Message {
+    Quit,                           // 无数据
+    Move { x: i32, y: i32 },       // 具名字段
+    Write(String),                  // 元组形式
+    ChangeColor(u8, u8, u8),       // 三个u8值
+}
+
+// 枚举也可以有方法
+impl Message {
+    fn call(&self) {
+        match self {
+            Message::Quit => println!("   执行退出操作"),
+            Message::Move { x, y } => println!("   移动到坐标 ({}, {})", x, y),
+            Message::Write(text) => println!("   写入文本: {}", text),
+            Message::ChangeColor(r, g, b) => println!("   改变颜色为 RGB({}, {}, {})", r, g, b),
+        }
+    }
+}
+
+// 3. 更复杂的枚举示例
+#[derive(Debug)]
+enum WebEvent {
+    PageLoad,                                     // 单元变体
+    PageUnload,
+    KeyPress(char),                               // 元组变体
+    Paste(String),
+    Click { x: i64, y: i64 },                    // 结构体变体
+}
+
+// 4. Option枚举的使用（标准库中最重要的枚举之一）
+// Option<T>已在标准库中定义，这里仅作演示
+/*
+enum Option<T> {
+    Some(T),
+    None,
+}
+*/
+
+// 5. Result枚举的使用（错误处理）
+// Result<T, E>已在标准库中定义
+/*
+enum Result<T, E> {
+    Ok(T),
+    Err(E),
+}
+*/
+
+// 6. 自定义错误枚举
+// MathError定义在库中（math模块），实现了Display和std::error::Error
+
+// 7. 状态机枚举
+// TrafficLight定义在库中（traffic模块），可以生成时间表，也可以作为迭代器使用
+
+/// 运行教程，打印全部内容
+pub fn run() {
+    println!("=== Rust 枚举教程 ===\n");
+
+    // 1. 基本枚举使用
+    println!("1. 基本枚举使用：");
+    let north = Direction::North;
+    let south = Direction::South;
+    
+    println!("   方向1: {:?}", north);
+    println!("   方向2: {:?}", south);
+    println!("   是否相等: {}", north == south);
+    println!("   是否相等: {}", north == Direction::North);
+
+    // 2. 枚举作为函数参数
+    println!("\n2. 枚举作为函数参数：");
+    
+    fn describe_direction(dir: Direction) -> &'static str {
+        match dir {
+            Direction::North => "向北",
+            Direction::South => "向南", 
+            Direction::East => "向东",
+            Direction::West => "向西",
+        }
+    }
+    
+    for direction in [Direction::North, Direction::South, Direction::East, Direction::West] {
+        println!("   {:?}: {}", direction, describe_direction(direction));
+    }
+    
+    // 库中的Direction还提供了一些常用方法
+    let parsed: Direction = "n".parse().unwrap();
+    println!("   解析\"n\": {:?}, 反方向: {:?}", parsed, parsed.opposite());
+    println!("   北方左转: {:?}, 右转: {:?}", parsed.turn_left(), parsed.turn_right());
+    let moves: Vec<Direction> = "wasd".chars().filter_map(Direction::from_wasd).collect();
+    println!("   WASD按键对应的方向: {:?}", moves);
+    if let Err(err) = "up".parse::<Direction>() {
+        println!("   解析\"up\"失败: {}", err);
+    }
+
+    // 3. 带数据的枚举
+    println!("\n3. 带数据的枚举：");
+    let messages = vec![
+        Message::Quit,
+        Message::Move { x: 10, y: 20 },
+        Message::Write(String::from("Hello, Rust!")),
+        Message::ChangeColor(255, 128, 0),
+    ];
+    
+    println!("   消息列表:");
+    for (i, msg) in messages.iter().enumerate() {
+        println!("     消息{}: {:?}", i + 1, msg);
+    }
+
+    // 4. 枚举方法
+    println!("\n4. 枚举方法：");
+    
+    // call方法定义在上面的impl Message块中
+    for msg in &messages {
+        msg.call();
+    }
+
+    // 5. 复杂枚举的模式匹配
+    println!("\n5. 复杂枚举的模式匹配：");
+    let events = vec![
+        WebEvent::PageLoad,
+        WebEvent::PageUnload,
+        WebEvent::KeyPress('q'),
+        WebEvent::Paste(String::from("复制的文本")),
+        WebEvent::Click { x: 100, y: 200 },
+    ];
+    
+    fn handle_event(event: &WebEvent) {
+        match event {
+            WebEvent::PageLoad => println!("   页面加载"),
+            WebEvent::PageUnload => println!("   页面卸载"),
+            WebEvent::KeyPress(c) => println!("   按键按下: '{}'", c),
+            WebEvent::Paste(s) => println!("   粘贴操作: {}", s),
+            WebEvent::Click { x, y } => println!("   鼠标点击位置: ({}, {})", x, y),
+        }
+    }
+    
+    println!("   处理Web事件:");
+    for event in &events {
+        handle_event(event);
+    }
+
+    // 6. Option枚举的使用
+    println!("\n6. Option枚举的使用：");
+    
+    fn find_user(id: u32) -> Option<String> {
+        if id == 1 {
+            Some(String::from("张三"))
+        } else if id == 2 {
+            Some(String::from("李四"))
+        } else {
+            None
+        }
+    }
+    
+    let user_ids = vec![1, 2, 3, 4];
+    for id in user_ids {
+        match find_user(id) {
+            Some(name) => println!("   用户ID {}: {}", id, name),
+            None => println!("   用户ID {}: 未找到", id),
+        }
+    }
+    
+    // 使用if let简化匹配
+    println!("\n   使用if let:");
+    if let Some(user) = find_user(1) {
+        println!("   找到用户: {}", user);
+    }
+    
+    // 使用unwrap_or提供默认值
+    let user = find_user(999).unwrap_or(String::from("匿名用户"));
+    println!("   用户名: {}", user);
+
+    // 7. Result枚举的使用
+    println!("\n7. Result枚举的使用：");
+    
+    let calculations = vec![
+        ("10 / 2", math::divide(10.0, 2.0)),
+        ("10 / 0", math::divide(10.0, 0.0)),
+        ("sqrt(16)", math::sqrt(16.0)),
+        ("sqrt(-4)", math::sqrt(-4.0)),
+        ("parse(\"abc\")", math::parse_number("abc")),
+    ];
+    
+    for (desc, result) in calculations {
+        match result {
+            Ok(value) => println!("   {}: {:.2}", desc, value),
+            Err(err) => println!("   {}: 错误 - {}", desc, err),
+        }
+    }
+    
+    // 实现了Error trait的错误可以通过?转换为Box<dyn Error>
+    fn average_sqrt(inputs: &[&str]) -> Result<f64, Box<dyn std::error::Error>> {
+        let mut total = 0.0;
+        for input in inputs {
+            total += math::sqrt(math::parse_number(input)?)?;
+        }
+        Ok(math::divide(total, inputs.len() as f64)?)
+    }
+    
+    for inputs in [&["4", "16"][..], &["4", "-9"][..], &["4", "x"][..], &[][..]] {
+        match average_sqrt(inputs) {
+            Ok(value) => println!("   平方根平均值{:?}: {:.2}", inputs, value),
+            Err(err) => {
+                println!("   平方根平均值{:?}: 错误 - {}", inputs, err);
+                // source()可以找到导致错误的底层原因
+                if let Some(source) = err.source() {
+                    println!("     底层原因: {}", source);
+                }
+            }
+        }
+    }
+    
+    // 也可以直接构造错误，InvalidInput只带一条说明，没有底层原因
+    let manual_error = MathError::InvalidInput("缺少参数".to_string());
+    println!("   手动构造的错误: {}", manual_error);
+
+    // 8. 枚举的嵌套匹配
+    println!("\n8. 枚举的嵌套匹配：");
+    
+    // Color和Shape定义在库中（geometry模块），Shape还提供了面积和周长计算
+    let shapes = vec![
+        Shape::Circle { 
+            radius: 5.0, 
+            color: Color::Rgb(255, 0, 0) 
+        },
+        Shape::Rectangle { 
+            width: 10.0, 
+            height: 20.0, 
+            color: Color::Hsv(240, 100, 100) 
+        },
+        Shape::Triangle {
+            base: 6.0,
+            height: 4.0,
+            sides: [6.0, 5.0, 5.0],
+            color: Color::Rgb(0, 128, 0)
+        },
+    ];
+    
+    for shape in &shapes {
+        match shape {
+            Shape::Circle { radius, .. } => {
+                println!("   圆形 - 半径: {:.1}", radius);
+            },
+            Shape::Rectangle { width, height, .. } => {
+                println!("   矩形 - 宽: {:.1}, 高: {:.1}", width, height);
+            },
+            Shape::Triangle { base, height, .. } => {
+                println!("   三角形 - 底: {:.1}, 高: {:.1}", base, height);
+            },
+        }
+        match shape.color() {
+            Color::Rgb(r, g, b) => println!("     RGB颜色: ({}, {}, {})", r, g, b),
+            Color::Hsv(h, s, v) => println!("     HSV颜色: ({}, {}, {})", h, s, v),
+        }
+        println!("     面积: {:.2}, 周长: {:.2}", shape.area(), shape.perimeter());
+    }
+
+    // 9. 状态机示例
+    println!("\n9. 状态机示例：");
+    
+    let mut current_light = TrafficLight::Red;
+    println!("   交通灯状态变化:");
+    
+    for cycle in 1..=6 {
+        println!("     周期{}: {:?} - {} ({}秒)", 
+               cycle, current_light, current_light.description(), current_light.duration());
+        current_light = current_light.next();
+    }
+    
+    // 用迭代器和时间表完成同样的事情
+    let states: Vec<String> = TrafficLight::Red.into_iter().take(4).map(|l| l.to_string()).collect();
+    println!("   迭代器产生的前4个状态: {}", states.join(" → "));
+    println!("   6个周期的时间表: {:?}", TrafficLight::Red.schedule(6));
+    println!("   6个周期总时长: {}秒", TrafficLight::Red.total_duration(6));
+
+    // 10. 枚举的匹配守卫
+    println!("\n10. 枚举的匹配守卫：");
+    
+    // Temperature定义在库中（units模块），支持三种温标之间的转换
+    fn describe_temperature(temp: Temperature) {
+        match temp {
+            Temperature::Celsius(t) if t > 30.0 => println!("   {:.1}°C - 很热", t),
+            Temperature::Celsius(t) if t > 20.0 => println!("   {:.1}°C - 温暖", t),
+            Temperature::Celsius(t) if t > 0.0 => println!("   {:.1}°C - 凉爽", t),
+            Temperature::Celsius(t) => println!("   {:.1}°C - 寒冷", t),
+            Temperature::Fahrenheit(t) if t > 86.0 => println!("   {:.1}°F - 很热", t),
+            Temperature::Fahrenheit(t) if t > 68.0 => println!("   {:.1}°F - 温暖", t),
+            Temperature::Fahrenheit(t) if t > 32.0 => println!("   {:.1}°F - 凉爽", t),
+            Temperature::Fahrenheit(t) => println!("   {:.1}°F - 寒冷", t),
+            // 开尔文先转换成摄氏度再判断
+            Temperature::Kelvin(t) => {
+                println!("   {}K 相当于 {}", t, temp.to_celsius());
+                describe_temperature(temp.to_celsius());
+            }
+        }
+    }
+    
+    let temperatures = vec![
+        Temperature::Celsius(35.0),
+        Temperature::Celsius(25.0),
+        Temperature::Celsius(10.0),
+        Temperature::Celsius(-5.0),
+        Temperature::Fahrenheit(95.0),
+        Temperature::Fahrenheit(75.0),
+        Temperature::Kelvin(300.0),
+    ];
+    
+    for temp in temperatures {
+        describe_temperature(temp);
+    }
+    
+    // 温标转换和绝对零度检查
+    let body = Temperature::Celsius(37.0);
+    println!("   {} = {} = {}", body, body.to_fahrenheit(), body.to_kelvin());
+    match Temperature::try_celsius(-300.0) {
+        Ok(temp) => println!("   创建温度: {}", temp),
+        Err(err) => println!("   创建温度失败: {}", err),
+    }
+
+    // 11. 枚举与Vec的组合
+    println!("\n11. 枚举与Vec的组合：");
+    
+    // Task和Board定义在库中（tasks模块），支持开始、更新进度和完成等状态转换
+    let mut board = Board::new();
+    board.add(Task::Todo(String::from("学习Rust")));
+    board.add(Task::InProgress(String::from("写代码"), 60));
+    board.add(Task::Done(String::from("看文档")));
+    
+    // 统计不同状态的任务
+    let counts = board.counts();
+    println!("   任务统计:");
+    println!("     待办: {} 个", counts.todo);
+    println!("     进行中: {} 个", counts.in_progress);
+    println!("     完成: {} 个", counts.done);
+    
+    // 状态转换：开始第一个任务，把第二个任务的进度推到100%（自动完成）
+    if let Some(task) = board.get_mut(0) {
+        task.start().unwrap();
+    }
+    if let Some(task) = board.get_mut(1) {
+        task.set_progress(100).unwrap();
+    }
+    if let Some(task) = board.get_mut(2) {
+        if let Err(err) = task.start() {
+            println!("   状态转换失败: {}", err);
+        }
+    }
+    
+    // 显示所有任务
+    println!("   任务列表:");
+    for (i, task) in board.tasks().iter().enumerate() {
+        println!("     {}. {}", i + 1, task);
+    }
+
+    println!("\n=== 枚举教程结束 ===");
+} 
\ No newline at end of file
@@ -0,0 +1,740 @@
+//! 几何相关的类型
+//!
+//! 来自枚举教程（`06_enum.rs`）。示例中的`Direction`只能在`main`里比较和打印，
+//! 这里补充了解析、反向和转向等常用操作；嵌套枚举`Shape`则补充了面积和周长计算。
+//! [`Polygon`]由任意多个[`Point`]组成，用鞋带公式计算面积；[`convex_hull`]求一组点的凸包。
+//! [`KdTree`]把点按坐标轮流切分，最近邻查询不必逐个比较所有点。
+
+use crate::data_structures::Point;
+use std::f64::consts::PI;
+use std::fmt;
+use std::str::FromStr;
+
+/// 四个基本方向
+///
+/// ```
+/// use rust_data_structures_tutorial::geometry::Direction;
+///
+/// let dir: Direction = "north".parse().unwrap();
+/// assert_eq!(dir.turn_right(), Direction::East);
+/// assert_eq!(dir.opposite(), Direction::South);
+/// assert_eq!(Direction::from_wasd('a'), Some(Direction::West));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    /// 按顺时针顺序（北、东、南、西）列出所有方向
+    pub fn all() -> [Direction; 4] {
+        [
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ]
+    }
+
+    /// 相反的方向
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// 向左转90度（逆时针）
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+            Direction::East => Direction::North,
+        }
+    }
+
+    /// 向右转90度（顺时针）
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// 把游戏中常用的WASD按键映射为方向，不区分大小写
+    pub fn from_wasd(key: char) -> Option<Direction> {
+        match key.to_ascii_lowercase() {
+            'w' => Some(Direction::North),
+            'a' => Some(Direction::West),
+            's' => Some(Direction::South),
+            'd' => Some(Direction::East),
+            _ => None,
+        }
+    }
+}
+
+/// 解析方向失败时返回的错误，保存了无法识别的输入
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError {
+    input: String,
+}
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "无法识别的方向: {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    /// 接受完整的英文名称或首字母，不区分大小写，例如"north"、"N"、"West"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "north" | "n" => Ok(Direction::North),
+            "south" | "s" => Ok(Direction::South),
+            "east" | "e" => Ok(Direction::East),
+            "west" | "w" => Ok(Direction::West),
+            _ => Err(ParseDirectionError {
+                input: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// 图形的颜色
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Rgb(u8, u8, u8),
+    Hsv(u8, u8, u8),
+}
+
+/// 带颜色的平面图形
+///
+/// ```
+/// use rust_data_structures_tutorial::geometry::{Color, Shape};
+///
+/// let square = Shape::Rectangle { width: 2.0, height: 2.0, color: Color::Rgb(0, 0, 255) };
+/// assert_eq!(square.area(), 4.0);
+/// assert_eq!(square.perimeter(), 8.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    Circle {
+        radius: f64,
+        color: Color,
+    },
+    Rectangle {
+        width: f64,
+        height: f64,
+        color: Color,
+    },
+    /// 面积由底和高计算，周长由三条边计算
+    Triangle {
+        base: f64,
+        height: f64,
+        sides: [f64; 3],
+        color: Color,
+    },
+}
+
+impl Shape {
+    /// 面积
+    pub fn area(&self) -> f64 {
+        match self {
+            Shape::Circle { radius, .. } => PI * radius * radius,
+            Shape::Rectangle { width, height, .. } => width * height,
+            Shape::Triangle { base, height, .. } => base * height / 2.0,
+        }
+    }
+
+    /// 周长
+    pub fn perimeter(&self) -> f64 {
+        match self {
+            Shape::Circle { radius, .. } => 2.0 * PI * radius,
+            Shape::Rectangle { width, height, .. } => 2.0 * (width + height),
+            Shape::Triangle { sides, .. } => sides.iter().sum(),
+        }
+    }
+
+    /// 图形的颜色
+    pub fn color(&self) -> Color {
+        match self {
+            Shape::Circle { color, .. }
+            | Shape::Rectangle { color, .. }
+            | Shape::Triangle { color, .. } => *color,
+        }
+    }
+}
+
+/// 由顶点依次连接、首尾相连的多边形
+///
+/// ```
+/// use rust_data_structures_tutorial::geometry::Polygon;
+///
+/// let square = Polygon::new(vec![(0.0, 0.0).into(), (2.0, 0.0).into(), (2.0, 2.0).into(), (0.0, 2.0).into()]);
+/// assert_eq!(square.area(), 4.0);
+/// assert_eq!(square.perimeter(), 8.0);
+/// assert!(square.is_convex());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// 按顺时针或逆时针顺序给出的顶点创建多边形
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Polygon { vertices }
+    }
+
+    /// 所有顶点
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// 依次遍历每条边的起点和终点，最后一条边回到第一个顶点
+    fn edges(&self) -> impl Iterator<Item = (&Point, &Point)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+    }
+
+    /// 面积（鞋带公式），顶点少于3个时为0
+    ///
+    /// 对每条边累加`x1 * y2 - x2 * y1`，结果的一半就是有向面积，
+    /// 取绝对值后与顶点的方向无关。
+    pub fn area(&self) -> f64 {
+        if self.vertices.len() < 3 {
+            return 0.0;
+        }
+        let twice_area: f64 = self.edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum();
+        twice_area.abs() / 2.0
+    }
+
+    /// 周长，即所有边长之和（包括最后一个顶点回到第一个顶点的边）
+    pub fn perimeter(&self) -> f64 {
+        self.edges()
+            .map(|(a, b)| (b.x - a.x).hypot(b.y - a.y))
+            .sum()
+    }
+
+    /// 是否为凸多边形：沿着边走时始终朝同一个方向转弯
+    ///
+    /// 共线的相邻边不影响判断；顶点少于3个或所有顶点共线时返回`false`。
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        if n < 3 {
+            return false;
+        }
+        let mut turn = 0.0;
+        for i in 0..n {
+            let a = &self.vertices[i];
+            let b = &self.vertices[(i + 1) % n];
+            let c = &self.vertices[(i + 2) % n];
+            // 相邻两条边的叉积，符号表示向左转还是向右转
+            let cross = (b.x - a.x) * (c.y - b.y) - (b.y - a.y) * (c.x - b.x);
+            if cross == 0.0 {
+                continue;
+            }
+            if turn == 0.0 {
+                turn = cross.signum();
+            } else if cross.signum() != turn {
+                return false;
+            }
+        }
+        turn != 0.0
+    }
+}
+
+/// 向量`o->a`与`o->b`的叉积，大于0表示从`a`到`b`是逆时针方向
+fn cross(o: &Point, a: &Point, b: &Point) -> f64 {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
+
+/// 求点集的凸包（Andrew单调链算法），时间复杂度O(n log n)
+///
+/// 返回凸包的顶点，从最左下角的点开始按逆时针排列。
+/// 重复的点只保留一个，落在凸包边上的共线点不算作顶点。
+/// 去重后少于3个点时，按坐标顺序原样返回。
+///
+/// ```
+/// use rust_data_structures_tutorial::data_structures::Point;
+/// use rust_data_structures_tutorial::geometry::{convex_hull, Polygon};
+///
+/// let points: Vec<Point> = [(0.0, 0.0), (1.0, 1.0), (2.0, 0.0), (1.0, 2.0)]
+///     .into_iter()
+///     .map(Point::from)
+///     .collect();
+/// let hull = convex_hull(&points);
+/// assert_eq!(hull, vec![Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(1.0, 2.0)]);
+/// assert!(Polygon::new(hull).is_convex());
+/// ```
+pub fn convex_hull(points: &[Point]) -> Vec<Point> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    // 从左到右求下凸壳：新的点让链条向右转（顺时针）或共线时，弹出链条末尾的点
+    let mut hull: Vec<Point> = Vec::with_capacity(sorted.len() + 1);
+    for point in &sorted {
+        while hull.len() >= 2 && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], point) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(point.clone());
+    }
+
+    // 再从右到左求上凸壳，最右边的点已经在链条末尾，弹出时不能越过它
+    let lower_len = hull.len() + 1;
+    for point in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len
+            && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], point) <= 0.0
+        {
+            hull.pop();
+        }
+        hull.push(point.clone());
+    }
+    // 上凸壳的最后一个点就是起点，去掉以免重复
+    hull.pop();
+    hull
+}
+
+/// 二维kd树，用于最近邻和范围查询
+///
+/// 树没有单独的节点结构：构建时把点数组按中位数切分，
+/// 每个子数组的中间元素就是子树的根，左边的点坐标不大于它，右边的点坐标不小于它
+/// （坐标与根相等的点可能在任意一侧，查询时两侧都会考虑）；
+/// 深度为偶数的层按`x`切分，奇数层按`y`切分。
+/// 对于分布均匀的点，查询平均只需要访问O(log n)个点，而逐个扫描需要O(n)。
+///
+/// ```
+/// use rust_data_structures_tutorial::data_structures::Point;
+/// use rust_data_structures_tutorial::geometry::KdTree;
+///
+/// let tree = KdTree::new(vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(5.0, 5.0),
+///     Point::new(9.0, 1.0),
+/// ]);
+/// assert_eq!(tree.nearest(&Point::new(6.0, 4.0)), Some(&Point::new(5.0, 5.0)));
+/// assert_eq!(tree.within_radius(&Point::new(0.0, 1.0), 2.0), vec![&Point::new(0.0, 0.0)]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct KdTree {
+    points: Vec<Point>,
+}
+
+impl KdTree {
+    /// 由一组点构建kd树，时间复杂度O(n log n)
+    pub fn new(mut points: Vec<Point>) -> Self {
+        Self::build(&mut points, 0);
+        KdTree { points }
+    }
+
+    fn build(points: &mut [Point], depth: usize) {
+        if points.len() <= 1 {
+            return;
+        }
+        let mid = points.len() / 2;
+        // 只保证左边不大于、右边不小于中位数，与中位数相等的点可能被分到任意一侧
+        points.select_nth_unstable_by(mid, |a, b| axis(a, depth).total_cmp(&axis(b, depth)));
+        let (left, right) = points.split_at_mut(mid);
+        Self::build(left, depth + 1);
+        Self::build(&mut right[1..], depth + 1);
+    }
+
+    /// 点的数量
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// 是否没有任何点
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// 离`query`最近的点，树为空时返回`None`
+    pub fn nearest(&self, query: &Point) -> Option<&Point> {
+        let mut best = None;
+        Self::search_nearest(&self.points, 0, query, &mut best);
+        best.map(|(point, _)| point)
+    }
+
+    fn search_nearest<'a>(
+        points: &'a [Point],
+        depth: usize,
+        query: &Point,
+        best: &mut Option<(&'a Point, f64)>,
+    ) {
+        if points.is_empty() {
+            return;
+        }
+        let mid = points.len() / 2;
+        let root = &points[mid];
+        let dist = distance_squared(root, query);
+        if best.is_none_or(|(_, best_dist)| dist < best_dist) {
+            *best = Some((root, dist));
+        }
+
+        // 先搜索查询点所在的一侧，另一侧只有在切分线比当前最近距离更近时才可能有更近的点
+        let diff = axis(query, depth) - axis(root, depth);
+        let (near, far) = if diff < 0.0 {
+            (&points[..mid], &points[mid + 1..])
+        } else {
+            (&points[mid + 1..], &points[..mid])
+        };
+        Self::search_nearest(near, depth + 1, query, best);
+        if best.is_none_or(|(_, best_dist)| diff * diff < best_dist) {
+            Self::search_nearest(far, depth + 1, query, best);
+        }
+    }
+
+    /// 与`query`的距离不超过`radius`的所有点，顺序不固定
+    pub fn within_radius(&self, query: &Point, radius: f64) -> Vec<&Point> {
+        let mut found = Vec::new();
+        Self::search_radius(&self.points, 0, query, radius, &mut found);
+        found
+    }
+
+    fn search_radius<'a>(
+        points: &'a [Point],
+        depth: usize,
+        query: &Point,
+        radius: f64,
+        found: &mut Vec<&'a Point>,
+    ) {
+        if points.is_empty() {
+            return;
+        }
+        let mid = points.len() / 2;
+        let root = &points[mid];
+        if distance_squared(root, query) <= radius * radius {
+            found.push(root);
+        }
+        // 圆越过切分线时才需要搜索另一侧；坐标与切分线相等的点可能在任意一侧，所以判断都包含等号
+        let diff = axis(query, depth) - axis(root, depth);
+        if diff - radius <= 0.0 {
+            Self::search_radius(&points[..mid], depth + 1, query, radius, found);
+        }
+        if diff + radius >= 0.0 {
+            Self::search_radius(&points[mid + 1..], depth + 1, query, radius, found);
+        }
+    }
+}
+
+/// 第`depth`层用来切分的坐标
+fn axis(point: &Point, depth: usize) -> f64 {
+    if depth.is_multiple_of(2) {
+        point.x
+    } else {
+        point.y
+    }
+}
+
+fn distance_squared(a: &Point, b: &Point) -> f64 {
+    let (dx, dy) = (a.x - b.x, a.y - b.y);
+    dx * dx + dy * dy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_direction() {
+        assert_eq!("north".parse(), Ok(Direction::North));
+        assert_eq!("N".parse(), Ok(Direction::North));
+        assert_eq!("SOUTH".parse(), Ok(Direction::South));
+        assert_eq!(" e ".parse(), Ok(Direction::East));
+        assert_eq!("West".parse(), Ok(Direction::West));
+
+        let err = "up".parse::<Direction>().unwrap_err();
+        assert_eq!(err.to_string(), "无法识别的方向: \"up\"");
+    }
+
+    #[test]
+    fn test_opposite_is_involution() {
+        for dir in Direction::all() {
+            assert_ne!(dir.opposite(), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+        }
+        assert_eq!(Direction::East.opposite(), Direction::West);
+    }
+
+    #[test]
+    fn test_turns_form_full_cycle() {
+        for dir in Direction::all() {
+            // 连续转4次回到原方向，转2次等于反向
+            let mut right = dir;
+            let mut left = dir;
+            for _ in 0..4 {
+                right = right.turn_right();
+                left = left.turn_left();
+            }
+            assert_eq!(right, dir);
+            assert_eq!(left, dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.turn_left().turn_right(), dir);
+        }
+
+        // all()按顺时针排列，右转得到下一个方向
+        let all = Direction::all();
+        for i in 0..4 {
+            assert_eq!(all[i].turn_right(), all[(i + 1) % 4]);
+        }
+    }
+
+    #[test]
+    fn test_from_wasd() {
+        assert_eq!(Direction::from_wasd('w'), Some(Direction::North));
+        assert_eq!(Direction::from_wasd('A'), Some(Direction::West));
+        assert_eq!(Direction::from_wasd('s'), Some(Direction::South));
+        assert_eq!(Direction::from_wasd('D'), Some(Direction::East));
+        assert_eq!(Direction::from_wasd('q'), None);
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_circle_area_and_perimeter() {
+        let circle = Shape::Circle {
+            radius: 2.0,
+            color: Color::Rgb(255, 0, 0),
+        };
+        assert_close(circle.area(), 4.0 * PI);
+        assert_close(circle.perimeter(), 4.0 * PI);
+        assert_eq!(circle.color(), Color::Rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn test_rectangle_area_and_perimeter() {
+        let rectangle = Shape::Rectangle {
+            width: 10.0,
+            height: 20.0,
+            color: Color::Hsv(240, 100, 100),
+        };
+        assert_close(rectangle.area(), 200.0);
+        assert_close(rectangle.perimeter(), 60.0);
+        assert_eq!(rectangle.color(), Color::Hsv(240, 100, 100));
+    }
+
+    #[test]
+    fn test_triangle_area_and_perimeter() {
+        // 直角边为3和4的直角三角形
+        let triangle = Shape::Triangle {
+            base: 3.0,
+            height: 4.0,
+            sides: [3.0, 4.0, 5.0],
+            color: Color::Rgb(0, 255, 0),
+        };
+        assert_close(triangle.area(), 6.0);
+        assert_close(triangle.perimeter(), 12.0);
+    }
+
+    fn polygon(points: &[(f64, f64)]) -> Polygon {
+        Polygon::new(points.iter().map(|&p| p.into()).collect())
+    }
+
+    #[test]
+    fn test_unit_square_polygon() {
+        let square = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+        assert_close(square.area(), 1.0);
+        assert_close(square.perimeter(), 4.0);
+        assert!(square.is_convex());
+
+        // 顶点顺序反过来面积不变
+        let mut reversed = square.vertices().to_vec();
+        reversed.reverse();
+        assert_close(Polygon::new(reversed).area(), 1.0);
+    }
+
+    #[test]
+    fn test_triangle_polygon() {
+        let triangle = polygon(&[(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)]);
+        assert_close(triangle.area(), 6.0);
+        assert_close(triangle.perimeter(), 12.0);
+        assert!(triangle.is_convex());
+    }
+
+    #[test]
+    fn test_concave_and_degenerate_polygons() {
+        // 凹进去的箭头形状
+        let arrow = polygon(&[(0.0, 0.0), (2.0, 1.0), (4.0, 0.0), (2.0, 4.0)]);
+        assert_close(arrow.area(), 6.0);
+        assert!(!arrow.is_convex());
+
+        assert_eq!(Polygon::default().area(), 0.0);
+        assert_eq!(polygon(&[(0.0, 0.0), (3.0, 4.0)]).area(), 0.0);
+        assert_close(polygon(&[(0.0, 0.0), (3.0, 4.0)]).perimeter(), 10.0);
+        assert!(!polygon(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]).is_convex());
+    }
+
+    #[test]
+    fn test_convex_hull_excludes_interior_points() {
+        let mut points: Vec<Point> = vec![(0.0, 0.0), (4.0, 0.0), (5.0, 3.0), (1.0, 4.0)]
+            .into_iter()
+            .map(Point::from)
+            .collect();
+        // 内部的点、边上的共线点和重复的点都不应出现在凸包中
+        for p in [
+            (2.0, 2.0),
+            (3.0, 1.0),
+            (1.0, 1.0),
+            (2.0, 0.0),
+            (4.0, 0.0),
+            (0.0, 0.0),
+        ] {
+            points.push(p.into());
+        }
+        points.reverse();
+
+        let hull = convex_hull(&points);
+        let expected: Vec<Point> = vec![(0.0, 0.0), (4.0, 0.0), (5.0, 3.0), (1.0, 4.0)]
+            .into_iter()
+            .map(Point::from)
+            .collect();
+        assert_eq!(hull, expected);
+
+        let polygon = Polygon::new(hull);
+        assert!(polygon.is_convex());
+        assert_close(polygon.area(), 14.5);
+    }
+
+    #[test]
+    fn test_convex_hull_degenerate_inputs() {
+        assert!(convex_hull(&[]).is_empty());
+        let single = [Point::new(1.0, 1.0), Point::new(1.0, 1.0)];
+        assert_eq!(convex_hull(&single), vec![Point::new(1.0, 1.0)]);
+
+        // 所有点共线时只剩两个端点
+        let line: Vec<Point> = (0..5)
+            .map(|i| Point::new(i as f64, i as f64 * 2.0))
+            .collect();
+        assert_eq!(
+            convex_hull(&line),
+            vec![Point::new(0.0, 0.0), Point::new(4.0, 8.0)]
+        );
+    }
+
+    /// 测试用的线性同余随机数，返回[0, 100)之间的坐标
+    fn random_points(seed: u64, count: usize) -> Vec<Point> {
+        let mut state = seed;
+        let mut next = move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64 * 100.0
+        };
+        (0..count).map(|_| Point::new(next(), next())).collect()
+    }
+
+    fn brute_force_nearest<'a>(points: &'a [Point], query: &Point) -> Option<&'a Point> {
+        points
+            .iter()
+            .min_by(|a, b| distance_squared(a, query).total_cmp(&distance_squared(b, query)))
+    }
+
+    #[test]
+    fn test_kd_tree_nearest_matches_brute_force() {
+        let points = random_points(42, 500);
+        let tree = KdTree::new(points.clone());
+        assert_eq!(tree.len(), 500);
+
+        for query in random_points(7, 200) {
+            let expected = brute_force_nearest(&points, &query).unwrap();
+            let actual = tree.nearest(&query).unwrap();
+            // 距离相同的点可能不止一个，比较距离即可
+            assert_eq!(
+                distance_squared(actual, &query),
+                distance_squared(expected, &query)
+            );
+        }
+        // 树中已有的点，最近的就是它自己
+        assert_eq!(tree.nearest(&points[123]), Some(&points[123]));
+    }
+
+    #[test]
+    fn test_kd_tree_within_radius_matches_brute_force() {
+        let points = random_points(3, 300);
+        let tree = KdTree::new(points.clone());
+
+        for (query, radius) in random_points(11, 50)
+            .into_iter()
+            .zip([5.0, 12.5, 30.0].iter().cycle())
+        {
+            let mut expected: Vec<&Point> = points
+                .iter()
+                .filter(|p| distance_squared(p, &query) <= radius * radius)
+                .collect();
+            let mut actual = tree.within_radius(&query, *radius);
+            let by_coords = |a: &&Point, b: &&Point| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y));
+            expected.sort_by(by_coords);
+            actual.sort_by(by_coords);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_kd_tree_ties_on_split_axis() {
+        // 整数网格上大量的点共享同一个x或y坐标，与切分线相等的点会落在两侧
+        let points: Vec<Point> = (0..100)
+            .map(|i| Point::new((i % 7) as f64, (i % 5) as f64))
+            .collect();
+        let tree = KdTree::new(points.clone());
+
+        for query in [
+            Point::new(3.0, 2.0),
+            Point::new(3.0, 2.4),
+            Point::new(0.0, 4.0),
+            Point::new(6.5, 0.0),
+        ] {
+            let expected = brute_force_nearest(&points, &query).unwrap();
+            assert_eq!(
+                distance_squared(tree.nearest(&query).unwrap(), &query),
+                distance_squared(expected, &query)
+            );
+            for radius in [0.0, 1.0, 1.5] {
+                let expected = points
+                    .iter()
+                    .filter(|p| distance_squared(p, &query) <= radius * radius)
+                    .count();
+                assert_eq!(tree.within_radius(&query, radius).len(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_kd_tree_empty_and_duplicates() {
+        let empty = KdTree::default();
+        assert!(empty.is_empty());
+        assert_eq!(empty.nearest(&Point::new(1.0, 1.0)), None);
+        assert!(empty.within_radius(&Point::new(1.0, 1.0), 10.0).is_empty());
+
+        let same = KdTree::new(vec![Point::new(2.0, 2.0); 4]);
+        assert_eq!(same.within_radius(&Point::new(2.0, 2.0), 0.0).len(), 4);
+        assert_eq!(
+            same.nearest(&Point::new(0.0, 0.0)),
+            Some(&Point::new(2.0, 2.0))
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! 用HashMap表示图
+//!
+//! HashMap教程（`07_hashmap.rs`）演示了`HashMap<String, Vec<String>>`这样一对多的映射，
+//! 图的邻接表正是这种结构：每个节点映射到它的邻居列表。
+
+use std::collections::HashMap;
+
+/// 由边列表构建邻接表
+///
+/// `directed`为`false`时每条边`(a, b)`同时加入反向边`(b, a)`。
+/// 所有出现过的节点都有一项，没有出边的节点对应空列表；
+/// 邻居按边在输入中的顺序排列，重复的边会重复出现。
+/// 自环`(a, a)`会保留，在无向图中也只记录一次。
+///
+/// ```
+/// use rust_data_structures_tutorial::graph::adjacency_from_edges;
+///
+/// let graph = adjacency_from_edges(&[(0, 1), (0, 2), (2, 2)], true);
+/// assert_eq!(graph[&0], vec![1, 2]);
+/// assert!(graph[&1].is_empty());
+/// assert_eq!(graph[&2], vec![2]);
+/// ```
+pub fn adjacency_from_edges(
+    edges: &[(usize, usize)],
+    directed: bool,
+) -> HashMap<usize, Vec<usize>> {
+    let mut adjacency: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &(from, to) in edges {
+        adjacency.entry(from).or_default().push(to);
+        let reverse = adjacency.entry(to).or_default();
+        if !directed && from != to {
+            reverse.push(from);
+        }
+    }
+    adjacency
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directed_edges() {
+        let graph = adjacency_from_edges(&[(1, 2), (1, 3), (3, 1), (4, 4)], true);
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph[&1], vec![2, 3]);
+        assert_eq!(graph[&2], Vec::<usize>::new());
+        assert_eq!(graph[&3], vec![1]);
+        assert_eq!(graph[&4], vec![4]);
+    }
+
+    #[test]
+    fn test_undirected_edges() {
+        let graph = adjacency_from_edges(&[(1, 2), (2, 3), (3, 3)], false);
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph[&1], vec![2]);
+        assert_eq!(graph[&2], vec![1, 3]);
+        // 自环只记录一次
+        assert_eq!(graph[&3], vec![2, 3]);
+
+        assert!(adjacency_from_edges(&[], false).is_empty());
+    }
+}
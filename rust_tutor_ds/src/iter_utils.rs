@@ -0,0 +1,264 @@
+//! 迭代器工具
+//!
+//! 向量教程（`01_vector.rs`）用`iter()`、`enumerate()`遍历元素，
+//! 这里补充几个标准库没有提供的迭代器适配器，演示如何用结构体保存迭代过程中的状态。
+
+use std::iter::Fuse;
+
+/// [`group_by_adjacent`]返回的迭代器
+#[derive(Debug, Clone)]
+pub struct GroupByAdjacent<I: Iterator, K, F> {
+    iter: I,
+    key_fn: F,
+    // 上一组结束时多读出来的元素，它是下一组的第一个元素
+    pending: Option<(K, I::Item)>,
+}
+
+impl<I, K, F> Iterator for GroupByAdjacent<I, K, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, first) = match self.pending.take() {
+            Some(pending) => pending,
+            None => {
+                let item = self.iter.next()?;
+                ((self.key_fn)(&item), item)
+            }
+        };
+        let mut group = vec![first];
+        for item in self.iter.by_ref() {
+            let item_key = (self.key_fn)(&item);
+            if item_key != key {
+                self.pending = Some((item_key, item));
+                break;
+            }
+            group.push(item);
+        }
+        Some((key, group))
+    }
+}
+
+/// 把相邻的、键相同的元素分为一组，依次产生`(键, 这一组的元素)`
+///
+/// 只合并相邻的元素：同一个键在不相邻的位置再次出现时会成为新的一组。
+///
+/// ```
+/// use rust_data_structures_tutorial::iter_utils::group_by_adjacent;
+///
+/// let words = ["apple", "avocado", "banana", "blueberry", "apricot"];
+/// let groups: Vec<_> = group_by_adjacent(words, |word| word.chars().next().unwrap()).collect();
+/// assert_eq!(groups[0], ('a', vec!["apple", "avocado"]));
+/// assert_eq!(groups[1], ('b', vec!["banana", "blueberry"]));
+/// assert_eq!(groups[2], ('a', vec!["apricot"]));
+/// ```
+pub fn group_by_adjacent<I, K, F>(iter: I, key_fn: F) -> GroupByAdjacent<I::IntoIter, K, F>
+where
+    I: IntoIterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    GroupByAdjacent {
+        iter: iter.into_iter(),
+        key_fn,
+        pending: None,
+    }
+}
+
+/// [`zip_longest`]返回的迭代器
+#[derive(Debug, Clone)]
+pub struct ZipLongest<A, B> {
+    // 用fuse保证较短的一边结束后继续调用next也总是返回None
+    a: Fuse<A>,
+    b: Fuse<B>,
+}
+
+impl<A: Iterator, B: Iterator> Iterator for ZipLongest<A, B> {
+    type Item = (Option<A::Item>, Option<B::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.a.next(), self.b.next()) {
+            (None, None) => None,
+            pair => Some(pair),
+        }
+    }
+}
+
+/// 同时遍历两个迭代器，直到两边都结束；较短的一边结束后用`None`补齐
+///
+/// 标准库的`zip`在较短的一边结束时就停止，多出来的元素会被丢弃。
+///
+/// ```
+/// use rust_data_structures_tutorial::iter_utils::zip_longest;
+///
+/// let pairs: Vec<_> = zip_longest([1, 2, 3], ["一"]).collect();
+/// assert_eq!(pairs, vec![(Some(1), Some("一")), (Some(2), None), (Some(3), None)]);
+/// ```
+pub fn zip_longest<A, B>(a: A, b: B) -> ZipLongest<A::IntoIter, B::IntoIter>
+where
+    A: IntoIterator,
+    B: IntoIterator,
+{
+    ZipLongest {
+        a: a.into_iter().fuse(),
+        b: b.into_iter().fuse(),
+    }
+}
+
+/// 两个集合的笛卡尔积：`a`中的每个元素与`b`中的每个元素组成一对
+///
+/// 结果按`a`的顺序排列，`a`的同一个元素对应的各对再按`b`的顺序排列。
+///
+/// ```
+/// use rust_data_structures_tutorial::iter_utils::cartesian_product;
+///
+/// let pairs = cartesian_product(["红", "蓝"], [1, 2]);
+/// assert_eq!(pairs, vec![("红", 1), ("红", 2), ("蓝", 1), ("蓝", 2)]);
+/// ```
+pub fn cartesian_product<A, B>(
+    a: impl IntoIterator<Item = A>,
+    b: impl IntoIterator<Item = B>,
+) -> Vec<(A, B)>
+where
+    A: Clone,
+    B: Clone,
+{
+    // b需要遍历多次，先收集起来
+    let b: Vec<B> = b.into_iter().collect();
+    let mut result = Vec::new();
+    for left in a {
+        for right in &b {
+            result.push((left.clone(), right.clone()));
+        }
+    }
+    result
+}
+
+/// 依次产生到当前元素为止所有元素的平均值
+///
+/// 只保存已读元素的总和与个数，不需要把元素存起来。
+///
+/// ```
+/// use rust_data_structures_tutorial::iter_utils::RunningAverage;
+///
+/// let averages: Vec<f64> = RunningAverage::new([10.0, 20.0, 60.0]).collect();
+/// assert_eq!(averages, vec![10.0, 15.0, 30.0]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RunningAverage<I> {
+    iter: I,
+    sum: f64,
+    count: u64,
+}
+
+impl<I: Iterator<Item = f64>> RunningAverage<I> {
+    /// 包装一个产生`f64`的迭代器
+    pub fn new(iter: impl IntoIterator<IntoIter = I>) -> Self {
+        RunningAverage {
+            iter: iter.into_iter(),
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    /// 目前为止读取的元素个数
+    pub fn count_so_far(&self) -> u64 {
+        self.count
+    }
+}
+
+impl<I: Iterator<Item = f64>> Iterator for RunningAverage<I> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        let value = self.iter.next()?;
+        self.sum += value;
+        self.count += 1;
+        Some(self.sum / self.count as f64)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // 每个元素恰好产生一个平均值
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_runs_by_identity() {
+        let groups: Vec<(i32, Vec<i32>)> =
+            group_by_adjacent([1, 1, 2, 3, 3, 3, 1], |&n| n).collect();
+        assert_eq!(
+            groups,
+            vec![
+                (1, vec![1, 1]),
+                (2, vec![2]),
+                (3, vec![3, 3, 3]),
+                (1, vec![1]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_by_derived_key() {
+        // 按奇偶分组
+        let groups: Vec<_> = group_by_adjacent(vec![2, 4, 1, 3, 5, 6], |n| n % 2 == 0)
+            .map(|(even, items)| (even, items.len()))
+            .collect();
+        assert_eq!(groups, vec![(true, 2), (false, 3), (true, 1)]);
+
+        let empty: Vec<(i32, Vec<i32>)> = group_by_adjacent(Vec::<i32>::new(), |&n| n).collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_zip_longest_unequal_lengths() {
+        let pairs: Vec<_> = zip_longest(vec!['a', 'b'], 1..=4).collect();
+        assert_eq!(
+            pairs,
+            vec![
+                (Some('a'), Some(1)),
+                (Some('b'), Some(2)),
+                (None, Some(3)),
+                (None, Some(4)),
+            ]
+        );
+
+        let mut both_empty = zip_longest(Vec::<i32>::new(), "".chars());
+        assert_eq!(both_empty.next(), None);
+    }
+
+    #[test]
+    fn test_cartesian_product_two_by_three() {
+        let product = cartesian_product(vec![1, 2], vec!["x", "y", "z"]);
+        assert_eq!(
+            product,
+            vec![(1, "x"), (1, "y"), (1, "z"), (2, "x"), (2, "y"), (2, "z"),]
+        );
+        assert!(cartesian_product(vec![1, 2], Vec::<i32>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_running_average() {
+        let averages: Vec<f64> = RunningAverage::new(vec![2.0, 4.0, 6.0]).collect();
+        assert_eq!(averages, vec![2.0, 3.0, 4.0]);
+
+        let mut empty = RunningAverage::new(std::iter::empty());
+        assert_eq!(empty.next(), None);
+        assert_eq!(empty.count_so_far(), 0);
+    }
+
+    #[test]
+    fn test_running_average_is_lazy() {
+        let mut averages = RunningAverage::new((1..).map(|n| n as f64));
+        assert_eq!(averages.nth(9), Some(5.5));
+        assert_eq!(averages.count_so_far(), 10);
+    }
+}
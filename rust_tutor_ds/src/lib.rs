@@ -38,6 +38,12 @@
 //! cargo run --example
 //! ```
 //!
+//! ### 在代码中运行教程
+//! ```no_run
+//! // 名字与上面的示例名一致
+//! rust_data_structures_tutorial::examples::run("hashmap").unwrap();
+//! ```
+//!
 //! ## 教程结构
 //!
 //! 本教程按照从基础到高级的顺序组织：
@@ -62,8 +68,13 @@
 pub mod data_structures {
     //! 数据结构相关的工具函数和类型定义
     
+    pub use crate::student::{CsvLineError, StudentBuilder, StudentError, StudentRoster};
+    
     /// 用于演示的学生信息结构体
-    #[derive(Debug, Clone)]
+    ///
+    /// 默认值是名字为空、年龄为0、成绩为0.0的学生
+    #[derive(Debug, Clone, PartialEq, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct Student {
         pub name: String,
         pub age: u32,
@@ -82,8 +93,9 @@ pub mod data_structures {
         }
     }
     
-    /// 用于演示的坐标点结构体
-    #[derive(Debug, Clone, PartialEq)]
+    /// 用于演示的坐标点结构体，默认值是原点
+    #[derive(Debug, Clone, PartialEq, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct Point {
         pub x: f64,
         pub y: f64,
@@ -100,10 +112,126 @@ pub mod data_structures {
             (self.x * self.x + self.y * self.y).sqrt()
         }
     }
+    
+    /// 从`(x, y)`元组创建坐标点
+    ///
+    /// ```
+    /// use rust_data_structures_tutorial::data_structures::Point;
+    ///
+    /// let p: Point = (3.0, 4.0).into();
+    /// assert_eq!(p.distance_from_origin(), 5.0);
+    /// let (x, y): (f64, f64) = p.into();
+    /// assert_eq!((x, y), (3.0, 4.0));
+    /// ```
+    impl From<(f64, f64)> for Point {
+        fn from((x, y): (f64, f64)) -> Self {
+            Point::new(x, y)
+        }
+    }
+    
+    /// 从`[x, y]`数组创建坐标点
+    impl From<[f64; 2]> for Point {
+        fn from([x, y]: [f64; 2]) -> Self {
+            Point::new(x, y)
+        }
+    }
+    
+    /// 把坐标点拆成`(x, y)`元组
+    impl From<Point> for (f64, f64) {
+        fn from(point: Point) -> Self {
+            (point.x, point.y)
+        }
+    }
+    
+    /// 把值序列化为JSON字符串（需要开启`serde`特性）
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde")] {
+    /// use rust_data_structures_tutorial::data_structures::{from_json, to_json, Point};
+    ///
+    /// let json = to_json(&Point::new(3.0, 4.0)).unwrap();
+    /// assert_eq!(json, r#"{"x":3.0,"y":4.0}"#);
+    /// assert_eq!(from_json::<Point>(&json).unwrap(), Point::new(3.0, 4.0));
+    /// # }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json<T: serde::Serialize>(value: &T) -> serde_json::Result<String> {
+        serde_json::to_string(value)
+    }
+    
+    /// 从JSON字符串反序列化（需要开启`serde`特性）
+    #[cfg(feature = "serde")]
+    pub fn from_json<T: serde::de::DeserializeOwned>(json: &str) -> serde_json::Result<T> {
+        serde_json::from_str(json)
+    }
 }
 
+pub mod auth;
+pub mod collections;
+pub mod content;
+pub mod encoding;
+pub mod geometry;
+pub mod graph;
+pub mod iter_utils;
+pub mod math;
+pub mod search;
+pub mod sorting;
+pub mod student;
+pub mod tasks;
+pub mod text;
+pub mod traffic;
+pub mod units;
+
 pub mod examples {
-    //! 示例代码的工具函数
+    //! 示例代码的工具函数，以及按名字运行各个教程的入口
+    //!
+    //! 每个教程的正文是这里的一个子模块，`examples/`下的示例程序只是调用对应的`run`，
+    //! 这样就可以在其他程序中嵌入教程，也可以在测试中检查教程能完整运行。
+    
+    pub mod array_tuple;
+    pub mod basic_types;
+    pub mod enums;
+    pub mod hashmap;
+    pub mod hashset;
+    pub mod string;
+    pub mod structs;
+    pub mod vector;
+    
+    /// 所有教程的名字，与`cargo run --example <名字>`中的名字一致
+    pub const NAMES: [&str; 8] = [
+        "vector",
+        "basic_types",
+        "string",
+        "array_tuple",
+        "struct",
+        "enum",
+        "hashmap",
+        "hashset",
+    ];
+    
+    /// 按名字运行教程，名字不存在时返回错误
+    ///
+    /// ```no_run
+    /// use rust_data_structures_tutorial::examples;
+    ///
+    /// examples::run("enum").unwrap();
+    /// assert!(examples::run("linked_list").is_err());
+    /// ```
+    pub fn run(name: &str) -> Result<(), String> {
+        let tutorial: fn() = match name {
+            "vector" => vector::run,
+            "basic_types" => basic_types::run,
+            "string" => string::run,
+            "array_tuple" => array_tuple::run,
+            "struct" => structs::run,
+            "enum" => enums::run,
+            "hashmap" => hashmap::run,
+            "hashset" => hashset::run,
+            _ => return Err(format!("未知的教程: {}（可选: {}）", name, NAMES.join(", "))),
+        };
+        tutorial();
+        Ok(())
+    }
     
     /// 打印分隔线，用于美化输出
     pub fn print_separator(title: &str) {
@@ -133,6 +261,14 @@ mod tests {
         assert!(student.is_passing());
     }
     
+    #[test]
+    fn test_run_every_example() {
+        for name in examples::NAMES {
+            assert_eq!(examples::run(name), Ok(()), "教程{}应该能完整运行", name);
+        }
+        assert!(examples::run("linked_list").unwrap_err().contains("未知的教程"));
+    }
+    
     #[test]
     fn test_point_creation() {
         let point = data_structures::Point::new(3.0, 4.0);
@@ -140,4 +276,57 @@ mod tests {
         assert_eq!(point.y, 4.0);
         assert_eq!(point.distance_from_origin(), 5.0);
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn test_point_conversions() {
+        use data_structures::Point;
+        
+        let from_tuple: Point = (1.5, -2.0).into();
+        assert_eq!(from_tuple, Point::new(1.5, -2.0));
+        
+        let from_array = Point::from([1.5, -2.0]);
+        assert_eq!(from_array, from_tuple);
+        
+        let tuple: (f64, f64) = from_array.into();
+        assert_eq!(tuple, (1.5, -2.0));
+        assert_eq!(Point::from(tuple), from_tuple);
+    }
+    
+    #[test]
+    fn test_default_values() {
+        let student = data_structures::Student::default();
+        assert_eq!(student.name, "");
+        assert_eq!(student.age, 0);
+        assert_eq!(student.grade, 0.0);
+        assert!(!student.is_passing());
+        
+        let origin = data_structures::Point::default();
+        assert_eq!(origin, data_structures::Point::new(0.0, 0.0));
+        assert_eq!(origin.distance_from_origin(), 0.0);
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_student_json_round_trip() {
+        let student = data_structures::Student::new("张三".to_string(), 20, 85.5);
+        let json = data_structures::to_json(&student).unwrap();
+        assert_eq!(json, r#"{"name":"张三","age":20,"grade":85.5}"#);
+        
+        let back: data_structures::Student = data_structures::from_json(&json).unwrap();
+        assert_eq!(back.name, student.name);
+        assert_eq!(back.age, student.age);
+        assert_eq!(back.grade, student.grade);
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_point_json_round_trip() {
+        let point = data_structures::Point::new(-1.5, 2.25);
+        let json = data_structures::to_json(&point).unwrap();
+        let back: data_structures::Point = data_structures::from_json(&json).unwrap();
+        assert_eq!(back, point);
+        
+        // 缺少字段时反序列化失败
+        assert!(data_structures::from_json::<data_structures::Point>(r#"{"x":1.0}"#).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,173 @@
+//! 带自定义错误类型的数学运算
+//!
+//! 来自枚举教程（`06_enum.rs`）中的`MathError`。示例中的错误枚举只能用`{:?}`打印，
+//! 这里为它实现了`Display`和`std::error::Error`，因此可以通过`?`
+//! 转换成`Box<dyn Error>`；解析失败的错误还能用`source()`查看底层原因。
+
+use std::error::Error;
+use std::fmt;
+use std::num::ParseFloatError;
+
+/// 数学运算可能出现的错误
+#[derive(Debug, Clone, PartialEq)]
+pub enum MathError {
+    /// 除数为零
+    DivisionByZero,
+    /// 对负数开平方根
+    NegativeSquareRoot,
+    /// 输入不合法，附带说明
+    InvalidInput(String),
+    /// 输入无法解析为数字，`source()`返回解析时的底层错误
+    InvalidNumber {
+        input: String,
+        source: ParseFloatError,
+    },
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::DivisionByZero => write!(f, "除数不能为零"),
+            MathError::NegativeSquareRoot => write!(f, "不能对负数开平方根"),
+            MathError::InvalidInput(message) => write!(f, "无效输入: {}", message),
+            MathError::InvalidNumber { input, .. } => {
+                write!(f, "无效输入: 无法把{:?}解析为数字", input)
+            }
+        }
+    }
+}
+
+impl Error for MathError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MathError::InvalidNumber { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// 除法，除数为零时返回错误
+///
+/// ```
+/// use rust_data_structures_tutorial::math::{divide, MathError};
+///
+/// assert_eq!(divide(10.0, 4.0).unwrap(), 2.5);
+/// assert!(matches!(divide(1.0, 0.0), Err(MathError::DivisionByZero)));
+/// ```
+pub fn divide(a: f64, b: f64) -> Result<f64, MathError> {
+    check_number(a)?;
+    check_number(b)?;
+    if b == 0.0 {
+        Err(MathError::DivisionByZero)
+    } else {
+        Ok(a / b)
+    }
+}
+
+/// 平方根，负数时返回错误
+pub fn sqrt(x: f64) -> Result<f64, MathError> {
+    check_number(x)?;
+    if x < 0.0 {
+        Err(MathError::NegativeSquareRoot)
+    } else {
+        Ok(x.sqrt())
+    }
+}
+
+/// 把字符串解析为数字，解析失败时把`ParseFloatError`作为错误的底层原因
+///
+/// ```
+/// use std::error::Error;
+/// use rust_data_structures_tutorial::math::parse_number;
+///
+/// let err = parse_number("abc").unwrap_err();
+/// assert_eq!(err.to_string(), "无效输入: 无法把\"abc\"解析为数字");
+/// assert!(err.source().is_some());
+/// ```
+pub fn parse_number(input: &str) -> Result<f64, MathError> {
+    let value = input
+        .trim()
+        .parse::<f64>()
+        .map_err(|source| MathError::InvalidNumber {
+            input: input.to_string(),
+            source,
+        })?;
+    check_number(value)?;
+    Ok(value)
+}
+
+/// NaN和无穷大不是合法的运算输入
+fn check_number(x: f64) -> Result<(), MathError> {
+    if x.is_finite() {
+        Ok(())
+    } else {
+        Err(MathError::InvalidInput(format!("{}不是有限的数字", x)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_divide_and_sqrt() {
+        assert_eq!(divide(10.0, 2.0).unwrap(), 5.0);
+        assert!(matches!(divide(10.0, 0.0), Err(MathError::DivisionByZero)));
+        assert_eq!(sqrt(16.0).unwrap(), 4.0);
+        assert!(matches!(sqrt(-4.0), Err(MathError::NegativeSquareRoot)));
+        assert!(matches!(sqrt(f64::NAN), Err(MathError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_error_messages() {
+        assert_eq!(MathError::DivisionByZero.to_string(), "除数不能为零");
+        assert_eq!(
+            MathError::NegativeSquareRoot.to_string(),
+            "不能对负数开平方根"
+        );
+        assert_eq!(
+            MathError::InvalidInput("缺少参数".to_string()).to_string(),
+            "无效输入: 缺少参数"
+        );
+        assert_eq!(
+            parse_number("abc").unwrap_err().to_string(),
+            "无效输入: 无法把\"abc\"解析为数字"
+        );
+        assert_eq!(
+            divide(f64::INFINITY, 1.0).unwrap_err().to_string(),
+            "无效输入: inf不是有限的数字"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input_source() {
+        assert!(MathError::InvalidInput("缺少参数".to_string())
+            .source()
+            .is_none());
+        assert!(MathError::DivisionByZero.source().is_none());
+
+        let err = parse_number("12x").unwrap_err();
+        assert!(matches!(&err, MathError::InvalidNumber { input, .. } if input == "12x"));
+        let source = err.source().expect("解析错误应该作为底层原因保留");
+        assert_eq!(source.to_string(), "invalid float literal");
+    }
+
+    #[test]
+    fn test_question_mark_into_box_dyn_error() {
+        fn hypotenuse_ratio(a: &str, b: &str) -> Result<f64, Box<dyn Error>> {
+            let a = parse_number(a)?;
+            let b = parse_number(b)?;
+            let hypotenuse = sqrt(a * a + b * b)?;
+            Ok(divide(a, hypotenuse)?)
+        }
+
+        assert_eq!(hypotenuse_ratio("3", "4").unwrap(), 0.6);
+
+        let err = hypotenuse_ratio("0", "0").unwrap_err();
+        assert_eq!(err.to_string(), "除数不能为零");
+        assert!(err.downcast_ref::<MathError>().is_some());
+
+        let err = hypotenuse_ratio("three", "4").unwrap_err();
+        assert!(err.source().is_some());
+    }
+}
@@ -0,0 +1,134 @@
+//! 二分查找
+//!
+//! 标准库的`binary_search`系列方法只在有序切片上使用。这里把二分查找显式地写出来，
+//! 并补充`lower_bound`、`upper_bound`：有重复元素时，它们分别给出等于`key`的
+//! 第一个元素和最后一个元素之后的位置，两者之间就是所有等于`key`的元素。
+
+use std::cmp::Ordering;
+
+/// 在有序切片上二分查找，语义与`slice::binary_search_by`相同
+///
+/// `f`返回元素与目标的比较结果（元素小于目标时返回`Less`）。
+/// 找到时返回`Ok(下标)`，有多个匹配时可能返回其中任意一个；
+/// 找不到时返回`Err(下标)`，在这个位置插入目标可以保持切片有序。
+///
+/// ```
+/// use rust_data_structures_tutorial::search::binary_search_by;
+///
+/// let primes = [2, 3, 5, 7, 11, 13];
+/// assert_eq!(binary_search_by(&primes, |p| p.cmp(&7)), Ok(3));
+/// assert_eq!(binary_search_by(&primes, |p| p.cmp(&8)), Err(4));
+/// ```
+pub fn binary_search_by<T, F>(slice: &[T], mut f: F) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    // 目标如果存在，一定在[low, high)之间
+    let (mut low, mut high) = (0, slice.len());
+    while low < high {
+        // 写成low + (high - low) / 2而不是(low + high) / 2，避免溢出
+        let mid = low + (high - low) / 2;
+        match f(&slice[mid]) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Ok(mid),
+        }
+    }
+    Err(low)
+}
+
+/// 第一个大于或等于`key`的元素的下标（没有时返回`slice.len()`）
+///
+/// 也就是把`key`插入到所有相等元素之前的位置。
+///
+/// ```
+/// use rust_data_structures_tutorial::search::{lower_bound, upper_bound};
+///
+/// let scores = [60, 75, 75, 75, 90];
+/// assert_eq!(lower_bound(&scores, &75), 1);
+/// assert_eq!(upper_bound(&scores, &75), 4);
+/// // 两者之差就是75出现的次数
+/// assert_eq!(upper_bound(&scores, &75) - lower_bound(&scores, &75), 3);
+/// ```
+pub fn lower_bound<T: Ord>(slice: &[T], key: &T) -> usize {
+    partition_point(slice, |item| item < key)
+}
+
+/// 第一个大于`key`的元素的下标（没有时返回`slice.len()`）
+///
+/// 也就是把`key`插入到所有相等元素之后的位置。
+pub fn upper_bound<T: Ord>(slice: &[T], key: &T) -> usize {
+    partition_point(slice, |item| item <= key)
+}
+
+/// 切片的前一部分满足`pred`、后一部分不满足时，返回第一个不满足的下标
+fn partition_point<T>(slice: &[T], mut pred: impl FnMut(&T) -> bool) -> usize {
+    let (mut low, mut high) = (0, slice.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        // 与binary_search_by不同，遇到相等的元素也不会提前返回，而是继续收缩范围
+        if pred(&slice[mid]) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_search_by_matches_std() {
+        let slice = [1, 3, 3, 5, 8, 13, 21];
+        for target in 0..=22 {
+            let ours = binary_search_by(&slice, |x| x.cmp(&target));
+            let std = slice.binary_search_by(|x| x.cmp(&target));
+            // 有重复元素时两者可能返回不同的下标，只比较是否找到以及找到的值
+            match (ours, std) {
+                (Ok(i), Ok(_)) => assert_eq!(slice[i], target),
+                (ours, std) => assert_eq!(ours, std, "目标 {}", target),
+            }
+        }
+        assert_eq!(binary_search_by(&[] as &[i32], |x| x.cmp(&1)), Err(0));
+    }
+
+    #[test]
+    fn test_binary_search_by_key_function() {
+        // 按元组的第二个字段查找
+        let people = [("小明", 18), ("小红", 20), ("小刚", 25)];
+        assert_eq!(binary_search_by(&people, |p| p.1.cmp(&20)), Ok(1));
+        assert_eq!(binary_search_by(&people, |p| p.1.cmp(&30)), Err(3));
+    }
+
+    #[test]
+    fn test_bounds_with_duplicates() {
+        let slice = [1, 2, 2, 2, 3, 5, 5];
+        assert_eq!((lower_bound(&slice, &2), upper_bound(&slice, &2)), (1, 4));
+        assert_eq!((lower_bound(&slice, &5), upper_bound(&slice, &5)), (5, 7));
+        // 不存在的key：两个边界相同，都是插入位置
+        assert_eq!((lower_bound(&slice, &4), upper_bound(&slice, &4)), (5, 5));
+        assert_eq!((lower_bound(&slice, &0), upper_bound(&slice, &0)), (0, 0));
+        assert_eq!((lower_bound(&slice, &9), upper_bound(&slice, &9)), (7, 7));
+        assert_eq!(lower_bound(&[] as &[i32], &1), 0);
+    }
+
+    #[test]
+    fn test_bounds_match_std_partition_point() {
+        let slice: Vec<u32> = (0..200).map(|i| i / 7).collect();
+        for key in 0..=30 {
+            assert_eq!(
+                lower_bound(&slice, &key),
+                slice.partition_point(|&x| x < key)
+            );
+            assert_eq!(
+                upper_bound(&slice, &key),
+                slice.partition_point(|&x| x <= key)
+            );
+            let count = slice.iter().filter(|&&x| x == key).count();
+            assert_eq!(upper_bound(&slice, &key) - lower_bound(&slice, &key), count);
+        }
+    }
+}
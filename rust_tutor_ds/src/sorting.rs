@@ -0,0 +1,232 @@
+//! 排序算法
+//!
+//! Vector教程（`01_vector.rs`）直接调用`sort`完成排序，这里手写两种经典的排序算法，
+//! 展示切片的拆分（`split_at`、`split_at_mut`）和元素交换（`swap`）。
+//! 实际代码中应该使用标准库的`sort`（稳定）和`sort_unstable`（不稳定）。
+
+/// 归并排序，返回排好序的新Vec，原切片不变
+///
+/// 把切片从中间拆成两半分别排序，再合并两个有序序列。时间复杂度总是O(n log n)，
+/// 需要O(n)的额外空间。
+///
+/// 排序是**稳定**的：相等的元素保持原来的相对顺序（合并时相等的元素先取左半边的）。
+///
+/// ```
+/// use rust_data_structures_tutorial::sorting::merge_sort;
+///
+/// let numbers = [5, 2, 9, 1, 5, 6];
+/// assert_eq!(merge_sort(&numbers), vec![1, 2, 5, 5, 6, 9]);
+/// ```
+pub fn merge_sort<T: Ord + Clone>(slice: &[T]) -> Vec<T> {
+    if slice.len() <= 1 {
+        return slice.to_vec();
+    }
+    let (left, right) = slice.split_at(slice.len() / 2);
+    merge(merge_sort(left), merge_sort(right))
+}
+
+/// 合并两个有序的Vec
+fn merge<T: Ord>(left: Vec<T>, right: Vec<T>) -> Vec<T> {
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+        // 只有右边严格更小时才取右边，这保证了稳定性
+        let next = if r < l { right.next() } else { left.next() };
+        merged.extend(next);
+    }
+    // 其中一边已经取完，另一边剩下的元素都更大，直接追加
+    merged.extend(left);
+    merged.extend(right);
+    merged
+}
+
+/// 快速排序，原地排序
+///
+/// 选一个基准值（pivot），把切片分成小于、等于、大于基准值的三段，再递归排序两端。
+/// 基准值取首、中、尾三个元素的中位数，已经有序或逆序的输入也不会退化；
+/// 三段划分让大量重复元素的输入同样高效。平均时间复杂度O(n log n)。
+///
+/// 排序**不稳定**：相等的元素在交换过程中可能改变相对顺序。
+///
+/// ```
+/// use rust_data_structures_tutorial::sorting::quicksort;
+///
+/// let mut words = ["pear", "apple", "fig", "banana"];
+/// quicksort(&mut words);
+/// assert_eq!(words, ["apple", "banana", "fig", "pear"]);
+/// ```
+pub fn quicksort<T: Ord>(mut slice: &mut [T]) {
+    while slice.len() > 1 {
+        let (less_end, greater_start) = partition(slice);
+        let (less, rest) = slice.split_at_mut(less_end);
+        let greater = &mut rest[greater_start - less_end..];
+        // 递归处理较短的一段，较长的一段留给循环，递归深度最多O(log n)
+        if less.len() < greater.len() {
+            quicksort(less);
+            slice = greater;
+        } else {
+            quicksort(greater);
+            slice = less;
+        }
+    }
+}
+
+/// 三段划分，返回`(lt, gt)`：`[..lt]`小于基准值，`[lt..gt]`等于基准值，`[gt..]`大于基准值
+fn partition<T: Ord>(slice: &mut [T]) -> (usize, usize) {
+    let last = slice.len() - 1;
+    let mid = slice.len() / 2;
+    // 让首、中、尾三个元素有序，中位数就在mid，然后把它换到开头作为基准值
+    if slice[mid] < slice[0] {
+        slice.swap(mid, 0);
+    }
+    if slice[last] < slice[mid] {
+        slice.swap(last, mid);
+        if slice[mid] < slice[0] {
+            slice.swap(mid, 0);
+        }
+    }
+    slice.swap(0, mid);
+
+    // 基准值固定在下标0，[1..lt]小于、[lt..i]等于、[i..gt]未处理、[gt..]大于基准值
+    let (mut lt, mut i, mut gt) = (1, 1, slice.len());
+    while i < gt {
+        match slice[i].cmp(&slice[0]) {
+            std::cmp::Ordering::Less => {
+                slice.swap(i, lt);
+                lt += 1;
+                i += 1;
+            }
+            std::cmp::Ordering::Equal => i += 1,
+            std::cmp::Ordering::Greater => {
+                gt -= 1;
+                slice.swap(i, gt);
+            }
+        }
+    }
+    // 把基准值放到“小于”和“等于”两段之间
+    slice.swap(0, lt - 1);
+    (lt - 1, gt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cmp::Ordering;
+
+    fn random_numbers(seed: u64, count: usize, max: u64) -> Vec<u64> {
+        let mut state = seed;
+        (0..count)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (state >> 33) % max
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_matches_std_sort_on_random_input() {
+        // max较小时会有大量重复元素
+        for (seed, count, max) in [
+            (1, 100, 1000),
+            (2, 1000, 10),
+            (3, 257, 2),
+            (4, 5000, u64::MAX),
+        ] {
+            let numbers = random_numbers(seed, count, max);
+            let mut expected = numbers.clone();
+            expected.sort();
+
+            assert_eq!(merge_sort(&numbers), expected);
+            let mut quick = numbers.clone();
+            quicksort(&mut quick);
+            assert_eq!(quick, expected);
+        }
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let inputs: [Vec<i32>; 6] = [
+            vec![],
+            vec![42],
+            vec![1, 2, 3, 4, 5, 6, 7, 8],
+            vec![8, 7, 6, 5, 4, 3, 2, 1],
+            vec![3; 20],
+            vec![i32::MAX, i32::MIN, 0, -1, 1],
+        ];
+        for input in inputs {
+            let mut expected = input.clone();
+            expected.sort();
+            assert_eq!(merge_sort(&input), expected);
+            let mut quick = input.clone();
+            quicksort(&mut quick);
+            assert_eq!(quick, expected, "输入: {:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_large_sorted_input_does_not_degrade() {
+        // 已经有序和逆序的输入在朴素的“取第一个元素作基准”实现中会退化成O(n²)
+        let mut ascending: Vec<u32> = (0..100_000).collect();
+        quicksort(&mut ascending);
+        assert!(ascending.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        let mut descending: Vec<u32> = (0..100_000).rev().collect();
+        quicksort(&mut descending);
+        assert_eq!(descending, ascending);
+    }
+
+    /// 只按key比较的记录，order记录它在输入中的位置，用来观察相等元素的相对顺序
+    #[derive(Debug, Clone)]
+    struct Record {
+        key: u64,
+        order: usize,
+    }
+
+    impl PartialEq for Record {
+        fn eq(&self, other: &Self) -> bool {
+            self.key == other.key
+        }
+    }
+
+    impl Eq for Record {}
+
+    impl PartialOrd for Record {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Record {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.key.cmp(&other.key)
+        }
+    }
+
+    #[test]
+    fn test_merge_sort_is_stable() {
+        let records: Vec<Record> = random_numbers(9, 500, 5)
+            .into_iter()
+            .enumerate()
+            .map(|(order, key)| Record { key, order })
+            .collect();
+
+        let orders = |records: &[Record]| records.iter().map(|r| r.order).collect::<Vec<_>>();
+
+        let sorted = merge_sort(&records);
+        // 与标准库的稳定排序结果完全相同，包括相等元素的顺序
+        let mut expected = records.clone();
+        expected.sort();
+        assert_eq!(orders(&sorted), orders(&expected));
+        assert!(sorted
+            .windows(2)
+            .all(|pair| pair[0].key < pair[1].key || pair[0].order < pair[1].order));
+
+        // 快速排序同样按key有序，但不保证order的顺序
+        let mut quick = records.clone();
+        quicksort(&mut quick);
+        assert!(quick.windows(2).all(|pair| pair[0].key <= pair[1].key));
+    }
+}
@@ -0,0 +1,468 @@
+//! 学生信息的构建与CSV读写
+//!
+//! 结构体教程（`05_structs.rs`）用到的[`Student`]定义在`data_structures`模块中，
+//! 这里为它补充校验字段的构建器[`StudentBuilder`]，以及与CSV文本的互相转换：
+//! 单行的`name,age,grade`和带表头的学生名单。
+//! 名字中可能含有逗号和引号，所以拆分字段时需要处理双引号括起来的字段。
+
+use crate::data_structures::Student;
+use std::error::Error;
+use std::fmt;
+
+/// 解析学生CSV时的错误
+#[derive(Debug, Clone, PartialEq)]
+pub enum StudentError {
+    /// 字段数量不是3个，记录实际的字段数量
+    FieldCount(usize),
+    /// 年龄不是合法的非负整数
+    InvalidAge(String),
+    /// 成绩不是合法的数字
+    InvalidGrade(String),
+    /// 引号没有闭合，或者闭合引号后面还有其他字符
+    BadQuote,
+    /// 构建器缺少必填字段，记录字段名
+    MissingField(&'static str),
+}
+
+impl fmt::Display for StudentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StudentError::FieldCount(count) => write!(f, "应该有3个字段，实际有{}个", count),
+            StudentError::InvalidAge(age) => write!(f, "无效的年龄: {}", age),
+            StudentError::InvalidGrade(grade) => write!(f, "无效的成绩: {}", grade),
+            StudentError::BadQuote => write!(f, "引号不匹配"),
+            StudentError::MissingField(field) => write!(f, "缺少字段: {}", field),
+        }
+    }
+}
+
+impl Error for StudentError {}
+
+/// 学生的构建器，逐个设置字段，最后由`build`统一校验
+///
+/// ```
+/// use rust_data_structures_tutorial::student::{StudentBuilder, StudentError};
+///
+/// let student = StudentBuilder::new().name("张三").age(20).grade(85.5).build().unwrap();
+/// assert_eq!(student.name, "张三");
+///
+/// let missing = StudentBuilder::new().name("李四").grade(90.0).build();
+/// assert_eq!(missing, Err(StudentError::MissingField("age")));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StudentBuilder {
+    name: Option<String>,
+    age: Option<u32>,
+    grade: Option<f64>,
+}
+
+impl StudentBuilder {
+    /// 合法年龄的上限
+    pub const MAX_AGE: u32 = 150;
+    /// 合法成绩的上限，成绩的范围是0到100
+    pub const MAX_GRADE: f64 = 100.0;
+
+    /// 创建所有字段都未设置的构建器
+    pub fn new() -> Self {
+        StudentBuilder::default()
+    }
+
+    /// 设置名字（必填，不能为空白）
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// 设置年龄（必填，不能超过`MAX_AGE`）
+    pub fn age(mut self, age: u32) -> Self {
+        self.age = Some(age);
+        self
+    }
+
+    /// 设置成绩（必填，范围是0到`MAX_GRADE`）
+    pub fn grade(mut self, grade: f64) -> Self {
+        self.grade = Some(grade);
+        self
+    }
+
+    /// 校验所有字段并创建学生，按名字、年龄、成绩的顺序报告第一个错误
+    pub fn build(self) -> Result<Student, StudentError> {
+        let name = self
+            .name
+            .filter(|name| !name.trim().is_empty())
+            .ok_or(StudentError::MissingField("name"))?;
+        let age = self.age.ok_or(StudentError::MissingField("age"))?;
+        if age > Self::MAX_AGE {
+            return Err(StudentError::InvalidAge(age.to_string()));
+        }
+        let grade = self.grade.ok_or(StudentError::MissingField("grade"))?;
+        if !(0.0..=Self::MAX_GRADE).contains(&grade) {
+            return Err(StudentError::InvalidGrade(grade.to_string()));
+        }
+        Ok(Student::new(name, age, grade))
+    }
+}
+
+impl Student {
+    /// 创建校验字段的构建器，等价于[`StudentBuilder::new`]
+    pub fn builder() -> StudentBuilder {
+        StudentBuilder::new()
+    }
+
+    /// 从一行`name,age,grade`格式的CSV解析学生
+    ///
+    /// 名字中含有逗号时可以用双引号括起来，引号内的`""`表示一个双引号。
+    ///
+    /// ```
+    /// use rust_data_structures_tutorial::data_structures::Student;
+    /// use rust_data_structures_tutorial::student::StudentError;
+    ///
+    /// let student = Student::from_csv_line("\"王, 小明\",20,88.5").unwrap();
+    /// assert_eq!(student.name, "王, 小明");
+    /// assert_eq!(student.to_csv_line(), "\"王, 小明\",20,88.5");
+    ///
+    /// assert!(matches!(Student::from_csv_line("张三,二十,90"), Err(StudentError::InvalidAge(_))));
+    /// ```
+    pub fn from_csv_line(line: &str) -> Result<Student, StudentError> {
+        let fields = split_csv_fields(line)?;
+        if fields.len() != 3 {
+            return Err(StudentError::FieldCount(fields.len()));
+        }
+        let age = fields[1].trim();
+        let age = age
+            .parse()
+            .map_err(|_| StudentError::InvalidAge(age.to_string()))?;
+        let grade = fields[2].trim();
+        let grade = grade
+            .parse::<f64>()
+            .ok()
+            .filter(|grade| grade.is_finite())
+            .ok_or_else(|| StudentError::InvalidGrade(grade.to_string()))?;
+        Ok(Student::new(fields[0].clone(), age, grade))
+    }
+
+    /// 转换为一行CSV，名字含有逗号、引号或换行时加上双引号
+    pub fn to_csv_line(&self) -> String {
+        let needs_quotes = self.name.contains([',', '"', '\n', '\r']);
+        let name = if needs_quotes {
+            format!("\"{}\"", self.name.replace('"', "\"\""))
+        } else {
+            self.name.clone()
+        };
+        format!("{},{},{}", name, self.age, self.grade)
+    }
+}
+
+/// 按逗号拆分一行CSV，处理双引号括起来的字段
+fn split_csv_fields(line: &str) -> Result<Vec<String>, StudentError> {
+    let mut fields = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        let mut field = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some('"') => break,
+                    Some(c) => field.push(c),
+                    None => return Err(StudentError::BadQuote),
+                }
+            }
+            match chars.next() {
+                Some(',') => fields.push(field),
+                None => {
+                    fields.push(field);
+                    return Ok(fields);
+                }
+                Some(_) => return Err(StudentError::BadQuote),
+            }
+        } else {
+            loop {
+                match chars.next() {
+                    Some(',') => break,
+                    Some('"') => return Err(StudentError::BadQuote),
+                    Some(c) => field.push(c),
+                    None => {
+                        fields.push(field);
+                        return Ok(fields);
+                    }
+                }
+            }
+            fields.push(field);
+        }
+    }
+}
+
+/// 把CSV文本拆分成记录，返回每条记录开始的行号（从1开始）和记录的内容
+///
+/// 与`str::lines`相同，以`\n`或`\r\n`结尾，但引号内的换行属于字段内容，不结束记录。
+fn split_csv_records(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut rest = text;
+    let mut line = 1;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let mut in_quotes = false;
+        // 引号内的""会让in_quotes连续翻转两次，不影响结果
+        let end = rest
+            .char_indices()
+            .find(|&(_, c)| {
+                if c == '"' {
+                    in_quotes = !in_quotes;
+                }
+                c == '\n' && !in_quotes
+            })
+            .map(|(i, _)| i);
+        let (record, next) = match end {
+            Some(i) => (&rest[..i], &rest[i + 1..]),
+            // 最后一条记录没有换行，或者引号一直没有闭合
+            None => (rest, ""),
+        };
+        let start = line;
+        line += record.matches('\n').count() + 1;
+        rest = next;
+        Some((start, record.strip_suffix('\r').unwrap_or(record)))
+    })
+}
+
+/// 某一行CSV解析失败，`line`是从1开始的行号（包括表头）
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvLineError {
+    pub line: usize,
+    pub error: StudentError,
+}
+
+impl fmt::Display for CsvLineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "第{}行: {}", self.line, self.error)
+    }
+}
+
+impl Error for CsvLineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// 学生名单，可以和带表头的CSV文本互相转换
+///
+/// ```
+/// use rust_data_structures_tutorial::student::StudentRoster;
+///
+/// let roster = StudentRoster::from_csv("name,age,grade\n张三,20,85.5\n李四,22,58\n").unwrap();
+/// assert_eq!(roster.len(), 2);
+/// assert_eq!(roster.to_csv(), "name,age,grade\n张三,20,85.5\n李四,22,58\n");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StudentRoster {
+    pub students: Vec<Student>,
+}
+
+impl StudentRoster {
+    /// CSV表头
+    pub const HEADER: &'static str = "name,age,grade";
+
+    /// 解析CSV文本：第一行是表头，会被跳过；空行也会被跳过
+    ///
+    /// 双引号括起来的名字可以包含换行（[`to_csv`](Self::to_csv)会这样输出），
+    /// 这样的一条记录跨越多行，出错时报告的是记录开始的行号。
+    pub fn from_csv(text: &str) -> Result<StudentRoster, CsvLineError> {
+        let mut students = Vec::new();
+        for (line, record) in split_csv_records(text).skip(1) {
+            if record.trim().is_empty() {
+                continue;
+            }
+            let student =
+                Student::from_csv_line(record).map_err(|error| CsvLineError { line, error })?;
+            students.push(student);
+        }
+        Ok(StudentRoster { students })
+    }
+
+    /// 转换为带表头的CSV文本，每行以换行结尾
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{}\n", Self::HEADER);
+        for student in &self.students {
+            csv.push_str(&student.to_csv_line());
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// 学生数量
+    pub fn len(&self) -> usize {
+        self.students.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.students.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_student_builder_complete() {
+        let student = Student::builder()
+            .name("王五")
+            .age(21)
+            .grade(100.0)
+            .build()
+            .unwrap();
+        assert_eq!(student, Student::new("王五".to_string(), 21, 100.0));
+    }
+
+    #[test]
+    fn test_student_builder_errors() {
+        assert_eq!(
+            StudentBuilder::new().build(),
+            Err(StudentError::MissingField("name"))
+        );
+        assert_eq!(
+            StudentBuilder::new().name("  ").age(20).grade(80.0).build(),
+            Err(StudentError::MissingField("name"))
+        );
+        assert_eq!(
+            StudentBuilder::new().name("张三").age(20).build(),
+            Err(StudentError::MissingField("grade"))
+        );
+        assert_eq!(
+            StudentBuilder::new()
+                .name("张三")
+                .age(200)
+                .grade(80.0)
+                .build(),
+            Err(StudentError::InvalidAge("200".to_string()))
+        );
+        assert_eq!(
+            StudentBuilder::new()
+                .name("张三")
+                .age(20)
+                .grade(100.5)
+                .build(),
+            Err(StudentError::InvalidGrade("100.5".to_string()))
+        );
+        assert!(StudentBuilder::new()
+            .name("张三")
+            .age(20)
+            .grade(f64::NAN)
+            .build()
+            .is_err());
+        assert_eq!(
+            StudentError::MissingField("age").to_string(),
+            "缺少字段: age"
+        );
+    }
+
+    #[test]
+    fn test_student_csv_quoting() {
+        let student = Student::new("Smith, \"Jr\"".to_string(), 19, 72.5);
+        let line = student.to_csv_line();
+        assert_eq!(line, r#""Smith, ""Jr""",19,72.5"#);
+
+        let back = Student::from_csv_line(&line).unwrap();
+        assert_eq!(back, student);
+
+        // 不需要引号的名字保持原样，数字字段两边的空白会被忽略
+        let plain = Student::from_csv_line("李四, 22 , 58").unwrap();
+        assert_eq!(plain.name, "李四");
+        assert_eq!(plain.to_csv_line(), "李四,22,58");
+    }
+
+    #[test]
+    fn test_student_csv_malformed_rows() {
+        assert_eq!(
+            Student::from_csv_line("张三,20"),
+            Err(StudentError::FieldCount(2))
+        );
+        assert_eq!(
+            Student::from_csv_line("张,三,20,90"),
+            Err(StudentError::FieldCount(4))
+        );
+        assert_eq!(
+            Student::from_csv_line("张三,-1,90"),
+            Err(StudentError::InvalidAge("-1".to_string()))
+        );
+        assert_eq!(
+            Student::from_csv_line("张三,20,"),
+            Err(StudentError::InvalidGrade("".to_string()))
+        );
+        assert_eq!(
+            Student::from_csv_line("张三,20,NaN"),
+            Err(StudentError::InvalidGrade("NaN".to_string()))
+        );
+        assert_eq!(
+            Student::from_csv_line("\"张三,20,90"),
+            Err(StudentError::BadQuote)
+        );
+        assert_eq!(
+            Student::from_csv_line("\"张\"三,20,90"),
+            Err(StudentError::BadQuote)
+        );
+        assert_eq!(
+            Student::from_csv_line("张\"三,20,90"),
+            Err(StudentError::BadQuote)
+        );
+    }
+
+    #[test]
+    fn test_roster_from_csv() {
+        let text = "name,age,grade\n张三,20,85.5\n\n\"王, 小明\",21,92\n";
+        let roster = StudentRoster::from_csv(text).unwrap();
+        assert_eq!(roster.len(), 2);
+        assert_eq!(roster.students[1].name, "王, 小明");
+        assert_eq!(
+            roster.to_csv(),
+            "name,age,grade\n张三,20,85.5\n\"王, 小明\",21,92\n"
+        );
+
+        // 只有表头时得到空名单
+        assert!(StudentRoster::from_csv("name,age,grade")
+            .unwrap()
+            .is_empty());
+
+        // 名字中的换行和回车在引号内，往返转换后不变
+        let roster = StudentRoster {
+            students: vec![
+                Student::new("多行\n名字".to_string(), 20, 80.0),
+                Student::new("回车\r\n名字".to_string(), 21, 90.0),
+                Student::new("王五".to_string(), 22, 70.0),
+            ],
+        };
+        let csv = roster.to_csv();
+        assert_eq!(
+            csv,
+            "name,age,grade\n\"多行\n名字\",20,80\n\"回车\r\n名字\",21,90\n王五,22,70\n"
+        );
+        assert_eq!(
+            StudentRoster::from_csv(&csv).unwrap().students,
+            roster.students
+        );
+        // \r\n结尾的文本同样可以解析
+        let crlf = StudentRoster::from_csv(&csv.replace(",80\n", ",80\r\n")).unwrap();
+        assert_eq!(crlf.students, roster.students);
+
+        // 跨行的记录之后，行号仍然按实际的行计算
+        let err = StudentRoster::from_csv("name,age,grade\n\"多行\n名字\",20,80\n李四,abc,70\n")
+            .unwrap_err();
+        assert_eq!(err.line, 4);
+        // 引号没有闭合时，剩下的文本都属于同一条记录
+        let err =
+            StudentRoster::from_csv("name,age,grade\n\"张三,20,80\n李四,21,70\n").unwrap_err();
+        assert_eq!((err.line, err.error), (2, StudentError::BadQuote));
+
+        // 错误带有行号（表头是第1行）
+        let err = StudentRoster::from_csv("name,age,grade\n张三,20,85\n李四,abc,70\n").unwrap_err();
+        assert_eq!(err.line, 3);
+        assert_eq!(err.to_string(), "第3行: 无效的年龄: abc");
+        assert_eq!(err.source().unwrap().to_string(), "无效的年龄: abc");
+    }
+}
@@ -0,0 +1,330 @@
+//! 带状态转换的任务和任务看板
+//!
+//! 来自枚举教程（`06_enum.rs`）中的`Task`。示例中的任务只用来统计和显示，
+//! 这里补充了状态转换：待办 → 进行中 → 完成，不合法的转换返回错误。
+
+use std::fmt;
+
+/// 任务，每个变体都带着任务名
+///
+/// ```
+/// use rust_data_structures_tutorial::tasks::Task;
+///
+/// let mut task = Task::new("写代码");
+/// task.start().unwrap();
+/// task.set_progress(60).unwrap();
+/// assert_eq!(task, Task::InProgress("写代码".to_string(), 60));
+///
+/// // 进度到达100%时自动完成
+/// task.set_progress(100).unwrap();
+/// assert!(task.is_done());
+/// assert!(task.start().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Task {
+    Todo(String),
+    /// 任务名，完成百分比（0..=100）
+    InProgress(String, u8),
+    Done(String),
+}
+
+/// 任务所处的状态，不带任务数据
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskState {
+    Todo,
+    InProgress,
+    Done,
+}
+
+impl fmt::Display for TaskState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            TaskState::Todo => "待办",
+            TaskState::InProgress => "进行中",
+            TaskState::Done => "已完成",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 不合法的状态转换，记录了尝试的操作和任务当时的状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransitionError {
+    pub action: &'static str,
+    pub state: TaskState,
+}
+
+impl fmt::Display for TransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "无法对{}的任务执行{}", self.state, self.action)
+    }
+}
+
+impl std::error::Error for TransitionError {}
+
+impl Task {
+    /// 创建待办任务
+    pub fn new(name: &str) -> Self {
+        Task::Todo(name.to_string())
+    }
+
+    /// 任务名
+    pub fn name(&self) -> &str {
+        match self {
+            Task::Todo(name) | Task::InProgress(name, _) | Task::Done(name) => name,
+        }
+    }
+
+    /// 当前状态
+    pub fn state(&self) -> TaskState {
+        match self {
+            Task::Todo(_) => TaskState::Todo,
+            Task::InProgress(_, _) => TaskState::InProgress,
+            Task::Done(_) => TaskState::Done,
+        }
+    }
+
+    /// 完成百分比：待办为0，完成为100
+    pub fn progress(&self) -> u8 {
+        match self {
+            Task::Todo(_) => 0,
+            Task::InProgress(_, progress) => *progress,
+            Task::Done(_) => 100,
+        }
+    }
+
+    /// 是否已完成
+    pub fn is_done(&self) -> bool {
+        matches!(self, Task::Done(_))
+    }
+
+    /// 开始任务：待办 → 进行中（0%）
+    pub fn start(&mut self) -> Result<(), TransitionError> {
+        match self {
+            Task::Todo(name) => {
+                *self = Task::InProgress(std::mem::take(name), 0);
+                Ok(())
+            }
+            _ => Err(self.invalid("开始")),
+        }
+    }
+
+    /// 更新进行中任务的进度，超过100的值按100处理；进度到达100%时任务自动完成
+    pub fn set_progress(&mut self, progress: u8) -> Result<(), TransitionError> {
+        match self {
+            Task::InProgress(name, current) => {
+                let progress = progress.min(100);
+                if progress == 100 {
+                    *self = Task::Done(std::mem::take(name));
+                } else {
+                    *current = progress;
+                }
+                Ok(())
+            }
+            _ => Err(self.invalid("更新进度")),
+        }
+    }
+
+    /// 完成任务：待办或进行中 → 完成
+    pub fn complete(&mut self) -> Result<(), TransitionError> {
+        match self {
+            Task::Todo(name) | Task::InProgress(name, _) => {
+                *self = Task::Done(std::mem::take(name));
+                Ok(())
+            }
+            Task::Done(_) => Err(self.invalid("完成")),
+        }
+    }
+
+    fn invalid(&self, action: &'static str) -> TransitionError {
+        TransitionError {
+            action,
+            state: self.state(),
+        }
+    }
+}
+
+impl fmt::Display for Task {
+    /// 清单格式：`[ ] 名称`、`[60%] 名称`、`[✓] 名称`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Task::Todo(name) => write!(f, "[ ] {}", name),
+            Task::InProgress(name, progress) => write!(f, "[{}%] {}", progress, name),
+            Task::Done(name) => write!(f, "[✓] {}", name),
+        }
+    }
+}
+
+/// 各状态的任务数量
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TaskCounts {
+    pub todo: usize,
+    pub in_progress: usize,
+    pub done: usize,
+}
+
+/// 任务看板：按加入顺序保存任务
+///
+/// ```
+/// use rust_data_structures_tutorial::tasks::{Board, Task, TaskState};
+///
+/// let mut board = Board::new();
+/// board.add(Task::new("学习Rust"));
+/// board.add(Task::new("看文档"));
+/// board.get_mut(1).unwrap().complete().unwrap();
+/// assert_eq!(board.count(TaskState::Done), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Board {
+    tasks: Vec<Task>,
+}
+
+impl Board {
+    /// 创建空看板
+    pub fn new() -> Self {
+        Board::default()
+    }
+
+    /// 加入任务，返回它的下标
+    pub fn add(&mut self, task: Task) -> usize {
+        self.tasks.push(task);
+        self.tasks.len() - 1
+    }
+
+    /// 所有任务
+    pub fn tasks(&self) -> &[Task] {
+        &self.tasks
+    }
+
+    /// 按下标获取任务，用于执行状态转换
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Task> {
+        self.tasks.get_mut(index)
+    }
+
+    /// 处于`state`的任务数量
+    pub fn count(&self, state: TaskState) -> usize {
+        self.tasks
+            .iter()
+            .filter(|task| task.state() == state)
+            .count()
+    }
+
+    /// 一次统计所有状态的任务数量
+    pub fn counts(&self) -> TaskCounts {
+        self.tasks
+            .iter()
+            .fold(TaskCounts::default(), |mut counts, task| {
+                match task.state() {
+                    TaskState::Todo => counts.todo += 1,
+                    TaskState::InProgress => counts.in_progress += 1,
+                    TaskState::Done => counts.done += 1,
+                }
+                counts
+            })
+    }
+}
+
+impl FromIterator<Task> for Board {
+    fn from_iter<I: IntoIterator<Item = Task>>(iter: I) -> Self {
+        Board {
+            tasks: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_moves_todo_to_in_progress() {
+        let mut task = Task::new("学习Rust");
+        assert_eq!(task.state(), TaskState::Todo);
+        assert_eq!(task.progress(), 0);
+
+        task.start().unwrap();
+        assert_eq!(task, Task::InProgress("学习Rust".to_string(), 0));
+
+        // 已经开始的任务不能再次开始
+        let err = task.start().unwrap_err();
+        assert_eq!(err.state, TaskState::InProgress);
+        assert_eq!(err.to_string(), "无法对进行中的任务执行开始");
+    }
+
+    #[test]
+    fn test_set_progress() {
+        let mut task = Task::new("写代码");
+        // 待办任务必须先开始
+        assert!(task.set_progress(10).is_err());
+
+        task.start().unwrap();
+        task.set_progress(60).unwrap();
+        assert_eq!(task.progress(), 60);
+        task.set_progress(30).unwrap();
+        assert_eq!(task.progress(), 30);
+        assert_eq!(task.to_string(), "[30%] 写代码");
+    }
+
+    #[test]
+    fn test_progress_auto_completes_at_100() {
+        let mut task = Task::InProgress("写代码".to_string(), 90);
+        task.set_progress(100).unwrap();
+        assert_eq!(task, Task::Done("写代码".to_string()));
+
+        // 超过100按100处理
+        let mut task = Task::InProgress("测试".to_string(), 0);
+        task.set_progress(250).unwrap();
+        assert!(task.is_done());
+        assert_eq!(task.progress(), 100);
+        assert!(task.set_progress(50).is_err());
+    }
+
+    #[test]
+    fn test_complete() {
+        let mut todo = Task::new("看文档");
+        todo.complete().unwrap();
+        assert_eq!(todo.to_string(), "[✓] 看文档");
+
+        let mut in_progress = Task::InProgress("写代码".to_string(), 40);
+        in_progress.complete().unwrap();
+        assert!(in_progress.is_done());
+
+        let err = in_progress.complete().unwrap_err();
+        assert_eq!(
+            err,
+            TransitionError {
+                action: "完成",
+                state: TaskState::Done
+            }
+        );
+        assert!(in_progress.start().is_err());
+        assert_eq!(in_progress.name(), "写代码");
+    }
+
+    #[test]
+    fn test_board_counts() {
+        let mut board: Board = vec![
+            Task::new("学习Rust"),
+            Task::InProgress("写代码".to_string(), 60),
+            Task::Done("看文档".to_string()),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            board.counts(),
+            TaskCounts {
+                todo: 1,
+                in_progress: 1,
+                done: 1
+            }
+        );
+
+        let index = board.add(Task::new("写测试"));
+        board.get_mut(index).unwrap().start().unwrap();
+        board.get_mut(0).unwrap().complete().unwrap();
+        assert_eq!(board.count(TaskState::Todo), 0);
+        assert_eq!(board.count(TaskState::InProgress), 2);
+        assert_eq!(board.count(TaskState::Done), 2);
+        assert!(board.get_mut(10).is_none());
+    }
+}
@@ -0,0 +1,553 @@
+//! 文本处理工具函数
+//!
+//! 把字符串和HashMap教程中零散演示的技巧组合成可以直接调用的完整功能。
+
+use std::collections::HashMap;
+
+/// 统计每个单词出现的次数
+///
+/// 按Unicode空白分词，转为小写，并去掉单词首尾的标点符号（单词内部的`'`、`-`等保留）。
+/// 结果按出现次数从多到少排序，次数相同时按字母顺序排序。
+///
+/// ```
+/// use rust_data_structures_tutorial::text::word_count;
+///
+/// let counts = word_count("The cat and the hat.");
+/// assert_eq!(counts[0], ("the".to_string(), 2));
+/// assert_eq!(counts.len(), 4);
+/// ```
+pub fn word_count(input: &str) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for word in input.split_whitespace() {
+        let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+        if !word.is_empty() {
+            *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+        }
+    }
+
+    let mut result: Vec<(String, usize)> = counts.into_iter().collect();
+    result.sort_by(|(word_a, count_a), (word_b, count_b)| {
+        count_b.cmp(count_a).then_with(|| word_a.cmp(word_b))
+    });
+    result
+}
+
+/// 计算两个字符串的编辑距离（Levenshtein距离）
+///
+/// 编辑距离是把`a`变成`b`所需的最少单字符插入、删除和替换次数。
+/// 按Unicode字符（`char`）而不是字节比较，因此多字节字符算作一个字符。
+/// 使用经典的动态规划，但只保留一行状态，额外空间为O(min(n, m))。
+///
+/// ```
+/// use rust_data_structures_tutorial::text::levenshtein;
+///
+/// assert_eq!(levenshtein("kitten", "sitting"), 3);
+/// assert_eq!(levenshtein("你好世界", "你好"), 2);
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // 让内层循环遍历较短的字符串，这样状态行更短
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+
+    // row[j]：long的前i个字符与short的前j个字符之间的距离
+    let mut row: Vec<usize> = (0..=short.len()).collect();
+    for (i, &long_char) in long.iter().enumerate() {
+        // diagonal保存上一行的row[j - 1]，也就是dp[i - 1][j - 1]
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &short_char) in short.iter().enumerate() {
+            let substitution = diagonal + usize::from(long_char != short_char);
+            let deletion = row[j + 1] + 1;
+            let insertion = row[j] + 1;
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(deletion).min(insertion);
+        }
+    }
+    row[short.len()]
+}
+
+/// 游程编码：把连续相同的字符压缩成`(字符, 重复次数)`
+///
+/// 按`char`分组，多字节字符不会被拆开。与[`rle_decode`]互为逆操作。
+///
+/// ```
+/// use rust_data_structures_tutorial::text::{rle_decode, rle_encode};
+///
+/// let runs = rle_encode("aaabccdd");
+/// assert_eq!(runs, vec![('a', 3), ('b', 1), ('c', 2), ('d', 2)]);
+/// assert_eq!(rle_decode(&runs), "aaabccdd");
+/// ```
+pub fn rle_encode(input: &str) -> Vec<(char, usize)> {
+    let mut runs: Vec<(char, usize)> = Vec::new();
+    for ch in input.chars() {
+        match runs.last_mut() {
+            Some((last, count)) if *last == ch => *count += 1,
+            _ => runs.push((ch, 1)),
+        }
+    }
+    runs
+}
+
+/// 游程解码：把每个`(字符, 重复次数)`展开并拼接成字符串
+pub fn rle_decode(runs: &[(char, usize)]) -> String {
+    let mut output = String::new();
+    for &(ch, count) in runs {
+        output.extend(std::iter::repeat_n(ch, count));
+    }
+    output
+}
+
+/// 凯撒密码加密：把每个ASCII字母在字母表中向后移动`shift`位
+///
+/// 大小写保持不变，数字、标点和非ASCII字符原样保留。
+/// `shift`可以是负数或大于26的数，会先按26取模。
+///
+/// ```
+/// use rust_data_structures_tutorial::text::{caesar_decrypt, caesar_encrypt};
+///
+/// let secret = caesar_encrypt("Hello, 世界!", 3);
+/// assert_eq!(secret, "Khoor, 世界!");
+/// assert_eq!(caesar_decrypt(&secret, 3), "Hello, 世界!");
+/// ```
+pub fn caesar_encrypt(input: &str, shift: i32) -> String {
+    // rem_euclid保证结果在0..26之间，负数也能正确回绕
+    let shift = shift.rem_euclid(26) as u8;
+    input
+        .chars()
+        .map(|ch| match ch {
+            'a'..='z' => rotate(ch, b'a', shift),
+            'A'..='Z' => rotate(ch, b'A', shift),
+            _ => ch,
+        })
+        .collect()
+}
+
+/// 凯撒密码解密：[`caesar_encrypt`]的逆操作
+pub fn caesar_decrypt(input: &str, shift: i32) -> String {
+    // 先取模再取反，避免i32::MIN取反溢出
+    caesar_encrypt(input, -shift.rem_euclid(26))
+}
+
+fn rotate(ch: char, base: u8, shift: u8) -> char {
+    (base + (ch as u8 - base + shift) % 26) as char
+}
+
+/// 判断字符串是否是回文（正着读和倒着读一样）
+///
+/// 按Unicode标量值（`char`）比较，而不是按字节：多字节字符的字节倒过来以后
+/// 不再是合法的UTF-8，按字节比较会把`"上海自来水来自海上"`判断为不是回文。
+/// 组合字符（例如`e`后面跟着组合用的重音符号）由多个`char`组成，会被分开比较。
+///
+/// ```
+/// use rust_data_structures_tutorial::text::{is_palindrome, is_palindrome_with};
+///
+/// assert!(is_palindrome("上海自来水来自海上"));
+/// assert!(!is_palindrome("Madam"));
+/// assert!(is_palindrome_with("Madam", true));
+/// ```
+pub fn is_palindrome(s: &str) -> bool {
+    is_palindrome_with(s, false)
+}
+
+/// 判断回文，`loose`为`true`时忽略ASCII大小写，并跳过所有非字母数字的字符（空格、标点等）
+pub fn is_palindrome_with(s: &str, loose: bool) -> bool {
+    let mut chars = s
+        .chars()
+        .filter(|c| !loose || c.is_alphanumeric())
+        .map(|c| if loose { c.to_ascii_lowercase() } else { c });
+    // 同时从两端向中间比较，不需要额外分配内存
+    while let (Some(front), Some(back)) = (chars.next(), chars.next_back()) {
+        if front != back {
+            return false;
+        }
+    }
+    true
+}
+
+/// 把字母异位词（由相同字符重新排列得到的单词）分到同一组
+///
+/// 每个单词先转为小写，再把字符排序后的结果作为签名，签名相同的单词属于同一组，
+/// 所以`"Listen"`和`"silent"`是一组；组内保留单词原来的写法。
+/// 按`char`排序，中文等多字节字符同样适用。
+/// 分组按每组第一个单词在输入中出现的顺序排列，组内也保持输入顺序，重复的单词会重复出现。
+///
+/// ```
+/// use rust_data_structures_tutorial::text::group_anagrams;
+///
+/// let groups = group_anagrams(&["Listen", "google", "silent"]);
+/// assert_eq!(groups, vec![vec!["Listen", "silent"], vec!["google"]]);
+/// ```
+pub fn group_anagrams(words: &[&str]) -> Vec<Vec<String>> {
+    // 签名 -> 在groups中的下标，用来保持分组的出现顺序
+    let mut index_of: HashMap<String, usize> = HashMap::new();
+    let mut groups: Vec<Vec<String>> = Vec::new();
+    for &word in words {
+        let mut signature: Vec<char> = word.to_lowercase().chars().collect();
+        signature.sort_unstable();
+        let index = *index_of
+            .entry(signature.into_iter().collect())
+            .or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+        groups[index].push(word.to_string());
+    }
+    groups
+}
+
+/// 用KMP（Knuth–Morris–Pratt）算法查找`needle`第一次出现的字节下标
+///
+/// 在字节上匹配，时间复杂度为O(n + m)。UTF-8编码中一个字符的首字节不会出现在
+/// 其他字符的中间，所以有效字符串之间的匹配位置一定落在字符边界上，
+/// 返回的下标可以直接用来切片。空的`needle`返回`Some(0)`。
+///
+/// ```
+/// use rust_data_structures_tutorial::text::kmp_find;
+///
+/// let text = "数据结构与算法";
+/// let index = kmp_find(text, "算法").unwrap();
+/// assert_eq!(&text[index..], "算法");
+/// assert_eq!(kmp_find(text, "排序"), None);
+/// ```
+pub fn kmp_find(haystack: &str, needle: &str) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    let mut first = None;
+    kmp_search(haystack.as_bytes(), needle.as_bytes(), |index| {
+        first = Some(index);
+        false
+    });
+    first
+}
+
+/// 查找`needle`所有出现位置的字节下标，允许匹配相互重叠
+///
+/// 与标准库的`match_indices`不同，`"aaa"`中的`"aa"`会在下标0和1各匹配一次。
+/// 空的`needle`与`match_indices`一致，在每个字符边界（包括末尾）都匹配。
+///
+/// ```
+/// use rust_data_structures_tutorial::text::kmp_find_all;
+///
+/// assert_eq!(kmp_find_all("abababa", "aba"), vec![0, 2, 4]);
+/// ```
+pub fn kmp_find_all(haystack: &str, needle: &str) -> Vec<usize> {
+    if needle.is_empty() {
+        return haystack
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(std::iter::once(haystack.len()))
+            .collect();
+    }
+    let mut matches = Vec::new();
+    kmp_search(haystack.as_bytes(), needle.as_bytes(), |index| {
+        matches.push(index);
+        true
+    });
+    matches
+}
+
+/// 构建失配表：`failure[i]`是`pattern[..=i]`中既是真前缀又是后缀的最长长度
+fn failure_table(pattern: &[u8]) -> Vec<usize> {
+    let mut failure = vec![0; pattern.len()];
+    let mut len = 0;
+    for i in 1..pattern.len() {
+        while len > 0 && pattern[i] != pattern[len] {
+            len = failure[len - 1];
+        }
+        if pattern[i] == pattern[len] {
+            len += 1;
+        }
+        failure[i] = len;
+    }
+    failure
+}
+
+/// 对每个匹配的起始下标调用`on_match`，回调返回`false`时停止搜索；`pattern`不能为空
+fn kmp_search(text: &[u8], pattern: &[u8], mut on_match: impl FnMut(usize) -> bool) {
+    let failure = failure_table(pattern);
+    // matched：当前已经匹配的pattern前缀长度
+    let mut matched = 0;
+    for (i, &byte) in text.iter().enumerate() {
+        while matched > 0 && byte != pattern[matched] {
+            matched = failure[matched - 1];
+        }
+        if byte == pattern[matched] {
+            matched += 1;
+        }
+        if matched == pattern.len() {
+            if !on_match(i + 1 - matched) {
+                return;
+            }
+            // 退回到最长的可重叠前缀，继续寻找下一个匹配
+            matched = failure[matched - 1];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owned(pairs: &[(&str, usize)]) -> Vec<(String, usize)> {
+        pairs
+            .iter()
+            .map(|&(word, count)| (word.to_string(), count))
+            .collect()
+    }
+
+    #[test]
+    fn test_word_count_paragraph() {
+        let paragraph = "Rust is fast. Rust is safe!\n\"Safe\" code, fast code -- and fun code.";
+        assert_eq!(
+            word_count(paragraph),
+            owned(&[
+                ("code", 3),
+                ("fast", 2),
+                ("is", 2),
+                ("rust", 2),
+                ("safe", 2),
+                ("and", 1),
+                ("fun", 1),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_word_count_keeps_inner_punctuation() {
+        assert_eq!(
+            word_count("Don't stop; don't\tSTOP (well-known)"),
+            owned(&[("don't", 2), ("stop", 2), ("well-known", 1)])
+        );
+    }
+
+    #[test]
+    fn test_word_count_unicode_and_empty() {
+        // 全角空格同样是Unicode空白，中文标点也会被去掉
+        assert_eq!(
+            word_count("Äpfel　äpfel， ÄPFEL。 Birnen"),
+            owned(&[("äpfel", 3), ("birnen", 1)])
+        );
+        assert!(word_count("").is_empty());
+        assert!(word_count(" ... !!! ").is_empty());
+    }
+
+    #[test]
+    fn test_levenshtein_classic() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("sitting", "kitten"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("abc", "xyz"), 3);
+    }
+
+    #[test]
+    fn test_levenshtein_identical_and_empty() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("rust", "rust"), 0);
+        assert_eq!(levenshtein("", "rust"), 4);
+        assert_eq!(levenshtein("rust", ""), 4);
+    }
+
+    #[test]
+    fn test_levenshtein_counts_chars_not_bytes() {
+        // "é"占两个字节，但只算一次替换
+        assert_eq!(levenshtein("café", "cafe"), 1);
+        assert_eq!(levenshtein("数据结构", "数据类型"), 2);
+        assert_eq!(levenshtein("🦀", ""), 1);
+    }
+
+    #[test]
+    fn test_rle_encode() {
+        assert_eq!(rle_encode(""), vec![]);
+        assert_eq!(rle_encode("a"), vec![('a', 1)]);
+        assert_eq!(
+            rle_encode("aaAA  !"),
+            vec![('a', 2), ('A', 2), (' ', 2), ('!', 1)]
+        );
+        assert_eq!(
+            rle_encode("好好学习"),
+            vec![('好', 2), ('学', 1), ('习', 1)]
+        );
+    }
+
+    #[test]
+    fn test_rle_decode_skips_empty_runs() {
+        assert_eq!(rle_decode(&[('x', 0), ('y', 3)]), "yyy");
+        assert_eq!(rle_decode(&[]), "");
+    }
+
+    #[test]
+    fn test_rle_round_trip() {
+        let inputs = [
+            "",
+            "abc",
+            "aaaaaaaaaaaaaaaaaaaa",
+            "aabbbaa",
+            "WWWWWWWWWWWWBWWWWWWWWWWWWBBB",
+            "🦀🦀🦀 Rust 🦀",
+            // 组合字符：e后面跟着组合用的重音符号
+            "ééé e\u{301}e\u{301}",
+            "中中中文文\n\n\t",
+        ];
+        for input in inputs {
+            let runs = rle_encode(input);
+            // 相邻的游程字符一定不同
+            assert!(runs.windows(2).all(|pair| pair[0].0 != pair[1].0));
+            assert_eq!(rle_decode(&runs), input);
+        }
+    }
+
+    #[test]
+    fn test_caesar_wraparound() {
+        assert_eq!(caesar_encrypt("z", 1), "a");
+        assert_eq!(caesar_encrypt("Z", 1), "A");
+        assert_eq!(caesar_encrypt("xyz", 3), "abc");
+        assert_eq!(caesar_encrypt("abc", 26), "abc");
+        assert_eq!(caesar_encrypt("abc", 27), "bcd");
+    }
+
+    #[test]
+    fn test_caesar_negative_shift() {
+        assert_eq!(caesar_encrypt("a", -1), "z");
+        assert_eq!(caesar_encrypt("Abc", -27), "Zab");
+        assert_eq!(caesar_encrypt("abc", i32::MIN), caesar_encrypt("abc", 2));
+    }
+
+    #[test]
+    fn test_caesar_leaves_other_chars_untouched() {
+        assert_eq!(caesar_encrypt("2024: été, 你好!", 5), "2024: éyé, 你好!");
+    }
+
+    #[test]
+    fn test_caesar_decrypt_reverses_encrypt() {
+        let message = "The Quick Brown Fox Jumps Over The Lazy Dog. 123 🦀";
+        for shift in [
+            -100,
+            -27,
+            -1,
+            0,
+            1,
+            13,
+            25,
+            26,
+            52,
+            1000,
+            i32::MIN,
+            i32::MAX,
+        ] {
+            let secret = caesar_encrypt(message, shift);
+            assert_eq!(caesar_decrypt(&secret, shift), message, "shift = {}", shift);
+        }
+        // ROT13加密两次得到原文
+        assert_eq!(caesar_encrypt(&caesar_encrypt(message, 13), 13), message);
+    }
+
+    #[test]
+    fn test_kmp_find() {
+        assert_eq!(kmp_find("hello world", "world"), Some(6));
+        assert_eq!(kmp_find("hello world", "o"), Some(4));
+        assert_eq!(kmp_find("hello", "hello!"), None);
+        assert_eq!(kmp_find("", "a"), None);
+        // 失配后需要回退到部分匹配的前缀
+        assert_eq!(kmp_find("aabaabaaab", "aaab"), Some(6));
+        assert_eq!(kmp_find("abcabcabd", "abcabd"), Some(3));
+    }
+
+    #[test]
+    fn test_kmp_empty_needle() {
+        assert_eq!(kmp_find("abc", ""), Some(0));
+        assert_eq!(kmp_find("", ""), Some(0));
+        assert_eq!(kmp_find_all("ab", ""), vec![0, 1, 2]);
+        assert_eq!(kmp_find_all("你a", ""), vec![0, 3, 4]);
+    }
+
+    #[test]
+    fn test_kmp_find_all_overlapping() {
+        assert_eq!(kmp_find_all("aaaa", "aa"), vec![0, 1, 2]);
+        assert_eq!(kmp_find_all("abababab", "abab"), vec![0, 2, 4]);
+        assert_eq!(kmp_find_all("abc", "d"), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_kmp_multibyte_indices_are_char_boundaries() {
+        let text = "学习Rust，学习算法，学习";
+        let matches = kmp_find_all(text, "学习");
+        assert_eq!(matches, vec![0, 13, 28]);
+        for index in matches {
+            assert!(text.is_char_boundary(index));
+            assert!(text[index..].starts_with("学习"));
+        }
+        assert_eq!(kmp_find(text, "算法"), text.find("算法"));
+    }
+
+    #[test]
+    fn test_kmp_matches_std_find() {
+        let haystacks = ["", "a", "mississippi", "ababcabcabababd", "🦀🦀 rust 🦀"];
+        let needles = ["a", "ss", "issi", "ababd", "🦀", "xyz", "p"];
+        for haystack in haystacks {
+            for needle in needles {
+                assert_eq!(kmp_find(haystack, needle), haystack.find(needle));
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_palindrome_ignoring_case_and_punctuation() {
+        let sentence = "A man a plan a canal Panama";
+        assert!(!is_palindrome(sentence));
+        assert!(is_palindrome_with(sentence, true));
+        assert!(is_palindrome_with("Was it a car or a cat I saw?", true));
+        assert!(!is_palindrome_with("Hello, world!", true));
+        // 标点符号本身不会被当成字符比较
+        assert!(is_palindrome_with("!!a--A??", true));
+    }
+
+    #[test]
+    fn test_is_palindrome_multibyte() {
+        assert!(is_palindrome("上海自来水来自海上"));
+        assert!(is_palindrome("🦀é🦀"));
+        assert!(!is_palindrome("上海"));
+        // 按字节比较会得出错误的结论
+        let text = "雾锁山头山锁雾";
+        assert!(is_palindrome(text));
+        assert!(!text.bytes().eq(text.bytes().rev()));
+        // 中文标点和全角空格同样会被跳过
+        assert!(is_palindrome_with("雾锁山头，山锁雾。", true));
+    }
+
+    #[test]
+    fn test_is_palindrome_edge_cases() {
+        assert!(is_palindrome(""));
+        assert!(is_palindrome("x"));
+        assert!(is_palindrome("abba"));
+        assert!(!is_palindrome("abca"));
+        assert!(is_palindrome_with(" .,!", true));
+        // 只忽略ASCII大小写
+        assert!(!is_palindrome_with("ÄbA", true));
+    }
+
+    #[test]
+    fn test_group_anagrams() {
+        let groups = group_anagrams(&["eat", "tea", "tan", "ate", "nat", "bat"]);
+        assert_eq!(
+            groups,
+            vec![vec!["eat", "tea", "ate"], vec!["tan", "nat"], vec!["bat"]]
+        );
+    }
+
+    #[test]
+    fn test_group_anagrams_case_and_unicode() {
+        let groups = group_anagrams(&["Dusty", "study", "蜜蜂", "蜂蜜", "dusty", "a", ""]);
+        assert_eq!(
+            groups,
+            vec![
+                vec!["Dusty", "study", "dusty"],
+                vec!["蜜蜂", "蜂蜜"],
+                vec!["a"],
+                vec![""],
+            ]
+        );
+        assert!(group_anagrams(&[]).is_empty());
+    }
+}
@@ -0,0 +1,169 @@
+//! 交通灯状态机
+//!
+//! 来自枚举教程（`06_enum.rs`）中的`TrafficLight`。示例在循环里手动调用`next()`，
+//! 这里补充了按周期生成时间表，并让交通灯可以作为迭代器无限地产生后续状态。
+
+use std::fmt;
+
+/// 交通灯的三种状态，按 红 → 绿 → 黄 → 红 循环
+///
+/// ```
+/// use rust_data_structures_tutorial::traffic::TrafficLight;
+///
+/// let light = TrafficLight::Red;
+/// assert_eq!(light.next(), TrafficLight::Green);
+/// assert_eq!(light.total_duration(3), 30 + 25 + 5);
+///
+/// let states: Vec<TrafficLight> = light.into_iter().take(4).collect();
+/// assert_eq!(
+///     states,
+///     vec![TrafficLight::Red, TrafficLight::Green, TrafficLight::Yellow, TrafficLight::Red]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrafficLight {
+    Red,
+    Yellow,
+    Green,
+}
+
+impl TrafficLight {
+    /// 下一个状态
+    pub fn next(&self) -> TrafficLight {
+        match self {
+            TrafficLight::Red => TrafficLight::Green,
+            TrafficLight::Yellow => TrafficLight::Red,
+            TrafficLight::Green => TrafficLight::Yellow,
+        }
+    }
+
+    /// 当前状态持续的秒数
+    pub fn duration(&self) -> u32 {
+        match self {
+            TrafficLight::Red => 30,
+            TrafficLight::Yellow => 5,
+            TrafficLight::Green => 25,
+        }
+    }
+
+    /// 状态的含义
+    pub fn description(&self) -> &'static str {
+        match self {
+            TrafficLight::Red => "停止",
+            TrafficLight::Yellow => "准备",
+            TrafficLight::Green => "通行",
+        }
+    }
+
+    /// 从当前状态开始的`cycles`个状态及各自的持续秒数
+    pub fn schedule(&self, cycles: usize) -> Vec<(TrafficLight, u32)> {
+        self.into_iter()
+            .take(cycles)
+            .map(|light| (light, light.duration()))
+            .collect()
+    }
+
+    /// 从当前状态开始的`cycles`个状态的总秒数
+    pub fn total_duration(&self, cycles: usize) -> u32 {
+        self.into_iter()
+            .take(cycles)
+            .map(|light| light.duration())
+            .sum()
+    }
+}
+
+impl fmt::Display for TrafficLight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            TrafficLight::Red => "红灯",
+            TrafficLight::Yellow => "黄灯",
+            TrafficLight::Green => "绿灯",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 从某个状态开始无限循环的交通灯状态序列，第一个元素是起始状态本身
+#[derive(Debug, Clone)]
+pub struct States {
+    current: TrafficLight,
+}
+
+impl Iterator for States {
+    type Item = TrafficLight;
+
+    fn next(&mut self) -> Option<TrafficLight> {
+        let light = self.current;
+        self.current = light.next();
+        Some(light)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl IntoIterator for TrafficLight {
+    type Item = TrafficLight;
+    type IntoIter = States;
+
+    fn into_iter(self) -> States {
+        States { current: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TrafficLight::{Green, Red, Yellow};
+
+    #[test]
+    fn test_next_cycles_through_all_states() {
+        assert_eq!(Red.next(), Green);
+        assert_eq!(Green.next(), Yellow);
+        assert_eq!(Yellow.next(), Red);
+        for light in [Red, Yellow, Green] {
+            assert_eq!(light.next().next().next(), light);
+        }
+    }
+
+    #[test]
+    fn test_six_cycle_schedule() {
+        assert_eq!(
+            Red.schedule(6),
+            vec![
+                (Red, 30),
+                (Green, 25),
+                (Yellow, 5),
+                (Red, 30),
+                (Green, 25),
+                (Yellow, 5)
+            ]
+        );
+        assert_eq!(Red.total_duration(6), 120);
+
+        // 从黄灯开始时顺序随之平移
+        let states: Vec<TrafficLight> = Yellow.schedule(6).into_iter().map(|(l, _)| l).collect();
+        assert_eq!(states, vec![Yellow, Red, Green, Yellow, Red, Green]);
+        assert_eq!(Yellow.total_duration(6), 120);
+    }
+
+    #[test]
+    fn test_empty_schedule() {
+        assert!(Green.schedule(0).is_empty());
+        assert_eq!(Green.total_duration(0), 0);
+        assert_eq!(Green.total_duration(1), 25);
+    }
+
+    #[test]
+    fn test_iterator_is_infinite() {
+        let mut states = Green.into_iter();
+        assert_eq!(states.next(), Some(Green));
+        assert_eq!(states.nth(299), Some(Green));
+        assert_eq!(states.size_hint().1, None);
+        assert_eq!(
+            Red.into_iter().take(1000).filter(|l| *l == Red).count(),
+            334
+        );
+    }
+}
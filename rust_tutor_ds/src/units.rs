@@ -0,0 +1,179 @@
+//! 带单位的数值类型
+//!
+//! 来自枚举教程（`06_enum.rs`）中的`Temperature`。示例只有摄氏度和华氏度，
+//! 而且只用来演示匹配守卫；这里补充了开尔文和三种温标之间的转换。
+
+use std::fmt;
+
+/// 绝对零度（摄氏度）
+pub const ABSOLUTE_ZERO_CELSIUS: f64 = -273.15;
+
+/// 三种温标表示的温度
+///
+/// 枚举变体可以直接构造任意数值；需要校验输入时使用`try_*`构造函数，
+/// 它们会拒绝低于绝对零度的温度。
+///
+/// ```
+/// use rust_data_structures_tutorial::units::Temperature;
+///
+/// let boiling = Temperature::Celsius(100.0);
+/// assert_eq!(boiling.to_fahrenheit(), Temperature::Fahrenheit(212.0));
+/// assert!(Temperature::try_kelvin(-1.0).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Temperature {
+    Celsius(f64),
+    Fahrenheit(f64),
+    Kelvin(f64),
+}
+
+/// 温度低于绝对零度时返回的错误
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BelowAbsoluteZero {
+    /// 被拒绝的温度
+    pub temperature: Temperature,
+}
+
+impl fmt::Display for BelowAbsoluteZero {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}低于绝对零度", self.temperature)
+    }
+}
+
+impl std::error::Error for BelowAbsoluteZero {}
+
+impl Temperature {
+    /// 创建摄氏温度，低于-273.15°C时返回错误
+    pub fn try_celsius(value: f64) -> Result<Self, BelowAbsoluteZero> {
+        Temperature::Celsius(value).validated()
+    }
+
+    /// 创建华氏温度，低于-459.67°F时返回错误
+    pub fn try_fahrenheit(value: f64) -> Result<Self, BelowAbsoluteZero> {
+        Temperature::Fahrenheit(value).validated()
+    }
+
+    /// 创建开尔文温度，低于0K时返回错误
+    pub fn try_kelvin(value: f64) -> Result<Self, BelowAbsoluteZero> {
+        Temperature::Kelvin(value).validated()
+    }
+
+    /// 当前温标下的数值
+    pub fn value(&self) -> f64 {
+        match *self {
+            Temperature::Celsius(v) | Temperature::Fahrenheit(v) | Temperature::Kelvin(v) => v,
+        }
+    }
+
+    /// 转换为摄氏度
+    pub fn to_celsius(&self) -> Temperature {
+        Temperature::Celsius(self.celsius_value())
+    }
+
+    /// 转换为华氏度
+    pub fn to_fahrenheit(&self) -> Temperature {
+        Temperature::Fahrenheit(self.celsius_value() * 9.0 / 5.0 + 32.0)
+    }
+
+    /// 转换为开尔文
+    pub fn to_kelvin(&self) -> Temperature {
+        Temperature::Kelvin(self.celsius_value() - ABSOLUTE_ZERO_CELSIUS)
+    }
+
+    /// 所有转换都以摄氏度为中间单位
+    fn celsius_value(&self) -> f64 {
+        match *self {
+            Temperature::Celsius(c) => c,
+            Temperature::Fahrenheit(f) => (f - 32.0) * 5.0 / 9.0,
+            Temperature::Kelvin(k) => k + ABSOLUTE_ZERO_CELSIUS,
+        }
+    }
+
+    fn validated(self) -> Result<Self, BelowAbsoluteZero> {
+        // 在开尔文下比较，避免不同温标的换算误差影响边界判断
+        // NaN同样无法通过这个比较
+        if self.to_kelvin().value() >= -1e-9 {
+            Ok(self)
+        } else {
+            Err(BelowAbsoluteZero { temperature: self })
+        }
+    }
+}
+
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Temperature::Celsius(v) => write!(f, "{:.2}°C", v),
+            Temperature::Fahrenheit(v) => write!(f, "{:.2}°F", v),
+            Temperature::Kelvin(v) => write!(f, "{:.2}K", v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Temperature, expected: Temperature) {
+        assert_eq!(
+            std::mem::discriminant(&actual),
+            std::mem::discriminant(&expected),
+            "{:?} vs {:?}",
+            actual,
+            expected
+        );
+        assert!(
+            (actual.value() - expected.value()).abs() < 1e-9,
+            "{:?} vs {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_known_conversions() {
+        assert_close(
+            Temperature::Celsius(0.0).to_fahrenheit(),
+            Temperature::Fahrenheit(32.0),
+        );
+        assert_close(
+            Temperature::Celsius(100.0).to_kelvin(),
+            Temperature::Kelvin(373.15),
+        );
+        assert_close(
+            Temperature::Fahrenheit(-40.0).to_celsius(),
+            Temperature::Celsius(-40.0),
+        );
+        assert_close(
+            Temperature::Kelvin(0.0).to_fahrenheit(),
+            Temperature::Fahrenheit(-459.67),
+        );
+    }
+
+    #[test]
+    fn test_round_trip_conversions() {
+        for value in [-273.15, -40.0, 0.0, 36.6, 100.0, 1000.0] {
+            let original = Temperature::Celsius(value);
+            assert_close(original.to_fahrenheit().to_celsius(), original);
+            assert_close(original.to_kelvin().to_celsius(), original);
+            assert_close(
+                original.to_kelvin().to_fahrenheit().to_kelvin(),
+                original.to_kelvin(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_absolute_zero_guard() {
+        assert!(Temperature::try_celsius(-273.15).is_ok());
+        assert!(Temperature::try_fahrenheit(-459.67).is_ok());
+        assert!(Temperature::try_kelvin(0.0).is_ok());
+
+        let err = Temperature::try_celsius(-300.0).unwrap_err();
+        assert_eq!(err.temperature, Temperature::Celsius(-300.0));
+        assert_eq!(err.to_string(), "-300.00°C低于绝对零度");
+        assert!(Temperature::try_fahrenheit(-500.0).is_err());
+        assert!(Temperature::try_kelvin(-0.5).is_err());
+        assert!(Temperature::try_kelvin(f64::NAN).is_err());
+    }
+}
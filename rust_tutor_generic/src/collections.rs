@@ -0,0 +1,106 @@
+//! 借用切片的集合迭代器
+//!
+//! 来自例子9（生命周期与泛型）中的`WindowIterator<'a, T>`。迭代器借用切片，
+//! 产生的每个窗口都带着切片的生命周期`'a`，而不是迭代器自身的生命周期，
+//! 所以窗口可以在迭代器被丢弃后继续使用。
+//!
+//! ```
+//! use rust_tutor_generic::collections::windows;
+//!
+//! let numbers = [1, 2, 3, 4];
+//! let sums: Vec<i32> = windows(&numbers, 2).map(|w| w.iter().sum()).collect();
+//! assert_eq!(sums, vec![3, 5, 7]);
+//! ```
+
+/// 返回`slice`上所有长度为`size`的重叠窗口
+///
+/// 与标准库的`slice::windows`相同，区别是`size == 0`时返回空迭代器而不是panic；
+/// `size`大于切片长度时同样返回空迭代器。
+pub fn windows<T>(slice: &[T], size: usize) -> WindowIter<'_, T> {
+    WindowIter {
+        data: slice,
+        size,
+        position: 0,
+    }
+}
+
+/// [`windows`]返回的迭代器
+#[derive(Debug, Clone)]
+pub struct WindowIter<'a, T> {
+    data: &'a [T],
+    size: usize,
+    position: usize,
+}
+
+impl<'a, T> Iterator for WindowIter<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.len() == 0 {
+            return None;
+        }
+        let window = &self.data[self.position..self.position + self.size];
+        self.position += 1;
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for WindowIter<'_, T> {
+    fn len(&self) -> usize {
+        if self.size == 0 {
+            return 0;
+        }
+        // 窗口起点的范围是0..=data.len() - size，减去已经走过的起点
+        match self.data.len().checked_sub(self.size) {
+            Some(last_start) => (last_start + 1).saturating_sub(self.position),
+            None => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_matches_std(data: &[i32], size: usize) {
+        let ours: Vec<&[i32]> = windows(data, size).collect();
+        let expected: Vec<&[i32]> = data.windows(size).collect();
+        assert_eq!(ours, expected, "data = {:?}, size = {}", data, size);
+        assert_eq!(windows(data, size).len(), expected.len());
+    }
+
+    #[test]
+    fn test_matches_slice_windows() {
+        let inputs: [&[i32]; 4] = [&[], &[7], &[1, 2, 3], &[1, 2, 3, 4, 5, 6]];
+        for data in inputs {
+            for size in 1..=data.len() + 2 {
+                assert_matches_std(data, size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_zero_size_is_empty() {
+        assert_eq!(windows(&[1, 2, 3], 0).next(), None);
+        assert_eq!(windows(&[1, 2, 3], 0).len(), 0);
+        assert_eq!(windows(&[1, 2, 3], usize::MAX).next(), None);
+    }
+
+    #[test]
+    fn test_windows_outlive_iterator() {
+        let data = vec![String::from("a"), String::from("b"), String::from("c")];
+        let last = {
+            let mut iter = windows(&data, 2);
+            assert_eq!(iter.len(), 2);
+            iter.next();
+            assert_eq!(iter.size_hint(), (1, Some(1)));
+            iter.next().unwrap()
+        };
+        assert_eq!(last, ["b", "c"]);
+    }
+}
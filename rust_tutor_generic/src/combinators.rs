@@ -0,0 +1,111 @@
+//! 在函子之上链式组合计算
+//!
+//! [`crate::functor::Functor`]的`fmap`只能映射内部的值，映射函数本身不能失败；
+//! [`Chainable`]的`and_then`允许每一步返回新的包装值（`None`或`Err`），
+//! 任何一步失败都会让后面的步骤被跳过，这就是解析、校验这类多步骤计算的常见写法。
+//!
+//! 与`fmap`一样，`Option`和`Result`都有同名的固有方法`and_then`，
+//! 直接写`opt.and_then(...)`调用的是固有方法；通过泛型约束或`Chainable::and_then(...)`
+//! 调用时才会用到这里的实现。
+//!
+//! ```
+//! use rust_tutor_generic::combinators::Chainable;
+//!
+//! let parse = |s: &str| s.parse::<i32>().map_err(|e| e.to_string());
+//! let positive = |n: i32| if n > 0 { Ok(n) } else { Err(format!("{}不是正数", n)) };
+//!
+//! assert_eq!(Chainable::and_then(parse("42"), positive), Ok(42));
+//! assert_eq!(Chainable::and_then(parse("-1"), positive), Err("-1不是正数".to_string()));
+//! ```
+
+use crate::functor::Functor;
+
+/// 可以链式组合的函子：每一步都返回同一种包装类型
+pub trait Chainable<T>: Functor<T> {
+    /// 对内部的值调用`f`，`f`返回的包装值就是结果；自身为空或错误时不调用`f`
+    fn and_then<U, F>(self, f: F) -> Self::Wrapped<U>
+    where
+        F: FnOnce(T) -> Self::Wrapped<U>;
+}
+
+impl<T> Chainable<T> for Option<T> {
+    fn and_then<U, F>(self, f: F) -> Option<U>
+    where
+        F: FnOnce(T) -> Option<U>,
+    {
+        match self {
+            Some(value) => f(value),
+            None => None,
+        }
+    }
+}
+
+impl<T, E> Chainable<T> for Result<T, E> {
+    fn and_then<U, F>(self, f: F) -> Result<U, E>
+    where
+        F: FnOnce(T) -> Result<U, E>,
+    {
+        match self {
+            Ok(value) => f(value),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn parse(input: &str) -> Result<u32, String> {
+        input
+            .trim()
+            .parse()
+            .map_err(|_| format!("无法解析: {}", input))
+    }
+
+    fn validate_age(age: u32) -> Result<u32, String> {
+        if age <= 150 {
+            Ok(age)
+        } else {
+            Err(format!("年龄超出范围: {}", age))
+        }
+    }
+
+    #[test]
+    fn test_result_chain_success() {
+        let age = Chainable::and_then(parse(" 30 "), validate_age);
+        assert_eq!(age, Ok(30));
+
+        // 每一步还可以换成别的类型
+        let label = Chainable::and_then(age, |n| Ok::<_, String>(format!("{}岁", n)));
+        assert_eq!(label, Ok("30岁".to_string()));
+    }
+
+    #[test]
+    fn test_result_chain_short_circuits() {
+        assert_eq!(
+            Chainable::and_then(parse("200"), validate_age),
+            Err("年龄超出范围: 200".to_string())
+        );
+
+        // 第一步失败时后面的函数不会被调用
+        let called = Cell::new(false);
+        let result = Chainable::and_then(parse("abc"), |age| {
+            called.set(true);
+            validate_age(age)
+        });
+        assert_eq!(result, Err("无法解析: abc".to_string()));
+        assert!(!called.get());
+    }
+
+    #[test]
+    fn test_option_chain() {
+        let first_char = |s: &str| s.chars().next();
+        let digit = |c: char| c.to_digit(10);
+
+        assert_eq!(Chainable::and_then(first_char("7号"), digit), Some(7));
+        assert_eq!(Chainable::and_then(first_char("七号"), digit), None);
+        assert_eq!(Chainable::and_then(first_char(""), digit), None);
+    }
+}
@@ -1,6 +1,8 @@
 // 例子4: 泛型枚举
 // 这个例子将详细讲解如何定义和使用泛型枚举
 
+use rust_tutor_generic::json::JsonValue;
+
 pub fn run() {
     println!("\n🎯 例子4: 泛型枚举");
     println!("===================");
@@ -299,40 +301,7 @@ pub fn run() {
     // 10. 实际应用：JSON值枚举
     println!("\n📖 10. 实际应用：JSON值枚举");
     
-    #[derive(Debug)]
-    enum JsonValue {
-        Null,
-        Bool(bool),
-        Number(f64),
-        String(String),
-        Array(Vec<JsonValue>),
-        Object(std::collections::HashMap<String, JsonValue>),
-    }
-    
-    impl JsonValue {
-        fn type_name(&self) -> &'static str {
-            match self {
-                JsonValue::Null => "null",
-                JsonValue::Bool(_) => "boolean",
-                JsonValue::Number(_) => "number",
-                JsonValue::String(_) => "string",
-                JsonValue::Array(_) => "array",
-                JsonValue::Object(_) => "object",
-            }
-        }
-        
-        fn is_truthy(&self) -> bool {
-            match self {
-                JsonValue::Null => false,
-                JsonValue::Bool(b) => *b,
-                JsonValue::Number(n) => *n != 0.0,
-                JsonValue::String(s) => !s.is_empty(),
-                JsonValue::Array(arr) => !arr.is_empty(),
-                JsonValue::Object(obj) => !obj.is_empty(),
-            }
-        }
-    }
-    
+    // JsonValue定义在库中（json模块），除了类型判断还支持解析和序列化
     let json_values = vec![
         JsonValue::Null,
         JsonValue::Bool(true),
@@ -346,6 +315,23 @@ pub fn run() {
         println!("  类型: {}, 真值: {}", value.type_name(), value.is_truthy());
     }
     
+    let text = r#"{"name": "Ferris", "langs": ["Rust", "C"], "age": 8, "admin": false}"#;
+    match JsonValue::parse(text) {
+        Ok(value) => {
+            println!("解析JSON文本: {}", text);
+            println!("  类型: {}, 序列化: {}", value.type_name(), value);
+            println!("  格式化输出:\n{}", value.to_pretty_string(2));
+            // 与serde_json::Value互相转换，方便和真实的serde代码配合使用
+            let serde_value = serde_json::Value::from(value);
+            println!("  转换为serde_json::Value后读取name字段: {}", serde_value["name"]);
+        }
+        Err(err) => println!("解析失败: {}", err),
+    }
+    
+    if let Err(err) = JsonValue::parse("[1, 2,]") {
+        println!("解析 [1, 2,] 失败: {}", err);
+    }
+    
     println!("\n🎉 泛型枚举学习完成！");
     println!("💡 关键要点：");
     println!("   • 泛型枚举让枚举更加灵活和通用");
@@ -1,6 +1,8 @@
 // 例子7: Where子句
 // 这个例子将详细讲解Where子句的各种用法和优势
 
+use rust_tutor_generic::query::QueryBuilder;
+
 pub fn run() {
     println!("\n🎯 例子7: Where子句");
     println!("===================");
@@ -294,73 +296,23 @@ pub fn run() {
     // 10. 实际应用：数据库查询构建器
     println!("\n📖 10. 实际应用：数据库查询构建器");
     
-    trait Query {
-        type Output;
-        fn execute(&self) -> Self::Output;
-    }
-    
-    trait Filterable<T> {
-        fn filter<F>(self, predicate: F) -> Self 
-        where 
-            F: Fn(&T) -> bool + 'static;
-    }
-    
-    struct QueryBuilder<T> 
-    where 
-        T: Clone + std::fmt::Debug,
-    {
-        data: Vec<T>,
-        filters: Vec<Box<dyn Fn(&T) -> bool>>,
-    }
-    
-    impl<T> QueryBuilder<T> 
-    where 
-        T: Clone + std::fmt::Debug + 'static,
-    {
-        fn new(data: Vec<T>) -> Self {
-            QueryBuilder {
-                data,
-                filters: Vec::new(),
-            }
-        }
-    }
-    
-    impl<T> Filterable<T> for QueryBuilder<T> 
-    where 
-        T: Clone + std::fmt::Debug + 'static,
-    {
-        fn filter<F>(mut self, predicate: F) -> Self 
-        where 
-            F: Fn(&T) -> bool + 'static,
-        {
-            self.filters.push(Box::new(predicate));
-            self
-        }
-    }
-    
-    impl<T> Query for QueryBuilder<T> 
-    where 
-        T: Clone + std::fmt::Debug,
-    {
-        type Output = Vec<T>;
-        
-        fn execute(&self) -> Self::Output {
-            let mut result = self.data.clone();
-            for filter in &self.filters {
-                result.retain(|item| filter(item));
-            }
-            result
-        }
-    }
-    
+    // QueryBuilder定义在库中（query模块）：
+    // 过滤、排序和截取按添加的顺序依次执行
     let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
-    let query = QueryBuilder::new(data)
+    let query = QueryBuilder::new(data.clone())
         .filter(|&x| x > 5)
         .filter(|&x| x % 2 == 0);
     
     let result = query.execute();
     println!("查询结果: {:?}", result);
     
+    let top_three = QueryBuilder::new(data)
+        .filter(|&x| x % 2 == 1)
+        .sort_by(|a, b| b.cmp(a))
+        .take(3)
+        .execute();
+    println!("最大的三个奇数: {:?}", top_three);
+    
     println!("\n🎉 Where子句学习完成！");
     println!("💡 关键要点：");
     println!("   • Where子句提供更清晰的约束语法");
@@ -1,6 +1,8 @@
 // 例子8: 关联类型
 // 这个例子将详细讲解关联类型的概念、用法和优势
 
+use rust_tutor_generic::parser::{literal, number, Combinator, Parser};
+
 pub fn run() {
     println!("\n🎯 例子8: 关联类型");
     println!("===================");
@@ -279,68 +281,32 @@ pub fn run() {
     // 8. 复杂的关联类型约束
     println!("\n📖 8. 复杂的关联类型约束");
     
-    trait Parser {
-        type Input;
-        type Output;
-        type Error;
-        
-        fn parse(&self, input: Self::Input) -> Result<Self::Output, Self::Error>;
-    }
-    
-    trait Combinator: Parser {
-        fn map<F, U>(self, f: F) -> Map<Self, F> 
-        where 
-            Self: Sized,
-            F: Fn(Self::Output) -> U,
-        {
-            Map { parser: self, func: f }
-        }
-    }
-    
-    struct Map<P, F> {
-        parser: P,
-        func: F,
-    }
+    // Parser特征和组合子定义在库中（parser模块）：
+    // 每个解析器用关联类型Input/Output/Error声明自己的类型，
+    // map/and_then/or只要求这些关联类型彼此匹配
+    let parser = number();
+    let mapped_parser = parser.map(|x| x * 2);
     
-    impl<P, F, U> Parser for Map<P, F> 
-    where 
-        P: Parser,
-        F: Fn(P::Output) -> U,
-    {
-        type Input = P::Input;
-        type Output = U;
-        type Error = P::Error;
-        
-        fn parse(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
-            match self.parser.parse(input) {
-                Ok(output) => Ok((self.func)(output)),
-                Err(error) => Err(error),
-            }
-        }
+    match mapped_parser.parse("42") {
+        Ok((result, _)) => println!("解析并映射结果: {}", result),
+        Err(error) => println!("解析错误: {}", error),
     }
     
-    impl<P: Parser> Combinator for P {}
-    
-    struct NumberParser;
+    // 组合多个解析器：解析并计算 "12+34" 或 "12-34"
+    let operator = literal("+").map(|_| '+').or(literal("-").map(|_| '-'));
+    let expression = number().and_then(move |a| {
+        operator.clone().and_then(move |op| {
+            number().map(move |b| if op == '+' { a + b } else { a - b })
+        })
+    });
     
-    impl Parser for NumberParser {
-        type Input = String;
-        type Output = i32;
-        type Error = String;
-        
-        fn parse(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
-            input.parse().map_err(|_| format!("无法解析数字: {}", input))
+    for input in ["12+34", "12-34", "12*34"] {
+        match expression.parse(input) {
+            Ok((value, _)) => println!("表达式 {} = {}", input, value),
+            Err(error) => println!("表达式 {} 解析失败: {}", input, error),
         }
     }
     
-    let parser = NumberParser;
-    let mapped_parser = parser.map(|x| x * 2);
-    
-    match mapped_parser.parse("42".to_string()) {
-        Ok(result) => println!("解析并映射结果: {}", result),
-        Err(error) => println!("解析错误: {}", error),
-    }
-    
     // 9. 关联类型与特征对象
     println!("\n📖 9. 关联类型与特征对象");
     
@@ -1,6 +1,8 @@
 // 例子9: 生命周期与泛型
 // 这个例子将详细讲解生命周期与泛型的结合使用
 
+use rust_tutor_generic::collections::windows;
+
 pub fn run() {
     println!("\n🎯 例子9: 生命周期与泛型");
     println!("==========================");
@@ -212,41 +214,13 @@ pub fn run() {
     // 8. 生命周期与迭代器
     println!("\n📖 8. 生命周期与迭代器");
     
-    struct WindowIterator<'a, T> {
-        data: &'a [T],
-        window_size: usize,
-        position: usize,
-    }
-    
-    impl<'a, T> WindowIterator<'a, T> {
-        fn new(data: &'a [T], window_size: usize) -> Self {
-            WindowIterator {
-                data,
-                window_size,
-                position: 0,
-            }
-        }
-    }
-    
-    impl<'a, T> Iterator for WindowIterator<'a, T> {
-        type Item = &'a [T];
-        
-        fn next(&mut self) -> Option<Self::Item> {
-            if self.position + self.window_size <= self.data.len() {
-                let window = &self.data[self.position..self.position + self.window_size];
-                self.position += 1;
-                Some(window)
-            } else {
-                None
-            }
-        }
-    }
-    
+    // windows定义在库中（collections模块）：
+    // 每个窗口借用的是numbers本身，生命周期与迭代器无关
     let numbers = vec![1, 2, 3, 4, 5, 6];
-    let mut window_iter = WindowIterator::new(&numbers, 3);
+    let window_iter = windows(&numbers, 3);
     
-    println!("滑动窗口迭代:");
-    while let Some(window) = window_iter.next() {
+    println!("滑动窗口迭代（共{}个窗口）:", window_iter.len());
+    for window in window_iter {
         println!("  窗口: {:?}", window);
     }
     
@@ -1,6 +1,16 @@
 // 例子10: 高级泛型技巧
 // 这个例子将展示高级泛型技巧和实际应用
 
+use rust_tutor_generic::combinators::Chainable;
+use rust_tutor_generic::functor::Functor;
+use rust_tutor_generic::hlist;
+use rust_tutor_generic::hlist::{HList, HMap, Poly};
+use rust_tutor_generic::peano::{self, Succ, Sum, Three, ToUsize, Zero};
+use rust_tutor_generic::singleton::Lazy;
+use rust_tutor_generic::type_map::TypeMap;
+use rust_tutor_generic::typestate::SafeBox;
+use rust_tutor_generic::units::{Celsius, Feet, Kilometers, Measurement, Meters, Miles};
+
 pub fn run() {
     println!("\n🎯 例子10: 高级泛型技巧");
     println!("========================");
@@ -10,49 +20,19 @@ pub fn run() {
     
     use std::marker::PhantomData;
     
-    // 使用幻影类型来区分不同的度量单位
-    struct Measurement<T, U> {
-        value: T,
-        _unit: PhantomData<U>,
-    }
-    
-    // 单位类型
-    struct Meters;
-    struct Feet;
-    struct Celsius;
-    struct Fahrenheit;
-    
-    impl<T, U> Measurement<T, U> {
-        fn new(value: T) -> Self {
-            Measurement {
-                value,
-                _unit: PhantomData,
-            }
-        }
-        
-        fn value(&self) -> &T {
-            &self.value
-        }
-    }
-    
-    // 类型安全的单位转换
-    impl Measurement<f64, Meters> {
-        fn to_feet(self) -> Measurement<f64, Feet> {
-            Measurement::new(self.value * 3.28084)
-        }
-    }
+    // Measurement定义在库中（units模块）：
+    // 单位只存在于类型中，不同单位的度量值不能直接相加
+    let distance_m = Measurement::<Meters>::new(10.0);
+    let distance_ft: Measurement<Feet> = distance_m.convert();
+    println!("10米 = {:.2}英尺", distance_ft.value());
     
-    impl Measurement<f64, Celsius> {
-        fn to_fahrenheit(self) -> Measurement<f64, Fahrenheit> {
-            Measurement::new(self.value * 9.0 / 5.0 + 32.0)
-        }
-    }
+    let total = distance_m + Measurement::new(5.0);
+    println!("10m + 5m = {}", total);
     
-    let distance_m = Measurement::<f64, Meters>::new(10.0);
-    let distance_ft = distance_m.to_feet();
-    println!("10米 = {:.2}英尺", distance_ft.value());
+    let marathon = Measurement::<Kilometers>::new(42.195);
+    println!("马拉松: {} = {:.2}", marathon, marathon.convert::<Miles>());
     
-    let temp_c = Measurement::<f64, Celsius>::new(25.0);
+    let temp_c = Measurement::<Celsius>::new(25.0);
     let temp_f = temp_c.to_fahrenheit();
     println!("25°C = {:.1}°F", temp_f.value());
     
@@ -60,27 +40,16 @@ pub fn run() {
     println!("\n📖 2. 类型级编程");
     
     // 使用类型来表示编译时常量
-    trait TypeNum {
-        const VALUE: usize;
-    }
-    
-    struct Zero;
-    struct One;
-    struct Two;
-    struct Three;
-    
-    impl TypeNum for Zero { const VALUE: usize = 0; }
-    impl TypeNum for One { const VALUE: usize = 1; }
-    impl TypeNum for Two { const VALUE: usize = 2; }
-    impl TypeNum for Three { const VALUE: usize = 3; }
+    // Zero/Succ定义在库中（peano模块）：Three就是Succ<Succ<Succ<Zero>>>，
+    // ToUsize::VALUE在编译期算出它代表的数
     
     // 固定大小的数组，大小在类型中编码
-    struct FixedArray<T, N: TypeNum> {
+    struct FixedArray<T, N: ToUsize> {
         data: Vec<T>,
         _size: PhantomData<N>,
     }
     
-    impl<T, N: TypeNum> FixedArray<T, N> {
+    impl<T, N: ToUsize> FixedArray<T, N> {
         fn new() -> Self {
             FixedArray {
                 data: Vec::with_capacity(N::VALUE),
@@ -121,92 +90,58 @@ pub fn run() {
     // 3. 高阶类型构造器模拟
     println!("\n📖 3. 高阶类型构造器模拟");
     
-    trait Functor<T> {
-        type Wrapped<U>;
-        fn map<U, F>(self, f: F) -> Self::Wrapped<U> 
-        where 
-            F: FnOnce(T) -> U;
-    }
-    
-    impl<T> Functor<T> for Option<T> {
-        type Wrapped<U> = Option<U>;
-        
-        fn map<U, F>(self, f: F) -> Self::Wrapped<U> 
-        where 
-            F: FnOnce(T) -> U,
-        {
-            self.map(f)
-        }
-    }
-    
-    impl<T, E> Functor<T> for Result<T, E> {
-        type Wrapped<U> = Result<U, E>;
-        
-        fn map<U, F>(self, f: F) -> Self::Wrapped<U> 
-        where 
-            F: FnOnce(T) -> U,
-        {
-            self.map(f)
-        }
+    // Functor定义在库中（functor模块）：
+    // 方法名是fmap而不是map，否则opt.map(...)会调用Option自带的map，特征根本不会被用到
+    fn double_all<W: Functor<i32>>(wrapped: W) -> W::Wrapped<i32> {
+        println!("调用{}的Functor实现", W::NAME);
+        wrapped.fmap(|x| x * 2)
     }
     
     let opt = Some(42);
-    let mapped_opt = opt.map(|x| x * 2);
+    let mapped_opt = double_all(opt);
     println!("映射Option: {:?}", mapped_opt);
     
     let res: Result<i32, String> = Ok(21);
-    let mapped_res = res.map(|x| x * 2);
+    let mapped_res = double_all(res);
     println!("映射Result: {:?}", mapped_res);
     
-    // 4. 类型状态模式
-    println!("\n📖 4. 类型状态模式");
-    
-    // 状态类型
-    struct Locked;
-    struct Unlocked;
+    let failed: Result<i32, String> = Err("计算失败".to_string());
+    println!("映射Err（值保持不变）: {:?}", double_all(failed));
     
-    struct SafeBox<T, State> {
-        content: Option<T>,
-        _state: PhantomData<State>,
-    }
-    
-    impl<T> SafeBox<T, Locked> {
-        fn new(content: T) -> Self {
-            SafeBox {
-                content: Some(content),
-                _state: PhantomData,
-            }
-        }
-        
-        fn unlock(self, _password: &str) -> SafeBox<T, Unlocked> {
-            SafeBox {
-                content: self.content,
-                _state: PhantomData,
+    // Chainable定义在库中（combinators模块）：
+    // and_then的每一步都可能失败，失败后剩下的步骤直接跳过
+    fn parse_then_validate(input: &str) -> Result<u8, String> {
+        let parsed = input.parse::<u8>().map_err(|e| format!("解析\"{}\"失败: {}", input, e));
+        Chainable::and_then(parsed, |age| {
+            if age >= 18 {
+                Ok(age)
+            } else {
+                Err(format!("年龄{}未满18岁", age))
             }
-        }
+        })
     }
     
-    impl<T> SafeBox<T, Unlocked> {
-        fn take(&mut self) -> Option<T> {
-            self.content.take()
-        }
-        
-        fn put(&mut self, content: T) {
-            self.content = Some(content);
-        }
-        
-        fn lock(self) -> SafeBox<T, Locked> {
-            SafeBox {
-                content: self.content,
-                _state: PhantomData,
-            }
-        }
+    for input in ["25", "16", "abc"] {
+        println!("解析并校验{:?}: {:?}", input, parse_then_validate(input));
     }
     
-    let locked_box = SafeBox::new("秘密文档");
+    // 4. 类型状态模式
+    println!("\n📖 4. 类型状态模式");
+    
+    // SafeBox定义在库中（typestate模块）：
+    // 锁定状态的保险箱没有take/put方法，调用它们无法通过编译
+    let locked_box = SafeBox::new("秘密文档", "password123");
     println!("创建了锁定的保险箱");
     
-    let mut unlocked_box = locked_box.unlock("password123");
+    let locked_box = match locked_box.unlock("wrong") {
+        Ok(_) => unreachable!("错误的密码不能解锁"),
+        Err(error) => {
+            println!("尝试错误密码: {}", error);
+            error.into_safe()
+        }
+    };
+    
+    let mut unlocked_box = locked_box.unlock("password123").expect("密码正确");
     println!("保险箱已解锁");
     
     if let Some(content) = unlocked_box.take() {
@@ -262,114 +197,60 @@ pub fn run() {
     // 6. 异构列表(HList)模拟
     println!("\n📖 6. 异构列表(HList)模拟");
     
-    struct HNil;
-    struct HCons<H, T> {
-        head: H,
-        tail: T,
-    }
+    // HCons/HNil定义在库中（hlist模块），hlist!宏负责嵌套构造
+    struct Describe;
     
-    trait HList {
-        fn len(&self) -> usize;
-    }
-    
-    impl HList for HNil {
-        fn len(&self) -> usize {
-            0
-        }
-    }
-    
-    impl<H, T: HList> HList for HCons<H, T> {
-        fn len(&self) -> usize {
-            1 + self.tail.len()
-        }
-    }
-    
-    impl<H, T> HCons<H, T> {
-        fn new(head: H, tail: T) -> Self {
-            HCons { head, tail }
+    impl<T: std::fmt::Debug> Poly<T> for Describe {
+        type Output = String;
+        
+        fn call(&mut self, value: T) -> String {
+            format!("{:?}", value)
         }
     }
     
     // 创建异构列表: (i32, String, bool)
-    let hlist = HCons::new(
-        42,
-        HCons::new(
-            String::from("hello"),
-            HCons::new(true, HNil),
-        ),
-    );
+    let hlist = hlist![42, String::from("hello"), true];
     
     println!("异构列表长度: {}", hlist.len());
-    println!("第一个元素: {}", hlist.head);
-    println!("第二个元素: {}", hlist.tail.head);
-    println!("第三个元素: {}", hlist.tail.tail.head);
+    println!("第一个元素: {}", hlist.head());
+    println!("第二个元素: {}", hlist.tail().head());
+    println!("第三个元素: {}", hlist.tail().tail().head());
+    
+    let described = hlist.map(Describe);
+    println!("映射为字符串: {:?}", described);
     
     // 7. 类型级别的计算
     println!("\n📖 7. 类型级别的计算");
     
-    trait Add<Rhs> {
-        type Output;
-    }
+    // Add定义在库中（peano模块）：
+    // 0 + b = b，(a + 1) + b = (a + b) + 1，两条规则递归地覆盖了所有加法
+    type TwoPlusOne = Sum<Succ<Succ<Zero>>, Succ<Zero>>;
+    println!("Succ<Succ<Zero>> + Succ<Zero> = {}", TwoPlusOne::VALUE);
     
-    impl Add<Zero> for Zero { type Output = Zero; }
-    impl Add<One> for Zero { type Output = One; }
-    impl Add<Zero> for One { type Output = One; }
-    impl Add<One> for One { type Output = Two; }
-    impl Add<Two> for One { type Output = Three; }
-    impl Add<One> for Two { type Output = Three; }
-    
-    fn type_add<A, B>() -> <A as Add<B>>::Output 
-    where 
-        A: Add<B>,
-        <A as Add<B>>::Output: Default,
-    {
-        Default::default()
-    }
+    // 结果类型与直接写出的Three完全相同，可以用在需要Three的地方
+    let mut from_sum: FixedArray<&str, TwoPlusOne> = FixedArray::new();
+    from_sum.push("a").unwrap();
+    println!("用加法结果作为数组容量: {}", from_sum.capacity());
     
-    // 这里我们只是演示类型级计算的概念
-    println!("类型级计算演示完成");
+    fn value_of<N: ToUsize>(_: N) -> usize {
+        N::VALUE
+    }
+    let sum = peano::add(peano::Three::default(), peano::Two::default());
+    println!("3 + 2 = {}（运行时读回）", value_of(sum));
     
     // 8. 泛型单例模式
     println!("\n📖 8. 泛型单例模式");
     
-    use std::sync::{Arc, Mutex};
+    // Lazy定义在库中（singleton模块）：
+    // 基于OnceLock，初始化只执行一次，之后读取不需要加锁
+    static CONFIG: Lazy<String> = Lazy::new();
     
-    struct Singleton<T> {
-        data: Arc<Mutex<Option<T>>>,
-    }
+    println!("初始化前: {:?}", CONFIG.get());
+    let config = CONFIG.get_or_init(|| String::from("全局配置"));
+    println!("单例值: {}", config);
     
-    impl<T> Singleton<T> {
-        fn new() -> Self {
-            Singleton {
-                data: Arc::new(Mutex::new(None)),
-            }
-        }
-        
-        fn initialize(&self, value: T) -> Result<(), &'static str> {
-            let mut data = self.data.lock().unwrap();
-            if data.is_none() {
-                *data = Some(value);
-                Ok(())
-            } else {
-                Err("单例已经初始化")
-            }
-        }
-        
-        fn get<F, R>(&self, f: F) -> Option<R> 
-        where 
-            F: FnOnce(&T) -> R,
-        {
-            let data = self.data.lock().unwrap();
-            data.as_ref().map(f)
-        }
-    }
-    
-    let singleton: Singleton<String> = Singleton::new();
-    singleton.initialize(String::from("全局配置")).unwrap();
-    
-    if let Some(config) = singleton.get(|s| s.clone()) {
-        println!("单例值: {}", config);
-    }
+    let again = CONFIG.get_or_init(|| String::from("另一个配置"));
+    println!("再次获取（不会重新初始化）: {}", again);
     
     // 9. 泛型构建器模式
     println!("\n📖 9. 泛型构建器模式");
@@ -498,6 +379,23 @@ pub fn run() {
     let machine = machine.stop();
     let _machine = machine.reset();
     
+    // 11. 以类型为键的映射
+    println!("\n📖 11. 以类型为键的映射");
+    
+    // TypeMap定义在库中（type_map模块）：
+    // 键是TypeId，值是Box<dyn Any>，每种类型最多保存一个值
+    struct DatabaseUrl(String);
+    
+    let mut resources = TypeMap::new();
+    resources.insert(DatabaseUrl(String::from("localhost:5432")));
+    resources.insert(8080_u16);
+    if let Some(url) = resources.get::<DatabaseUrl>() {
+        println!("数据库地址: {}", url.0);
+    }
+    println!("端口: {:?}", resources.get::<u16>());
+    println!("没有保存的类型: {:?}", resources.get::<String>());
+    println!("移除端口: {:?}，剩余{}个值", resources.remove::<u16>(), resources.len());
+    
     println!("\n🎉 高级泛型技巧学习完成！");
     println!("💡 关键要点：");
     println!("   • 幻影类型提供编译时类型安全");
@@ -0,0 +1,91 @@
+//! 用泛型关联类型模拟函子（Functor）
+//!
+//! 来自例子10（高级泛型技巧）中的“高阶类型构造器模拟”。示例原来把方法命名为`map`，
+//! 但`opt.map(...)`会优先调用`Option`自带的固有方法，自定义的特征从来没有被用到；
+//! 这里把方法命名为`fmap`，并通过泛型函数调用，保证运行的一定是特征中的实现。
+//!
+//! ```
+//! use rust_tutor_generic::functor::Functor;
+//!
+//! // 只知道参数实现了Functor，只能调用特征中的fmap
+//! fn double<W: Functor<i32>>(wrapped: W) -> W::Wrapped<i32> {
+//!     wrapped.fmap(|x| x * 2)
+//! }
+//!
+//! assert_eq!(double(Some(21)), Some(42));
+//! assert_eq!(double(Err::<i32, &str>("出错")), Err("出错"));
+//! ```
+
+/// 可以对内部的值做映射、同时保持外层结构的类型
+///
+/// `Wrapped<U>`是把内部类型换成`U`之后的外层类型，例如`Option<T>`映射后得到`Option<U>`。
+pub trait Functor<T> {
+    /// 内部类型换成`U`之后的类型
+    type Wrapped<U>;
+
+    /// 实现的名字，用来展示实际运行的是哪个实现
+    const NAME: &'static str;
+
+    /// 用`f`映射内部的值，外层结构不变
+    fn fmap<U, F>(self, f: F) -> Self::Wrapped<U>
+    where
+        F: FnOnce(T) -> U;
+}
+
+impl<T> Functor<T> for Option<T> {
+    type Wrapped<U> = Option<U>;
+
+    const NAME: &'static str = "Option";
+
+    fn fmap<U, F>(self, f: F) -> Option<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        // 固有方法优先于特征方法，这里调用的是Option自带的map
+        self.map(f)
+    }
+}
+
+impl<T, E> Functor<T> for Result<T, E> {
+    type Wrapped<U> = Result<U, E>;
+
+    const NAME: &'static str = "Result";
+
+    fn fmap<U, F>(self, f: F) -> Result<U, E>
+    where
+        F: FnOnce(T) -> U,
+    {
+        self.map(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 泛型参数只有Functor约束，这里只可能调用特征中的fmap
+    fn describe<W: Functor<i32>>(wrapped: W) -> (&'static str, W::Wrapped<String>) {
+        (W::NAME, wrapped.fmap(|x| format!("值为{}", x)))
+    }
+
+    #[test]
+    fn test_option_impl_is_invoked() {
+        assert_eq!(describe(Some(7)), ("Option", Some("值为7".to_string())));
+        assert_eq!(describe(None), ("Option", None));
+    }
+
+    #[test]
+    fn test_result_impl_is_invoked() {
+        let ok: Result<i32, String> = Ok(3);
+        assert_eq!(describe(ok), ("Result", Ok("值为3".to_string())));
+
+        let err: Result<i32, String> = Err("失败".to_string());
+        assert_eq!(describe(err), ("Result", Err("失败".to_string())));
+    }
+
+    #[test]
+    fn test_fmap_changes_inner_type() {
+        let lengths = Functor::fmap(Some("你好"), |s: &str| s.chars().count());
+        assert_eq!(lengths, Some(2));
+    }
+}
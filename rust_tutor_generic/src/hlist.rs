@@ -0,0 +1,204 @@
+//! 异构列表（HList）
+//!
+//! 来自例子10（高级泛型技巧）中的`HCons`/`HNil`。每个元素的类型都记录在列表的类型里，
+//! 例如`hlist![1, "a", true]`的类型是`HCons<i32, HCons<&str, HCons<bool, HNil>>>`，
+//! 所以长度可以在编译期算出，取出的元素也不需要类型转换。
+//!
+//! 对每个元素做同一种操作时，元素类型各不相同，普通闭包无法胜任；
+//! 这里用[`Poly`]特征为每种元素类型分别实现操作，再由[`HMap`]逐个应用：
+//!
+//! ```
+//! use rust_tutor_generic::hlist;
+//! use rust_tutor_generic::hlist::{HList, HMap, Poly};
+//!
+//! struct Double;
+//!
+//! impl Poly<i32> for Double {
+//!     type Output = i32;
+//!     fn call(&mut self, value: i32) -> i32 { value * 2 }
+//! }
+//!
+//! impl Poly<&str> for Double {
+//!     type Output = String;
+//!     fn call(&mut self, value: &str) -> String { value.repeat(2) }
+//! }
+//!
+//! let list = hlist![21, "ab"];
+//! assert_eq!(list.len(), 2);
+//!
+//! let doubled = list.map(Double);
+//! assert_eq!(doubled, hlist![42, String::from("abab")]);
+//! ```
+
+/// 空列表
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HNil;
+
+/// 由第一个元素`head`和剩余列表`tail`组成的非空列表
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HCons<H, T> {
+    pub head: H,
+    pub tail: T,
+}
+
+impl<H, T: HList> HCons<H, T> {
+    pub fn new(head: H, tail: T) -> Self {
+        HCons { head, tail }
+    }
+
+    /// 第一个元素
+    pub fn head(&self) -> &H {
+        &self.head
+    }
+
+    /// 去掉第一个元素后的列表
+    pub fn tail(&self) -> &T {
+        &self.tail
+    }
+
+    /// 拆成第一个元素和剩余列表
+    pub fn into_parts(self) -> (H, T) {
+        (self.head, self.tail)
+    }
+}
+
+/// 所有异构列表都实现的特征，长度在编译期确定
+pub trait HList {
+    const LEN: usize;
+
+    fn len(&self) -> usize {
+        Self::LEN
+    }
+
+    fn is_empty(&self) -> bool {
+        Self::LEN == 0
+    }
+}
+
+impl HList for HNil {
+    const LEN: usize = 0;
+}
+
+impl<H, T: HList> HList for HCons<H, T> {
+    const LEN: usize = 1 + T::LEN;
+}
+
+/// 可以作用于类型`T`的操作，每种元素类型单独实现，输出类型也可以各不相同
+pub trait Poly<T> {
+    type Output;
+
+    fn call(&mut self, value: T) -> Self::Output;
+}
+
+/// 用操作`F`逐个转换元素，得到同样长度的新列表
+pub trait HMap<F>: HList {
+    type Output: HList;
+
+    fn map(self, f: F) -> Self::Output;
+}
+
+impl<F> HMap<F> for HNil {
+    type Output = HNil;
+
+    fn map(self, _f: F) -> HNil {
+        HNil
+    }
+}
+
+impl<F, H, T> HMap<F> for HCons<H, T>
+where
+    F: Poly<H>,
+    T: HMap<F>,
+{
+    type Output = HCons<F::Output, T::Output>;
+
+    fn map(self, mut f: F) -> Self::Output {
+        let head = f.call(self.head);
+        HCons {
+            head,
+            tail: self.tail.map(f),
+        }
+    }
+}
+
+/// 构造异构列表：`hlist![1, "a", true]`
+#[macro_export]
+macro_rules! hlist {
+    () => {
+        $crate::hlist::HNil
+    };
+    ($head:expr $(, $tail:expr)* $(,)?) => {
+        $crate::hlist::HCons {
+            head: $head,
+            tail: $crate::hlist!($($tail),*),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Display;
+
+    /// 把任何可显示的元素转换成字符串
+    struct ToStringOp;
+
+    impl<T: Display> Poly<T> for ToStringOp {
+        type Output = String;
+
+        fn call(&mut self, value: T) -> String {
+            value.to_string()
+        }
+    }
+
+    #[test]
+    fn test_build_and_access() {
+        let list = hlist![1, "a", true];
+        assert_eq!(list.len(), 3);
+        assert!(!list.is_empty());
+        assert_eq!(*list.head(), 1);
+        assert_eq!(*list.tail().head(), "a");
+        assert!(*list.tail().tail().head());
+        assert_eq!(list.tail().tail().tail(), &HNil);
+
+        let (head, tail) = list.into_parts();
+        assert_eq!(head, 1);
+        assert_eq!(tail, HCons::new("a", HCons::new(true, HNil)));
+    }
+
+    #[test]
+    fn test_len_is_known_at_compile_time() {
+        const LEN: usize = <HCons<u8, HCons<char, HNil>> as HList>::LEN;
+        assert_eq!(LEN, 2);
+        assert!(hlist![].is_empty());
+        // 允许末尾的逗号
+        assert_eq!(hlist![1, 2,].len(), 2);
+    }
+
+    #[test]
+    fn test_map_to_string() {
+        let strings = hlist![1, "a", true].map(ToStringOp);
+        assert_eq!(
+            strings,
+            hlist![String::from("1"), String::from("a"), String::from("true")]
+        );
+    }
+
+    #[test]
+    fn test_map_with_stateful_operation() {
+        // 操作可以携带状态：按顺序给每个元素编号
+        struct Enumerate(usize);
+
+        impl<T> Poly<T> for Enumerate {
+            type Output = (usize, T);
+
+            fn call(&mut self, value: T) -> (usize, T) {
+                self.0 += 1;
+                (self.0 - 1, value)
+            }
+        }
+
+        let numbered = hlist!['x', 2.5].map(Enumerate(0));
+        assert_eq!(numbered, hlist![(0, 'x'), (1, 2.5)]);
+    }
+}
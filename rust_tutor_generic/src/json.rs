@@ -0,0 +1,822 @@
+//! JSON值类型以及配套的解析器和序列化器
+//!
+//! 来自例子4（泛型枚举）中的`JsonValue`。例子中的枚举只有`type_name`和`is_truthy`，
+//! 这里补充了从字符串解析和序列化回字符串（紧凑格式和带缩进的格式）的能力。
+//!
+//! 对象使用`BTreeMap`保存，键按字典序排列，因此序列化结果是确定的。
+//!
+//! 需要和真实的serde代码交互时，可以通过`From`在`JsonValue`和`serde_json::Value`之间转换。
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// JSON中的值
+///
+/// ```
+/// use rust_tutor_generic::json::JsonValue;
+///
+/// let value = JsonValue::parse(r#"{"name": "Rust", "tags": ["fast", "safe"]}"#).unwrap();
+/// assert_eq!(value.type_name(), "object");
+/// assert_eq!(value.to_string(), r#"{"name":"Rust","tags":["fast","safe"]}"#);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(BTreeMap<String, JsonValue>),
+}
+
+impl JsonValue {
+    /// 解析时数组和对象最多嵌套的层数，与serde_json的默认限制相同
+    ///
+    /// 解析器是递归下降的，不加限制时`[[[[...]]]]`这样的合法输入会耗尽调用栈。
+    pub const MAX_DEPTH: usize = 128;
+
+    /// 解析完整的JSON文本，前后允许有空白，之后不能再有其他字符
+    pub fn parse(input: &str) -> Result<JsonValue, ParseError> {
+        let mut parser = Parser {
+            input,
+            pos: 0,
+            depth: 0,
+        };
+        parser.skip_whitespace();
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.pos < input.len() {
+            return Err(parser.error(ParseErrorKind::TrailingCharacters));
+        }
+        Ok(value)
+    }
+
+    /// 值的类型名称
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            JsonValue::Null => "null",
+            JsonValue::Bool(_) => "boolean",
+            JsonValue::Number(_) => "number",
+            JsonValue::String(_) => "string",
+            JsonValue::Array(_) => "array",
+            JsonValue::Object(_) => "object",
+        }
+    }
+
+    /// 按JavaScript的规则判断真假（空数组和空对象视为假）
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            JsonValue::Null => false,
+            JsonValue::Bool(b) => *b,
+            JsonValue::Number(n) => *n != 0.0,
+            JsonValue::String(s) => !s.is_empty(),
+            JsonValue::Array(arr) => !arr.is_empty(),
+            JsonValue::Object(obj) => !obj.is_empty(),
+        }
+    }
+}
+
+impl FromStr for JsonValue {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        JsonValue::parse(s)
+    }
+}
+
+impl JsonValue {
+    /// 序列化为带缩进的多行JSON文本，每一层嵌套缩进`indent`个空格
+    ///
+    /// 对象的键按字典序输出（与[`Display`](fmt::Display)的紧凑格式一致），
+    /// 空数组和空对象仍然写在一行内。
+    ///
+    /// ```
+    /// use rust_tutor_generic::json::JsonValue;
+    ///
+    /// let value = JsonValue::parse(r#"{"b": [1, 2], "a": {}}"#).unwrap();
+    /// assert_eq!(value.to_pretty_string(2), "{\n  \"a\": {},\n  \"b\": [\n    1,\n    2\n  ]\n}");
+    /// ```
+    pub fn to_pretty_string(&self, indent: usize) -> String {
+        let mut out = String::new();
+        // 写入String不会失败
+        let _ = write_value(&mut out, self, Some(indent));
+        out
+    }
+}
+
+/// 序列化为紧凑的JSON文本（没有多余空白），`to_string()`由此得到
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_value(f, self, None)
+    }
+}
+
+impl From<serde_json::Value> for JsonValue {
+    /// 所有数字都转换为`f64`，超出`f64`精确范围的整数会损失精度
+    fn from(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => JsonValue::Null,
+            serde_json::Value::Bool(b) => JsonValue::Bool(b),
+            serde_json::Value::Number(n) => JsonValue::Number(n.as_f64().unwrap_or(f64::NAN)),
+            serde_json::Value::String(s) => JsonValue::String(s),
+            serde_json::Value::Array(items) => {
+                JsonValue::Array(items.into_iter().map(JsonValue::from).collect())
+            }
+            serde_json::Value::Object(fields) => JsonValue::Object(
+                fields
+                    .into_iter()
+                    .map(|(k, v)| (k, JsonValue::from(v)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<JsonValue> for serde_json::Value {
+    /// 没有小数部分的数字转换为serde_json的整数，其余转换为浮点数；
+    /// NaN和无穷大在JSON中无法表示，转换为`Null`
+    fn from(value: JsonValue) -> Self {
+        match value {
+            JsonValue::Null => serde_json::Value::Null,
+            JsonValue::Bool(b) => serde_json::Value::Bool(b),
+            JsonValue::Number(n) => {
+                if n.fract() == 0.0 && n.abs() < 1e15 {
+                    serde_json::Value::from(n as i64)
+                } else {
+                    serde_json::Number::from_f64(n)
+                        .map(serde_json::Value::Number)
+                        .unwrap_or(serde_json::Value::Null)
+                }
+            }
+            JsonValue::String(s) => serde_json::Value::String(s),
+            JsonValue::Array(items) => {
+                serde_json::Value::Array(items.into_iter().map(serde_json::Value::from).collect())
+            }
+            JsonValue::Object(fields) => serde_json::Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(k, v)| (k, serde_json::Value::from(v)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// 正在输出的数组或对象中还没有输出的元素
+enum Container<'a> {
+    Array(std::slice::Iter<'a, JsonValue>),
+    Object(std::collections::btree_map::Iter<'a, String, JsonValue>),
+}
+
+/// 输出JSON文本，`indent`为`None`时输出紧凑格式，否则每层缩进`indent`个空格
+///
+/// 用显式的栈代替递归：手动构造的值可能嵌套得比[`JsonValue::MAX_DEPTH`]深得多，
+/// 递归输出会耗尽调用栈。
+fn write_value<W: fmt::Write>(out: &mut W, root: &JsonValue, indent: Option<usize>) -> fmt::Result {
+    let newline = |out: &mut W, level: usize| -> fmt::Result {
+        if let Some(indent) = indent {
+            out.write_char('\n')?;
+            for _ in 0..indent * level {
+                out.write_char(' ')?;
+            }
+        }
+        Ok(())
+    };
+
+    // 栈中每一项是一个还没有输出完的容器，以及它是否已经输出过元素
+    let mut stack: Vec<(Container<'_>, bool)> = Vec::new();
+    let mut next = Some(root);
+    loop {
+        if let Some(value) = next.take() {
+            match value {
+                JsonValue::Null => out.write_str("null")?,
+                JsonValue::Bool(b) => write!(out, "{}", b)?,
+                JsonValue::Number(n) => write_number(out, *n)?,
+                JsonValue::String(s) => write_escaped(out, s)?,
+                // 空数组和空对象在两种格式下都写在一行内
+                JsonValue::Array(items) if items.is_empty() => out.write_str("[]")?,
+                JsonValue::Object(fields) if fields.is_empty() => out.write_str("{}")?,
+                JsonValue::Array(items) => {
+                    out.write_char('[')?;
+                    stack.push((Container::Array(items.iter()), false));
+                }
+                JsonValue::Object(fields) => {
+                    out.write_char('{')?;
+                    stack.push((Container::Object(fields.iter()), false));
+                }
+            }
+        }
+
+        let level = stack.len();
+        let Some((container, started)) = stack.last_mut() else {
+            return Ok(());
+        };
+        let (item, close) = match container {
+            Container::Array(items) => (items.next().map(|item| (None, item)), ']'),
+            Container::Object(fields) => (fields.next().map(|(k, v)| (Some(k), v)), '}'),
+        };
+        match item {
+            Some((key, value)) => {
+                if *started {
+                    out.write_char(',')?;
+                }
+                *started = true;
+                newline(out, level)?;
+                if let Some(key) = key {
+                    write_escaped(out, key)?;
+                    out.write_str(if indent.is_some() { ": " } else { ":" })?;
+                }
+                next = Some(value);
+            }
+            None => {
+                stack.pop();
+                newline(out, level - 1)?;
+                out.write_char(close)?;
+            }
+        }
+    }
+}
+
+/// 整数值不输出小数部分；JSON不能表示NaN和无穷大，按`JSON.stringify`的做法输出null
+fn write_number<W: fmt::Write>(f: &mut W, n: f64) -> fmt::Result {
+    if !n.is_finite() {
+        f.write_str("null")
+    } else if n.fract() == 0.0 && n.abs() < 1e15 {
+        write!(f, "{}", n as i64)
+    } else {
+        write!(f, "{}", n)
+    }
+}
+
+/// 按JSON字符串的规则转义并加上引号输出
+fn write_escaped<W: fmt::Write>(f: &mut W, s: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            '\u{08}' => f.write_str("\\b")?,
+            '\u{0c}' => f.write_str("\\f")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    f.write_str("\"")
+}
+
+/// 解析错误的种类
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// 输入在值完整之前就结束了
+    UnexpectedEnd,
+    /// 出现了当前位置不允许的字符
+    UnexpectedChar(char),
+    /// 字符串缺少结尾的引号
+    UnterminatedString,
+    /// 不合法的转义序列
+    InvalidEscape,
+    /// 不符合JSON语法的数字
+    InvalidNumber,
+    /// 数组或对象的最后一个元素后面多了逗号
+    TrailingComma,
+    /// 完整的值后面还有多余的字符
+    TrailingCharacters,
+    /// 数组和对象的嵌套层数超过了[`JsonValue::MAX_DEPTH`]
+    TooDeep,
+}
+
+/// 解析失败的原因和位置（字节偏移）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub position: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::UnexpectedEnd => write!(f, "输入意外结束")?,
+            ParseErrorKind::UnexpectedChar(c) => write!(f, "意外的字符 {:?}", c)?,
+            ParseErrorKind::UnterminatedString => write!(f, "字符串没有结束引号")?,
+            ParseErrorKind::InvalidEscape => write!(f, "无效的转义序列")?,
+            ParseErrorKind::InvalidNumber => write!(f, "无效的数字")?,
+            ParseErrorKind::TrailingComma => write!(f, "多余的逗号")?,
+            ParseErrorKind::TrailingCharacters => write!(f, "值后面有多余的字符")?,
+            ParseErrorKind::TooDeep => write!(f, "嵌套超过{}层", JsonValue::MAX_DEPTH)?,
+        }
+        write!(f, " (位置 {})", self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// 递归下降解析器，`pos`是当前的字节偏移，`depth`是当前所在的数组/对象层数
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            kind,
+            position: self.pos,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(' ' | '\t' | '\n' | '\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    /// 期望下一个字符是`expected`
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += c.len_utf8();
+                Ok(())
+            }
+            Some(c) => Err(self.error(ParseErrorKind::UnexpectedChar(c))),
+            None => Err(self.error(ParseErrorKind::UnexpectedEnd)),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, ParseError> {
+        match self.peek() {
+            None => Err(self.error(ParseErrorKind::UnexpectedEnd)),
+            Some('n') => self.parse_keyword("null", JsonValue::Null),
+            Some('t') => self.parse_keyword("true", JsonValue::Bool(true)),
+            Some('f') => self.parse_keyword("false", JsonValue::Bool(false)),
+            Some('"') => self.parse_string().map(JsonValue::String),
+            Some('[') => self.nested(Self::parse_array),
+            Some('{') => self.nested(Self::parse_object),
+            Some('-' | '0'..='9') => self.parse_number(),
+            Some(c) => Err(self.error(ParseErrorKind::UnexpectedChar(c))),
+        }
+    }
+
+    /// 进入一层数组或对象，超过嵌套限制时报错而不是继续递归
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<JsonValue, ParseError>,
+    ) -> Result<JsonValue, ParseError> {
+        if self.depth == JsonValue::MAX_DEPTH {
+            return Err(self.error(ParseErrorKind::TooDeep));
+        }
+        self.depth += 1;
+        let value = parse(self)?;
+        self.depth -= 1;
+        Ok(value)
+    }
+
+    fn parse_keyword(&mut self, keyword: &str, value: JsonValue) -> Result<JsonValue, ParseError> {
+        for expected in keyword.chars() {
+            self.expect(expected)?;
+        }
+        Ok(value)
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValue, ParseError> {
+        let start = self.pos;
+        // 按JSON语法检查：-?(0|[1-9][0-9]*)(.[0-9]+)?([eE][+-]?[0-9]+)?
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+        match self.peek() {
+            Some('0') => self.pos += 1,
+            Some('1'..='9') => self.skip_digits(),
+            _ => return Err(self.error(ParseErrorKind::InvalidNumber)),
+        }
+        if self.peek() == Some('.') {
+            self.pos += 1;
+            if !matches!(self.peek(), Some('0'..='9')) {
+                return Err(self.error(ParseErrorKind::InvalidNumber));
+            }
+            self.skip_digits();
+        }
+        if let Some('e' | 'E') = self.peek() {
+            self.pos += 1;
+            if let Some('+' | '-') = self.peek() {
+                self.pos += 1;
+            }
+            if !matches!(self.peek(), Some('0'..='9')) {
+                return Err(self.error(ParseErrorKind::InvalidNumber));
+            }
+            self.skip_digits();
+        }
+
+        self.input[start..self.pos]
+            .parse()
+            .map(JsonValue::Number)
+            .map_err(|_| ParseError {
+                kind: ParseErrorKind::InvalidNumber,
+                position: start,
+            })
+    }
+
+    fn skip_digits(&mut self) {
+        while let Some('0'..='9') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, ParseError> {
+        let start = self.pos;
+        self.expect('"')?;
+        let mut result = String::new();
+        loop {
+            match self.next_char() {
+                None => {
+                    return Err(ParseError {
+                        kind: ParseErrorKind::UnterminatedString,
+                        position: start,
+                    });
+                }
+                Some('"') => return Ok(result),
+                Some('\\') => result.push(self.parse_escape()?),
+                // JSON字符串中不允许出现未转义的控制字符
+                Some(c) if (c as u32) < 0x20 => {
+                    self.pos -= c.len_utf8();
+                    return Err(self.error(ParseErrorKind::UnexpectedChar(c)));
+                }
+                Some(c) => result.push(c),
+            }
+        }
+    }
+
+    /// 解析反斜杠之后的转义序列
+    fn parse_escape(&mut self) -> Result<char, ParseError> {
+        let c = match self.next_char() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{08}',
+            Some('f') => '\u{0c}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => {
+                let high = self.parse_hex4()?;
+                if !(0xD800..0xDC00).contains(&high) {
+                    return char::from_u32(high).ok_or(self.error(ParseErrorKind::InvalidEscape));
+                }
+                // UTF-16代理对：高位代理后面必须紧跟一个\u形式的低位代理
+                self.expect('\\')?;
+                self.expect('u')?;
+                let low = self.parse_hex4()?;
+                if !(0xDC00..0xE000).contains(&low) {
+                    return Err(self.error(ParseErrorKind::InvalidEscape));
+                }
+                let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                return char::from_u32(code).ok_or(self.error(ParseErrorKind::InvalidEscape));
+            }
+            Some(_) => return Err(self.error(ParseErrorKind::InvalidEscape)),
+            None => return Err(self.error(ParseErrorKind::UnexpectedEnd)),
+        };
+        Ok(c)
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, ParseError> {
+        let digits = self
+            .input
+            .get(self.pos..self.pos + 4)
+            .ok_or(self.error(ParseErrorKind::InvalidEscape))?;
+        // from_str_radix接受开头的'+'，所以先逐个检查是不是十六进制数字
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(self.error(ParseErrorKind::InvalidEscape));
+        }
+        let value = u32::from_str_radix(digits, 16).expect("已经检查过是4个十六进制数字");
+        self.pos += 4;
+        Ok(value)
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, ParseError> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(JsonValue::Array(items));
+        }
+        loop {
+            self.skip_whitespace();
+            items.push(self.parse_value()?);
+            self.skip_whitespace();
+            if !self.parse_separator(']')? {
+                return Ok(JsonValue::Array(items));
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, ParseError> {
+        self.expect('{')?;
+        let mut fields = BTreeMap::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(JsonValue::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            self.skip_whitespace();
+            let value = self.parse_value()?;
+            // 重复的键以最后一次出现的值为准
+            fields.insert(key, value);
+            self.skip_whitespace();
+            if !self.parse_separator('}')? {
+                return Ok(JsonValue::Object(fields));
+            }
+        }
+    }
+
+    /// 处理数组/对象元素之后的字符：逗号返回true（还有下一个元素），
+    /// 结束符返回false；逗号后面紧跟结束符是多余的逗号
+    fn parse_separator(&mut self, close: char) -> Result<bool, ParseError> {
+        match self.peek() {
+            Some(',') => {
+                let comma = self.pos;
+                self.pos += 1;
+                self.skip_whitespace();
+                if self.peek() == Some(close) {
+                    return Err(ParseError {
+                        kind: ParseErrorKind::TrailingComma,
+                        position: comma,
+                    });
+                }
+                Ok(true)
+            }
+            Some(c) if c == close => {
+                self.pos += 1;
+                Ok(false)
+            }
+            Some(c) => Err(self.error(ParseErrorKind::UnexpectedChar(c))),
+            None => Err(self.error(ParseErrorKind::UnexpectedEnd)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(fields: Vec<(&str, JsonValue)>) -> JsonValue {
+        JsonValue::Object(
+            fields
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_parse_scalars() {
+        assert_eq!(JsonValue::parse("null"), Ok(JsonValue::Null));
+        assert_eq!(JsonValue::parse(" true "), Ok(JsonValue::Bool(true)));
+        assert_eq!(JsonValue::parse("false"), Ok(JsonValue::Bool(false)));
+        assert_eq!(JsonValue::parse("-12.5e2"), Ok(JsonValue::Number(-1250.0)));
+        assert_eq!(JsonValue::parse("0"), Ok(JsonValue::Number(0.0)));
+        assert_eq!(
+            JsonValue::parse(r#""a\"b\\c\n中🦀""#),
+            Ok(JsonValue::String("a\"b\\c\n中🦀".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_nested_object_round_trip() {
+        let text = r#"
+            {
+                "name": "Ferris",
+                "age": 8,
+                "score": 99.5,
+                "active": true,
+                "tags": ["crab", "rust", []],
+                "owner": {"name": "Rust \"Team\"", "members": null},
+                "empty": {}
+            }
+        "#;
+        let value = JsonValue::parse(text).unwrap();
+        let expected = object(vec![
+            ("name", JsonValue::String("Ferris".to_string())),
+            ("age", JsonValue::Number(8.0)),
+            ("score", JsonValue::Number(99.5)),
+            ("active", JsonValue::Bool(true)),
+            (
+                "tags",
+                JsonValue::Array(vec![
+                    JsonValue::String("crab".to_string()),
+                    JsonValue::String("rust".to_string()),
+                    JsonValue::Array(vec![]),
+                ]),
+            ),
+            (
+                "owner",
+                object(vec![
+                    ("name", JsonValue::String("Rust \"Team\"".to_string())),
+                    ("members", JsonValue::Null),
+                ]),
+            ),
+            ("empty", object(vec![])),
+        ]);
+        assert_eq!(value, expected);
+
+        // 键按字典序输出，整数不带小数部分
+        let serialized = value.to_string();
+        assert_eq!(
+            serialized,
+            r#"{"active":true,"age":8,"empty":{},"name":"Ferris","owner":{"members":null,"name":"Rust \"Team\""},"score":99.5,"tags":["crab","rust",[]]}"#
+        );
+        assert_eq!(JsonValue::parse(&serialized).unwrap(), value);
+    }
+
+    #[test]
+    fn test_string_escaping_round_trip() {
+        let original = JsonValue::String("引号\" 反斜杠\\ 换行\n 制表\t 控制\u{01}".to_string());
+        let serialized = original.to_string();
+        assert_eq!(serialized, r#""引号\" 反斜杠\\ 换行\n 制表\t 控制\u0001""#);
+        assert_eq!(serialized.parse::<JsonValue>().unwrap(), original);
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let kind = |input: &str| JsonValue::parse(input).unwrap_err().kind;
+
+        assert_eq!(kind(r#""abc"#), ParseErrorKind::UnterminatedString);
+        assert_eq!(kind(r#"{"a": "b}"#), ParseErrorKind::UnterminatedString);
+        assert_eq!(kind("[1, 2,]"), ParseErrorKind::TrailingComma);
+        assert_eq!(kind(r#"{"a": 1, }"#), ParseErrorKind::TrailingComma);
+        assert_eq!(kind("[1 2]"), ParseErrorKind::UnexpectedChar('2'));
+        assert_eq!(kind("[1, 2"), ParseErrorKind::UnexpectedEnd);
+        assert_eq!(kind(""), ParseErrorKind::UnexpectedEnd);
+        assert_eq!(kind("nul"), ParseErrorKind::UnexpectedEnd);
+        assert_eq!(kind("01"), ParseErrorKind::TrailingCharacters);
+        assert_eq!(kind("1."), ParseErrorKind::InvalidNumber);
+        assert_eq!(kind("-"), ParseErrorKind::InvalidNumber);
+        assert_eq!(kind(r#""\x""#), ParseErrorKind::InvalidEscape);
+        assert_eq!(kind("{1: 2}"), ParseErrorKind::UnexpectedChar('1'));
+        // \u后面必须是4个十六进制数字
+        for escape in [
+            r#""\u+041""#,
+            r#""\u-041""#,
+            r#""\u04g1""#,
+            r#""\u04""#,
+            r#""\ud83d\u+e00""#,
+        ] {
+            assert_eq!(
+                kind(escape),
+                ParseErrorKind::InvalidEscape,
+                "输入: {}",
+                escape
+            );
+        }
+        assert_eq!(
+            JsonValue::parse(r#""\u0041\u00e9""#),
+            Ok(JsonValue::String("Aé".to_string()))
+        );
+
+        let err = JsonValue::parse("[1, 2,]").unwrap_err();
+        assert_eq!(err.position, 5);
+        assert_eq!(err.to_string(), "多余的逗号 (位置 5)");
+    }
+
+    #[test]
+    fn test_nesting_limit() {
+        let nested = |depth: usize| "[".repeat(depth) + &"]".repeat(depth);
+
+        let value = JsonValue::parse(&nested(JsonValue::MAX_DEPTH)).unwrap();
+        assert_eq!(value.to_string(), nested(JsonValue::MAX_DEPTH));
+
+        // 超过限制时返回错误，位置是第一个超出限制的'['
+        let err = JsonValue::parse(&nested(JsonValue::MAX_DEPTH + 1)).unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::TooDeep);
+        assert_eq!(err.position, JsonValue::MAX_DEPTH);
+        assert_eq!(err.to_string(), "嵌套超过128层 (位置 128)");
+
+        // 足以耗尽调用栈的深度同样只是返回错误
+        let err = JsonValue::parse(&nested(200_000)).unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::TooDeep);
+        let objects = r#"{"a":"#.repeat(200_000);
+        assert_eq!(
+            JsonValue::parse(&objects).unwrap_err().kind,
+            ParseErrorKind::TooDeep
+        );
+    }
+
+    #[test]
+    fn test_serialize_deeply_nested_value() {
+        // 手动构造的值不受解析限制，序列化不能依赖递归
+        const DEPTH: usize = 100_000;
+        let mut value = JsonValue::Null;
+        for _ in 0..DEPTH {
+            value = JsonValue::Array(vec![value]);
+        }
+        let expected = "[".repeat(DEPTH) + "null" + &"]".repeat(DEPTH);
+        assert_eq!(value.to_string(), expected);
+        // 缩进为0时每个元素和结束括号各占一行
+        assert_eq!(
+            value.to_pretty_string(0),
+            expected.replace('[', "[\n").replace(']', "\n]")
+        );
+
+        // JsonValue的析构同样是递归的，逐层拆开再释放
+        while let JsonValue::Array(mut items) = value {
+            value = items.pop().unwrap_or(JsonValue::Null);
+        }
+    }
+
+    #[test]
+    fn test_display_is_compact() {
+        let value = JsonValue::parse("[ 1 , { \"k\" : [ ] } , \"s\" ]").unwrap();
+        assert_eq!(format!("{}", value), r#"[1,{"k":[]},"s"]"#);
+    }
+
+    #[test]
+    fn test_pretty_output() {
+        let value = JsonValue::parse(
+            r#"{"name": "Ferris", "tags": ["crab", "rust"], "owner": {"id": 1, "team": []}, "meta": {}}"#,
+        )
+        .unwrap();
+        let expected = r#"{
+    "meta": {},
+    "name": "Ferris",
+    "owner": {
+        "id": 1,
+        "team": []
+    },
+    "tags": [
+        "crab",
+        "rust"
+    ]
+}"#;
+        assert_eq!(value.to_pretty_string(4), expected);
+        assert_eq!(JsonValue::parse(expected).unwrap(), value);
+
+        assert_eq!(JsonValue::Number(1.5).to_pretty_string(2), "1.5");
+        assert_eq!(
+            JsonValue::Array(vec![JsonValue::Null]).to_pretty_string(0),
+            "[\nnull\n]"
+        );
+    }
+
+    #[test]
+    fn test_serde_json_round_trip_each_variant() {
+        let values = vec![
+            JsonValue::Null,
+            JsonValue::Bool(true),
+            JsonValue::Number(42.0),
+            JsonValue::Number(-7.0),
+            JsonValue::Number(2.5),
+            JsonValue::String("你好\n".to_string()),
+            JsonValue::Array(vec![JsonValue::Number(1.0), JsonValue::Null]),
+            object(vec![
+                ("nested", object(vec![("ok", JsonValue::Bool(false))])),
+                ("list", JsonValue::Array(vec![])),
+            ]),
+        ];
+        for value in values {
+            let serde_value = serde_json::Value::from(value.clone());
+            // 两边序列化出的文本一致
+            assert_eq!(serde_value.to_string(), value.to_string());
+            assert_eq!(JsonValue::from(serde_value), value);
+        }
+    }
+
+    #[test]
+    fn test_serde_json_number_mapping() {
+        assert!(serde_json::Value::from(JsonValue::Number(3.0)).is_i64());
+        assert!(serde_json::Value::from(JsonValue::Number(3.5)).is_f64());
+        assert_eq!(
+            serde_json::Value::from(JsonValue::Number(f64::NAN)),
+            serde_json::Value::Null
+        );
+
+        let parsed: serde_json::Value =
+            serde_json::from_str(r#"{"big": 18446744073709551615, "neg": -1, "ratio": 0.25}"#)
+                .unwrap();
+        let value = JsonValue::from(parsed);
+        let JsonValue::Object(fields) = value else {
+            panic!("expected object");
+        };
+        assert_eq!(fields["big"], JsonValue::Number(u64::MAX as f64));
+        assert_eq!(fields["neg"], JsonValue::Number(-1.0));
+        assert_eq!(fields["ratio"], JsonValue::Number(0.25));
+    }
+}
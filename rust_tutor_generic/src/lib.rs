@@ -0,0 +1,32 @@
+//! # Rust 泛型教程 - 可复用组件
+//!
+//! `main.rs`中的交互菜单按编号运行各个例子，
+//! 这个库把例子中反复出现的类型整理成可以直接复用和测试的组件。
+//!
+//! ## 模块
+//!
+//! - [`collections`] - 借用切片的滑动窗口迭代器
+//! - [`combinators`] - 在函子之上链式组合可能失败的计算
+//! - [`functor`] - 用泛型关联类型模拟的函子特征
+//! - [`hlist`] - 异构列表，支持编译期长度和逐元素映射
+//! - [`json`] - JSON值类型、解析器和序列化器
+//! - [`parser`] - 基于关联类型的解析器组合子
+//! - [`peano`] - 类型级的皮亚诺自然数和加法
+//! - [`query`] - 按阶段执行过滤、排序和截取的查询构建器
+//! - [`singleton`] - 基于`OnceLock`的线程安全延迟初始化单例
+//! - [`type_map`] - 以类型为键、每种类型保存一个值的映射
+//! - [`typestate`] - 类型状态模式实现的带密码保险箱
+//! - [`units`] - 用幻影类型标记单位的度量值
+
+pub mod collections;
+pub mod combinators;
+pub mod functor;
+pub mod hlist;
+pub mod json;
+pub mod parser;
+pub mod peano;
+pub mod query;
+pub mod singleton;
+pub mod type_map;
+pub mod typestate;
+pub mod units;
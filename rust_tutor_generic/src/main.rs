@@ -12,7 +12,50 @@ mod example08_associated_types;
 mod example09_lifetime_generics;
 mod example10_advanced_generics;
 
+/// 例子的数量，编号从1开始
+const EXAMPLE_COUNT: u32 = 10;
+
+/// 用法：
+/// - `cargo run` 进入交互式菜单
+/// - `cargo run -- 3` 运行例子3后退出
+/// - `cargo run -- all` 按顺序运行所有例子后退出
 fn main() {
+    match std::env::args().nth(1) {
+        None => interactive(),
+        Some(arg) if arg == "all" => {
+            for n in 1..=EXAMPLE_COUNT {
+                run_example(n);
+            }
+        }
+        Some(arg) => {
+            let found = arg.parse().map(run_example).unwrap_or(false);
+            if !found {
+                eprintln!("❌ 无效的例子编号: {}（可选 1-{} 或 all）", arg, EXAMPLE_COUNT);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// 运行编号为`n`的例子，编号无效时返回false
+fn run_example(n: u32) -> bool {
+    match n {
+        1 => example01_basic_generics::run(),
+        2 => example02_generic_functions::run(),
+        3 => example03_generic_structs::run(),
+        4 => example04_generic_enums::run(),
+        5 => example05_generic_traits::run(),
+        6 => example06_trait_bounds::run(),
+        7 => example07_where_clause::run(),
+        8 => example08_associated_types::run(),
+        9 => example09_lifetime_generics::run(),
+        10 => example10_advanced_generics::run(),
+        _ => return false,
+    }
+    true
+}
+
+fn interactive() {
     println!("🦀 Rust 泛型教程 - 交互式学习系统");
     println!("=====================================");
     
@@ -23,21 +66,16 @@ fn main() {
         io::stdin().read_line(&mut input).expect("读取输入失败");
         
         match input.trim() {
-            "1" => example01_basic_generics::run(),
-            "2" => example02_generic_functions::run(),
-            "3" => example03_generic_structs::run(),
-            "4" => example04_generic_enums::run(),
-            "5" => example05_generic_traits::run(),
-            "6" => example06_trait_bounds::run(),
-            "7" => example07_where_clause::run(),
-            "8" => example08_associated_types::run(),
-            "9" => example09_lifetime_generics::run(),
-            "10" => example10_advanced_generics::run(),
             "0" => {
                 println!("感谢使用Rust泛型教程！再见！👋");
                 break;
             }
-            _ => println!("❌ 无效选择，请重新输入"),
+            choice => {
+                let valid = choice.parse().map(run_example).unwrap_or(false);
+                if !valid {
+                    println!("❌ 无效选择，请重新输入");
+                }
+            }
         }
         
         println!("\n按回车键继续...");
@@ -61,3 +99,21 @@ fn print_menu() {
     println!("0.  退出");
     print!("请输入选择 (0-10): ");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_every_example() {
+        for n in 1..=EXAMPLE_COUNT {
+            assert!(run_example(n), "例子{}应该存在", n);
+        }
+    }
+
+    #[test]
+    fn test_invalid_example_number() {
+        assert!(!run_example(0));
+        assert!(!run_example(EXAMPLE_COUNT + 1));
+    }
+}
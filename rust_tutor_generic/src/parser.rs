@@ -0,0 +1,323 @@
+//! 基于关联类型的解析器组合子
+//!
+//! 来自例子8（关联类型）中的`Parser`特征和`Map`组合子。每个解析器通过关联类型
+//! 声明自己的输入、输出和错误类型，组合子只要求这些类型彼此匹配。
+//!
+//! 与例子中一次消耗整个输入不同，这里的解析器返回解析结果和剩余的输入，
+//! 这样才能把多个解析器按顺序串起来：
+//!
+//! ```
+//! use rust_tutor_generic::parser::{literal, number, Combinator, Parser};
+//!
+//! // 解析形如"12+34"的加法表达式并计算结果
+//! let addition = number().and_then(|a| literal("+").and_then(move |_| number().map(move |b| a + b)));
+//! assert_eq!(addition.parse("12+34"), Ok((46, "")));
+//! assert!(addition.parse("12-34").is_err());
+//! ```
+
+use std::fmt;
+
+/// 解析器：从输入的开头解析出一个值，并返回剩余的输入
+pub trait Parser {
+    type Input: ?Sized;
+    type Output;
+    type Error;
+
+    fn parse<'a>(
+        &self,
+        input: &'a Self::Input,
+    ) -> Result<(Self::Output, &'a Self::Input), Self::Error>;
+}
+
+/// 为所有解析器提供的组合子方法
+pub trait Combinator: Parser {
+    /// 用`f`转换解析结果
+    fn map<F, U>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: Fn(Self::Output) -> U,
+    {
+        Map {
+            parser: self,
+            func: f,
+        }
+    }
+
+    /// 根据解析结果构造下一个解析器，并用它继续解析剩余的输入
+    fn and_then<F, Q>(self, f: F) -> AndThen<Self, F>
+    where
+        Self: Sized,
+        F: Fn(Self::Output) -> Q,
+        Q: Parser<Input = Self::Input, Error = Self::Error>,
+    {
+        AndThen {
+            parser: self,
+            func: f,
+        }
+    }
+
+    /// 先尝试自己，失败时在同一位置尝试`other`
+    fn or<Q>(self, other: Q) -> Or<Self, Q>
+    where
+        Self: Sized,
+        Q: Parser<Input = Self::Input, Output = Self::Output, Error = Self::Error>,
+    {
+        Or {
+            first: self,
+            second: other,
+        }
+    }
+}
+
+impl<P: Parser> Combinator for P {}
+
+/// [`Combinator::map`]返回的解析器
+#[derive(Debug, Clone)]
+pub struct Map<P, F> {
+    parser: P,
+    func: F,
+}
+
+impl<P, F, U> Parser for Map<P, F>
+where
+    P: Parser,
+    F: Fn(P::Output) -> U,
+{
+    type Input = P::Input;
+    type Output = U;
+    type Error = P::Error;
+
+    fn parse<'a>(&self, input: &'a P::Input) -> Result<(U, &'a P::Input), P::Error> {
+        let (output, rest) = self.parser.parse(input)?;
+        Ok(((self.func)(output), rest))
+    }
+}
+
+/// [`Combinator::and_then`]返回的解析器
+#[derive(Debug, Clone)]
+pub struct AndThen<P, F> {
+    parser: P,
+    func: F,
+}
+
+impl<P, F, Q> Parser for AndThen<P, F>
+where
+    P: Parser,
+    F: Fn(P::Output) -> Q,
+    Q: Parser<Input = P::Input, Error = P::Error>,
+{
+    type Input = P::Input;
+    type Output = Q::Output;
+    type Error = P::Error;
+
+    fn parse<'a>(&self, input: &'a P::Input) -> Result<(Q::Output, &'a P::Input), P::Error> {
+        let (output, rest) = self.parser.parse(input)?;
+        (self.func)(output).parse(rest)
+    }
+}
+
+/// [`Combinator::or`]返回的解析器
+#[derive(Debug, Clone)]
+pub struct Or<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Parser for Or<A, B>
+where
+    A: Parser,
+    B: Parser<Input = A::Input, Output = A::Output, Error = A::Error>,
+{
+    type Input = A::Input;
+    type Output = A::Output;
+    type Error = A::Error;
+
+    fn parse<'a>(&self, input: &'a A::Input) -> Result<(A::Output, &'a A::Input), A::Error> {
+        self.first
+            .parse(input)
+            .or_else(|_| self.second.parse(input))
+    }
+}
+
+/// 文本解析器的错误：期望的内容和实际遇到的字符（`None`表示输入已结束）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub expected: String,
+    pub found: Option<char>,
+}
+
+impl ParseError {
+    fn new(expected: impl Into<String>, input: &str) -> Self {
+        ParseError {
+            expected: expected.into(),
+            found: input.chars().next(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.found {
+            Some(c) => write!(f, "期望{}，但遇到{:?}", self.expected, c),
+            None => write!(f, "期望{}，但输入已结束", self.expected),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// 解析一位十进制数字，输出它的数值
+pub fn digit() -> Digit {
+    Digit
+}
+
+/// [`digit`]返回的解析器
+#[derive(Debug, Clone, Copy)]
+pub struct Digit;
+
+impl Parser for Digit {
+    type Input = str;
+    type Output = u32;
+    type Error = ParseError;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<(u32, &'a str), ParseError> {
+        match input.chars().next().and_then(|c| c.to_digit(10)) {
+            Some(value) => Ok((value, &input[1..])),
+            None => Err(ParseError::new("数字", input)),
+        }
+    }
+}
+
+/// 解析一个十进制整数，允许以`-`开头
+pub fn number() -> Number {
+    Number
+}
+
+/// [`number`]返回的解析器
+#[derive(Debug, Clone, Copy)]
+pub struct Number;
+
+impl Parser for Number {
+    type Input = str;
+    type Output = i64;
+    type Error = ParseError;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<(i64, &'a str), ParseError> {
+        let sign_len = usize::from(input.starts_with('-'));
+        let digits_len = input[sign_len..]
+            .bytes()
+            .take_while(u8::is_ascii_digit)
+            .count();
+        if digits_len == 0 {
+            return Err(ParseError::new("整数", &input[sign_len..]));
+        }
+        let end = sign_len + digits_len;
+        let value = input[..end]
+            .parse()
+            .map_err(|_| ParseError::new("i64范围内的整数", input))?;
+        Ok((value, &input[end..]))
+    }
+}
+
+/// 匹配固定的字符串
+pub fn literal(expected: &str) -> Literal {
+    Literal {
+        expected: expected.to_string(),
+    }
+}
+
+/// [`literal`]返回的解析器，匹配成功时输出`()`
+#[derive(Debug, Clone)]
+pub struct Literal {
+    expected: String,
+}
+
+impl Parser for Literal {
+    type Input = str;
+    type Output = ();
+    type Error = ParseError;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<((), &'a str), ParseError> {
+        match input.strip_prefix(self.expected.as_str()) {
+            Some(rest) => Ok(((), rest)),
+            None => Err(ParseError::new(format!("{:?}", self.expected), input)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitives() {
+        assert_eq!(digit().parse("7a"), Ok((7, "a")));
+        assert_eq!(
+            digit().parse("a7").unwrap_err(),
+            ParseError {
+                expected: "数字".to_string(),
+                found: Some('a')
+            }
+        );
+
+        assert_eq!(number().parse("1234+"), Ok((1234, "+")));
+        assert_eq!(number().parse("-56"), Ok((-56, "")));
+        assert!(number().parse("-").is_err());
+        assert!(number().parse("99999999999999999999").is_err());
+
+        assert_eq!(literal("let").parse("let x"), Ok(((), " x")));
+        let err = literal("let").parse("le").unwrap_err();
+        assert_eq!(err.to_string(), "期望\"let\"，但遇到'l'");
+        assert_eq!(
+            digit().parse("").unwrap_err().to_string(),
+            "期望数字，但输入已结束"
+        );
+    }
+
+    #[test]
+    fn test_map() {
+        let doubled = number().map(|n| n * 2);
+        assert_eq!(doubled.parse("21rest"), Ok((42, "rest")));
+        assert!(doubled.parse("x").is_err());
+    }
+
+    #[test]
+    fn test_and_then() {
+        // 两位数字组成一个两位数
+        let two_digits = digit().and_then(|tens| digit().map(move |ones| tens * 10 + ones));
+        assert_eq!(two_digits.parse("427"), Ok((42, "7")));
+        assert!(two_digits.parse("4").is_err());
+    }
+
+    #[test]
+    fn test_or() {
+        let sign = literal("+").map(|_| 1).or(literal("-").map(|_| -1));
+        assert_eq!(sign.parse("+1"), Ok((1, "1")));
+        assert_eq!(sign.parse("-1"), Ok((-1, "1")));
+        // 两个分支都失败时返回第二个分支的错误
+        let err = sign.parse("*1").unwrap_err();
+        assert_eq!(err.expected, "\"-\"");
+    }
+
+    #[test]
+    fn test_composed_arithmetic() {
+        let operator = literal("+")
+            .map(|_| '+')
+            .or(literal("-").map(|_| '-'))
+            .or(literal("*").map(|_| '*'));
+        let expression = number().and_then(move |a| {
+            operator.clone().and_then(move |op| {
+                number().map(move |b| match op {
+                    '+' => a + b,
+                    '-' => a - b,
+                    _ => a * b,
+                })
+            })
+        });
+
+        assert_eq!(expression.parse("12+34"), Ok((46, "")));
+        assert_eq!(expression.parse("12-34"), Ok((-22, "")));
+        assert_eq!(expression.parse("6*7;"), Ok((42, ";")));
+        assert!(expression.parse("12/34").is_err());
+    }
+}
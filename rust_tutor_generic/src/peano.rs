@@ -0,0 +1,117 @@
+//! 类型级的皮亚诺自然数
+//!
+//! 来自例子10（高级泛型技巧）中的“类型级编程”和“类型级别的计算”。示例为0到3分别定义了结构体，
+//! 加法也只能逐个列出几种组合；这里用`Zero`和`Succ<N>`递归地表示任意自然数，
+//! 两条递归的`impl`就覆盖了所有加法，计算全部在编译期由类型推导完成。
+//!
+//! ```
+//! use rust_tutor_generic::peano::{Add, One, Sum, ToUsize, Two};
+//!
+//! // 2 + 1 的结果是一个类型，它的值在编译期就确定了
+//! type Three = Sum<Two, One>;
+//! assert_eq!(Three::VALUE, 3);
+//! assert_eq!(<Sum<Three, Three> as ToUsize>::to_usize(), 6);
+//! ```
+
+use std::marker::PhantomData;
+
+/// 自然数0
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Zero;
+
+/// `N`的后继，即`N + 1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Succ<N>(PhantomData<N>);
+
+// 手动实现而不是derive，derive会额外要求N: Default
+impl<N> Default for Succ<N> {
+    fn default() -> Self {
+        Succ(PhantomData)
+    }
+}
+
+pub type One = Succ<Zero>;
+pub type Two = Succ<One>;
+pub type Three = Succ<Two>;
+
+/// 读取类型所代表的自然数
+pub trait ToUsize {
+    /// 编译期确定的值
+    const VALUE: usize;
+
+    /// 运行时读取值，等价于`Self::VALUE`
+    fn to_usize() -> usize {
+        Self::VALUE
+    }
+}
+
+impl ToUsize for Zero {
+    const VALUE: usize = 0;
+}
+
+impl<N: ToUsize> ToUsize for Succ<N> {
+    const VALUE: usize = N::VALUE + 1;
+}
+
+/// 类型级的加法关系，`Output`是`Self + Rhs`的结果
+pub trait Add<Rhs> {
+    type Output;
+}
+
+/// 0 + b = b
+impl<Rhs> Add<Rhs> for Zero {
+    type Output = Rhs;
+}
+
+/// (a + 1) + b = (a + b) + 1
+impl<N: Add<Rhs>, Rhs> Add<Rhs> for Succ<N> {
+    type Output = Succ<N::Output>;
+}
+
+/// `A + B`的结果类型
+pub type Sum<A, B> = <A as Add<B>>::Output;
+
+/// 用值调用的加法：参数只用来确定类型，返回值的类型就是计算结果
+///
+/// ```
+/// use rust_tutor_generic::peano::{add, One, ToUsize, Two};
+///
+/// fn value_of<N: ToUsize>(_: N) -> usize {
+///     N::VALUE
+/// }
+///
+/// assert_eq!(value_of(add(Two::default(), One::default())), 3);
+/// ```
+pub fn add<A, B>(_: A, _: B) -> Sum<A, B>
+where
+    A: Add<B>,
+    Sum<A, B>: Default,
+{
+    Default::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_two_plus_one_is_three() {
+        type Result = Sum<Succ<Succ<Zero>>, Succ<Zero>>;
+        assert_eq!(Result::VALUE, 3);
+        assert_eq!(Result::to_usize(), 3);
+        // 结果与直接写出的3是同一个类型
+        let three: Three = add(Two::default(), One::default());
+        assert_eq!(three, Succ::default());
+    }
+
+    #[test]
+    fn test_identity_and_larger_sums() {
+        type Five = Sum<Two, Three>;
+        type Ten = Sum<Five, Five>;
+        assert_eq!(<Sum<Zero, Zero>>::VALUE, 0);
+        assert_eq!(<Sum<Three, Zero>>::VALUE, 3);
+        assert_eq!(<Sum<Zero, Three>>::VALUE, 3);
+        assert_eq!(Ten::VALUE, 10);
+        assert_eq!(<Sum<Ten, Sum<Ten, One>>>::VALUE, 21);
+    }
+}
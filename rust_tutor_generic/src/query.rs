@@ -0,0 +1,165 @@
+//! 链式的内存查询构建器
+//!
+//! 来自例子7（Where子句）中的`QueryBuilder<T>`。示例只支持过滤；
+//! 这里把查询拆成按顺序执行的阶段，增加了排序和截取，闭包仍然装箱保存。
+//!
+//! ```
+//! use rust_tutor_generic::query::QueryBuilder;
+//!
+//! let result = QueryBuilder::new(vec![5, 3, 8, 1, 9, 2])
+//!     .filter(|&x| x > 2)
+//!     .sort_by(|a, b| b.cmp(a))
+//!     .take(2)
+//!     .execute();
+//! assert_eq!(result, vec![9, 8]);
+//! ```
+
+use std::cmp::Ordering;
+
+type Predicate<T> = Box<dyn Fn(&T) -> bool>;
+type Comparator<T> = Box<dyn Fn(&T, &T) -> Ordering>;
+
+/// 查询中的一个阶段
+enum Stage<T> {
+    Filter(Predicate<T>),
+    Sort(Comparator<T>),
+    Take(usize),
+}
+
+/// 查询构建器：记录各个阶段，调用[`execute`](QueryBuilder::execute)时按添加顺序执行
+pub struct QueryBuilder<T> {
+    data: Vec<T>,
+    stages: Vec<Stage<T>>,
+}
+
+impl<T> QueryBuilder<T>
+where
+    T: Clone,
+{
+    /// 以`data`为数据源创建查询
+    pub fn new(data: Vec<T>) -> Self {
+        QueryBuilder {
+            data,
+            stages: Vec::new(),
+        }
+    }
+
+    /// 只保留满足`predicate`的元素
+    pub fn filter<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&T) -> bool + 'static,
+    {
+        self.stages.push(Stage::Filter(Box::new(predicate)));
+        self
+    }
+
+    /// 按`compare`排序，排序是稳定的
+    pub fn sort_by<F>(mut self, compare: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + 'static,
+    {
+        self.stages.push(Stage::Sort(Box::new(compare)));
+        self
+    }
+
+    /// 只保留前`n`个元素
+    pub fn take(mut self, n: usize) -> Self {
+        self.stages.push(Stage::Take(n));
+        self
+    }
+
+    /// 在数据源的副本上依次执行所有阶段，数据源本身不变，查询可以重复执行
+    pub fn execute(&self) -> Vec<T> {
+        let mut result = self.data.clone();
+        for stage in &self.stages {
+            match stage {
+                Stage::Filter(predicate) => result.retain(|item| predicate(item)),
+                Stage::Sort(compare) => result.sort_by(|a, b| compare(a, b)),
+                Stage::Take(n) => result.truncate(*n),
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Student {
+        name: &'static str,
+        age: u32,
+        score: u32,
+    }
+
+    fn students() -> Vec<Student> {
+        vec![
+            Student {
+                name: "张三",
+                age: 20,
+                score: 85,
+            },
+            Student {
+                name: "李四",
+                age: 17,
+                score: 92,
+            },
+            Student {
+                name: "王五",
+                age: 22,
+                score: 78,
+            },
+            Student {
+                name: "赵六",
+                age: 19,
+                score: 92,
+            },
+            Student {
+                name: "孙七",
+                age: 21,
+                score: 60,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_filter_sort_take() {
+        let names: Vec<_> = QueryBuilder::new(students())
+            .filter(|s| s.age >= 18)
+            .sort_by(|a, b| b.score.cmp(&a.score))
+            .take(3)
+            .execute()
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        assert_eq!(names, vec!["赵六", "张三", "王五"]);
+    }
+
+    #[test]
+    fn test_stages_run_in_order() {
+        let data = vec![5, 3, 8, 1, 9, 2];
+        // 先截取再过滤，与先过滤再截取结果不同
+        let take_first = QueryBuilder::new(data.clone())
+            .take(3)
+            .filter(|&x| x > 4)
+            .execute();
+        assert_eq!(take_first, vec![5, 8]);
+
+        let filter_first = QueryBuilder::new(data).filter(|&x| x > 4).take(3).execute();
+        assert_eq!(filter_first, vec![5, 8, 9]);
+    }
+
+    #[test]
+    fn test_sort_is_stable_and_query_is_reusable() {
+        let query = QueryBuilder::new(students()).sort_by(|a, b| b.score.cmp(&a.score));
+        let first = query.execute();
+        // 分数相同的李四和赵六保持原来的先后顺序
+        assert_eq!(first[0].name, "李四");
+        assert_eq!(first[1].name, "赵六");
+        assert_eq!(query.execute(), first);
+
+        assert_eq!(QueryBuilder::new(vec![1, 2]).take(10).execute(), vec![1, 2]);
+        assert!(QueryBuilder::<i32>::new(Vec::new()).execute().is_empty());
+    }
+}
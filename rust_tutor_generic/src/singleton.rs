@@ -0,0 +1,94 @@
+//! 线程安全的延迟初始化单例
+//!
+//! 来自例子10（高级泛型技巧）中的`Singleton<T>`。示例用`Arc<Mutex<Option<T>>>`保存值，
+//! 每次读取都要加锁，还只能通过闭包访问；这里改用`std::sync::OnceLock`，
+//! 初始化只执行一次，之后的读取不需要加锁，可以直接拿到`&T`。
+//!
+//! ```
+//! use rust_tutor_generic::singleton::Lazy;
+//!
+//! static CONFIG: Lazy<String> = Lazy::new();
+//!
+//! assert_eq!(CONFIG.get(), None);
+//! assert_eq!(CONFIG.get_or_init(|| "全局配置".to_string()), "全局配置");
+//! // 已经初始化过，新的初始化函数不会执行
+//! assert_eq!(CONFIG.get_or_init(|| "另一个配置".to_string()), "全局配置");
+//! ```
+
+use std::sync::OnceLock;
+
+/// 只初始化一次的值，可以放在`static`中作为全局单例
+#[derive(Debug, Default)]
+pub struct Lazy<T> {
+    cell: OnceLock<T>,
+}
+
+impl<T> Lazy<T> {
+    /// 创建尚未初始化的单例
+    pub const fn new() -> Self {
+        Lazy {
+            cell: OnceLock::new(),
+        }
+    }
+
+    /// 返回已经初始化的值；还没有初始化时用`f`初始化
+    ///
+    /// 多个线程同时调用时只有一个线程会执行`f`，其他线程等待它完成后得到同一个值。
+    pub fn get_or_init(&self, f: impl FnOnce() -> T) -> &T {
+        self.cell.get_or_init(f)
+    }
+
+    /// 返回已经初始化的值，不会触发初始化
+    pub fn get(&self) -> Option<&T> {
+        self.cell.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Barrier;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    #[test]
+    fn test_get_before_and_after_init() {
+        let lazy = Lazy::new();
+        assert_eq!(lazy.get(), None);
+        assert_eq!(*lazy.get_or_init(|| 42), 42);
+        assert_eq!(lazy.get(), Some(&42));
+    }
+
+    #[test]
+    fn test_initializer_runs_once_across_threads() {
+        static VALUE: Lazy<Vec<u32>> = Lazy::new();
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        const THREADS: usize = 8;
+
+        // 所有线程在同一时刻开始竞争初始化
+        let barrier = Barrier::new(THREADS);
+        let addresses: Vec<usize> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..THREADS)
+                .map(|i| {
+                    let barrier = &barrier;
+                    scope.spawn(move || {
+                        barrier.wait();
+                        let value = VALUE.get_or_init(|| {
+                            CALLS.fetch_add(1, Ordering::SeqCst);
+                            vec![i as u32; 3]
+                        });
+                        value as *const Vec<u32> as usize
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+        // 每个线程拿到的都是同一个值的引用
+        assert!(addresses.iter().all(|&addr| addr == addresses[0]));
+        let value = VALUE.get().unwrap();
+        assert_eq!(value.len(), 3);
+        assert!(value.iter().all(|&x| x == value[0]));
+    }
+}
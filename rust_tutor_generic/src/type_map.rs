@@ -0,0 +1,120 @@
+//! 以类型为键的异构映射
+//!
+//! 例子10（高级泛型技巧）中的异构列表（[`crate::hlist`]）在编译期就确定了每个位置的类型；
+//! [`TypeMap`]则在运行时按类型存取：每种具体类型最多保存一个值，
+//! 用`TypeId`作为键，用`Box<dyn Any>`擦除类型，取出时再向下转换回原来的类型。
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+/// 每种类型最多保存一个值的映射
+///
+/// ```
+/// use rust_tutor_generic::type_map::TypeMap;
+///
+/// let mut map = TypeMap::new();
+/// map.insert(42_i32);
+/// map.insert(String::from("配置"));
+///
+/// assert_eq!(map.get::<i32>(), Some(&42));
+/// assert_eq!(map.get::<String>().map(String::as_str), Some("配置"));
+/// // 没有存过u64，即使存过数值相同的i32也取不到
+/// assert_eq!(map.get::<u64>(), None);
+/// ```
+#[derive(Debug, Default)]
+pub struct TypeMap {
+    values: HashMap<TypeId, Box<dyn Any>>,
+}
+
+impl TypeMap {
+    /// 创建空映射
+    pub fn new() -> Self {
+        TypeMap::default()
+    }
+
+    /// 保存一个`T`类型的值，返回之前保存的同类型的值
+    pub fn insert<T: 'static>(&mut self, value: T) -> Option<T> {
+        self.values
+            .insert(TypeId::of::<T>(), Box::new(value))
+            .map(|old| *old.downcast::<T>().expect("键与值的类型一致"))
+    }
+
+    /// 读取`T`类型的值
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref::<T>())
+    }
+
+    /// 可变地读取`T`类型的值
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.values
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_mut::<T>())
+    }
+
+    /// 取出并移除`T`类型的值
+    pub fn remove<T: 'static>(&mut self) -> Option<T> {
+        self.values
+            .remove(&TypeId::of::<T>())
+            .map(|value| *value.downcast::<T>().expect("键与值的类型一致"))
+    }
+
+    /// 是否保存了`T`类型的值
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.values.contains_key(&TypeId::of::<T>())
+    }
+
+    /// 保存的值的数量（也就是不同类型的数量）
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_get_by_type() {
+        let mut map = TypeMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.insert(7_i32), None);
+        assert_eq!(map.insert(String::from("你好")), None);
+
+        assert_eq!(map.get::<i32>(), Some(&7));
+        assert_eq!(map.get::<String>(), Some(&String::from("你好")));
+        assert_eq!(map.get::<i64>(), None);
+        assert!(map.contains::<i32>());
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn test_insert_replaces_same_type() {
+        let mut map = TypeMap::new();
+        map.insert(vec![1, 2]);
+        assert_eq!(map.insert(vec![3]), Some(vec![1, 2]));
+        map.get_mut::<Vec<i32>>().unwrap().push(4);
+        assert_eq!(map.get::<Vec<i32>>(), Some(&vec![3, 4]));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_remove() {
+        #[derive(Debug, PartialEq)]
+        struct Port(u16);
+
+        let mut map = TypeMap::new();
+        map.insert(Port(8080));
+        map.insert(true);
+        assert_eq!(map.remove::<Port>(), Some(Port(8080)));
+        assert_eq!(map.remove::<Port>(), None);
+        assert_eq!(map.get::<bool>(), Some(&true));
+        assert_eq!(map.len(), 1);
+    }
+}
@@ -0,0 +1,157 @@
+//! 类型状态模式：带密码的保险箱
+//!
+//! 来自例子10（高级泛型技巧）中的`SafeBox<T, State>`。状态用幻影类型表示，
+//! 只有`SafeBox<T, Unlocked>`才有`take`/`put`方法，状态转换在编译期检查；
+//! 示例中的`unlock`会忽略密码，这里改为校验创建时设置的密码。
+//!
+//! ```
+//! use rust_tutor_generic::typestate::SafeBox;
+//!
+//! let safe = SafeBox::new("秘密文档", "password123");
+//! let mut opened = safe.unlock("password123").unwrap();
+//! assert_eq!(opened.take(), Some("秘密文档"));
+//! opened.put("新文档");
+//! let _locked = opened.lock();
+//! ```
+//!
+//! 锁定状态下无法取出内容，下面的代码不能通过编译：
+//!
+//! ```compile_fail
+//! use rust_tutor_generic::typestate::SafeBox;
+//!
+//! let mut safe = SafeBox::new("秘密文档", "password123");
+//! safe.take();
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// 锁定状态
+#[derive(Debug, Clone, Copy)]
+pub struct Locked;
+
+/// 解锁状态
+#[derive(Debug, Clone, Copy)]
+pub struct Unlocked;
+
+/// 用幻影类型`State`记录当前状态的保险箱，新建的保险箱总是锁定的
+pub struct SafeBox<T, State = Locked> {
+    content: Option<T>,
+    password: String,
+    _state: PhantomData<State>,
+}
+
+impl<T, State> SafeBox<T, State> {
+    /// 转换到另一个状态，内容和密码保持不变
+    fn transition<Next>(self) -> SafeBox<T, Next> {
+        SafeBox {
+            content: self.content,
+            password: self.password,
+            _state: PhantomData,
+        }
+    }
+}
+
+// 手动实现，不要求T: Debug，也不会把内容和密码打印出来
+impl<T, State> fmt::Debug for SafeBox<T, State> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SafeBox")
+            .field("state", &std::any::type_name::<State>())
+            .field("has_content", &self.content.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> SafeBox<T, Locked> {
+    /// 创建装有`content`的锁定保险箱，之后需要用`password`解锁
+    pub fn new(content: T, password: impl Into<String>) -> Self {
+        SafeBox {
+            content: Some(content),
+            password: password.into(),
+            _state: PhantomData,
+        }
+    }
+
+    /// 用密码解锁；密码错误时保险箱保持锁定，并通过错误原样返回
+    pub fn unlock(self, password: &str) -> Result<SafeBox<T, Unlocked>, WrongPassword<T>> {
+        if self.password == password {
+            Ok(self.transition())
+        } else {
+            Err(WrongPassword { safe: self })
+        }
+    }
+}
+
+impl<T> SafeBox<T, Unlocked> {
+    /// 取出内容，保险箱变为空
+    pub fn take(&mut self) -> Option<T> {
+        self.content.take()
+    }
+
+    /// 放入内容，替换原有的内容
+    pub fn put(&mut self, content: T) {
+        self.content = Some(content);
+    }
+
+    /// 重新锁定，密码不变
+    pub fn lock(self) -> SafeBox<T, Locked> {
+        self.transition()
+    }
+}
+
+/// 密码错误时返回的错误，保存了仍处于锁定状态的保险箱
+pub struct WrongPassword<T> {
+    safe: SafeBox<T, Locked>,
+}
+
+impl<T> WrongPassword<T> {
+    /// 取回锁定的保险箱，可以用正确的密码再试一次
+    pub fn into_safe(self) -> SafeBox<T, Locked> {
+        self.safe
+    }
+}
+
+impl<T> fmt::Debug for WrongPassword<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WrongPassword")
+            .field("safe", &self.safe)
+            .finish()
+    }
+}
+
+impl<T> fmt::Display for WrongPassword<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "密码错误，保险箱仍处于锁定状态")
+    }
+}
+
+impl<T> std::error::Error for WrongPassword<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlock_with_right_password() {
+        let safe = SafeBox::new(vec![1, 2, 3], "secret");
+        let mut opened = safe.unlock("secret").unwrap();
+        assert_eq!(opened.take(), Some(vec![1, 2, 3]));
+        assert_eq!(opened.take(), None);
+
+        opened.put(vec![4]);
+        // 重新锁定后密码不变
+        let mut reopened = opened.lock().unlock("secret").unwrap();
+        assert_eq!(reopened.take(), Some(vec![4]));
+    }
+
+    #[test]
+    fn test_unlock_with_wrong_password() {
+        let safe = SafeBox::new("文档", "secret");
+        let err = safe.unlock("guess").unwrap_err();
+        assert_eq!(err.to_string(), "密码错误，保险箱仍处于锁定状态");
+
+        // 错误中取回的保险箱内容完好，可以重试
+        let mut opened = err.into_safe().unlock("secret").unwrap();
+        assert_eq!(opened.take(), Some("文档"));
+    }
+}
@@ -0,0 +1,200 @@
+//! 用幻影类型标记单位的度量值
+//!
+//! 来自例子10（高级泛型技巧）中的`Measurement<T, Unit>`。单位只存在于类型中，
+//! 运行时只保存一个`f64`；不同单位的度量值是不同的类型，编译器会阻止它们相加。
+//! 示例只有米到英尺、摄氏到华氏两种转换，这里为长度单位统一提供了换算。
+//!
+//! ```
+//! use rust_tutor_generic::units::{Kilometers, Measurement, Meters, Miles};
+//!
+//! let total = Measurement::<Meters>::new(1.0) + Measurement::new(2.0);
+//! assert_eq!(total.value(), 3.0);
+//!
+//! let marathon = Measurement::<Kilometers>::new(42.195);
+//! let miles: Measurement<Miles> = marathon.convert();
+//! assert!((miles.value() - 26.219).abs() < 1e-3);
+//! ```
+//!
+//! 不同单位的度量值不能直接相加，下面的代码不能通过编译：
+//!
+//! ```compile_fail,E0308
+//! use rust_tutor_generic::units::{Feet, Measurement, Meters};
+//!
+//! let sum = Measurement::<Meters>::new(1.0) + Measurement::<Feet>::new(1.0);
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Add;
+
+/// 单位：只用于类型标记，提供显示用的符号
+pub trait Unit {
+    const SYMBOL: &'static str;
+}
+
+/// 长度单位：记录一个单位等于多少米，用于相互换算
+pub trait LengthUnit: Unit {
+    const METERS_PER_UNIT: f64;
+}
+
+macro_rules! length_units {
+    ($($(#[$doc:meta])* $name:ident => $symbol:literal, $meters:expr;)*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub struct $name;
+
+            impl Unit for $name {
+                const SYMBOL: &'static str = $symbol;
+            }
+
+            impl LengthUnit for $name {
+                const METERS_PER_UNIT: f64 = $meters;
+            }
+        )*
+    };
+}
+
+length_units! {
+    /// 米
+    Meters => "m", 1.0;
+    /// 千米
+    Kilometers => "km", 1000.0;
+    /// 英尺
+    Feet => "ft", 0.3048;
+    /// 英寸
+    Inches => "in", 0.0254;
+    /// 英里
+    Miles => "mi", 1609.344;
+}
+
+/// 摄氏度
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Celsius;
+
+impl Unit for Celsius {
+    const SYMBOL: &'static str = "°C";
+}
+
+/// 华氏度
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fahrenheit;
+
+impl Unit for Fahrenheit {
+    const SYMBOL: &'static str = "°F";
+}
+
+/// 以`U`为单位的度量值
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Measurement<U> {
+    value: f64,
+    _unit: PhantomData<U>,
+}
+
+impl<U> Measurement<U> {
+    pub fn new(value: f64) -> Self {
+        Measurement {
+            value,
+            _unit: PhantomData,
+        }
+    }
+
+    /// 以`U`为单位的数值
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl<U: LengthUnit> Measurement<U> {
+    /// 换算成另一个长度单位
+    pub fn convert<V: LengthUnit>(self) -> Measurement<V> {
+        Measurement::new(self.value * U::METERS_PER_UNIT / V::METERS_PER_UNIT)
+    }
+}
+
+impl Measurement<Celsius> {
+    pub fn to_fahrenheit(self) -> Measurement<Fahrenheit> {
+        Measurement::new(self.value * 9.0 / 5.0 + 32.0)
+    }
+}
+
+impl Measurement<Fahrenheit> {
+    pub fn to_celsius(self) -> Measurement<Celsius> {
+        Measurement::new((self.value - 32.0) * 5.0 / 9.0)
+    }
+}
+
+/// 只有相同单位的度量值才能相加
+impl<U> Add for Measurement<U> {
+    type Output = Measurement<U>;
+
+    fn add(self, other: Self) -> Self::Output {
+        Measurement::new(self.value + other.value)
+    }
+}
+
+impl<U: Unit> fmt::Display for Measurement<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 把格式参数（如精度）传给数值部分
+        fmt::Display::fmt(&self.value, f)?;
+        write!(f, "{}", U::SYMBOL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_add_same_unit() {
+        let sum = Measurement::<Meters>::new(1.0) + Measurement::<Meters>::new(2.0);
+        assert_eq!(sum, Measurement::<Meters>::new(3.0));
+        assert_eq!(sum.to_string(), "3m");
+    }
+
+    #[test]
+    fn test_kilometers_miles_round_trip() {
+        let km = Measurement::<Kilometers>::new(1.609344);
+        let miles: Measurement<Miles> = km.convert();
+        assert_close(miles.value(), 1.0);
+
+        let back: Measurement<Kilometers> = miles.convert();
+        assert_close(back.value(), km.value());
+    }
+
+    #[test]
+    fn test_length_conversions() {
+        let foot = Measurement::<Feet>::new(1.0);
+        assert_close(foot.convert::<Inches>().value(), 12.0);
+        assert_close(foot.convert::<Meters>().value(), 0.3048);
+        assert_close(
+            Measurement::<Miles>::new(1.0).convert::<Feet>().value(),
+            5280.0,
+        );
+        // 换算成相同单位不改变数值
+        assert_close(
+            Measurement::<Meters>::new(7.5).convert::<Meters>().value(),
+            7.5,
+        );
+        // 先换算再相加
+        let total = Measurement::<Meters>::new(100.0) + foot.convert();
+        assert_eq!(format!("{:.2}", total), "100.30m");
+    }
+
+    #[test]
+    fn test_temperature_conversions() {
+        let boiling = Measurement::<Celsius>::new(100.0).to_fahrenheit();
+        assert_close(boiling.value(), 212.0);
+        assert_close(boiling.to_celsius().value(), 100.0);
+        assert_eq!(format!("{:.1}", boiling), "212.0°F");
+    }
+}
@@ -0,0 +1,137 @@
+//! 无锁的线程安全计数器
+//!
+//! 示例3（Arc）中的`Counter`用`Mutex<i32>`保护计数值，每次修改都要加锁。
+//! 对单个整数的加减，原子类型就能保证不丢失更新，不需要锁。
+
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+
+/// 基于`AtomicI64`的计数器，所有操作都是无锁的
+///
+/// 计数器只用来计数，不用来同步其他数据，所以使用`Ordering::Relaxed`即可：
+/// 每次读-改-写仍然是原子的，不会丢失更新。
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+/// use rust_tutor_ptr::atomic::AtomicCounter;
+///
+/// let counter = AtomicCounter::new();
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let counter = Arc::clone(&counter);
+///         thread::spawn(move || {
+///             counter.increment();
+///         })
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// assert_eq!(counter.get(), 4);
+/// ```
+#[derive(Debug, Default)]
+pub struct AtomicCounter {
+    value: AtomicI64,
+}
+
+impl AtomicCounter {
+    /// 创建从0开始的计数器，直接包在`Arc`中以便在线程间共享
+    pub fn new() -> Arc<Self> {
+        Arc::new(AtomicCounter::with_value(0))
+    }
+
+    /// 创建从`value`开始的计数器
+    pub fn with_value(value: i64) -> Self {
+        AtomicCounter {
+            value: AtomicI64::new(value),
+        }
+    }
+
+    /// 加1，返回加之后的值
+    pub fn increment(&self) -> i64 {
+        self.add(1)
+    }
+
+    /// 减1，返回减之后的值
+    pub fn decrement(&self) -> i64 {
+        self.add(-1)
+    }
+
+    /// 加上`n`（可以为负数），返回加之后的值；溢出时回绕
+    pub fn add(&self, n: i64) -> i64 {
+        self.value.fetch_add(n, Ordering::Relaxed).wrapping_add(n)
+    }
+
+    /// 当前值
+    pub fn get(&self) -> i64 {
+        self.value.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_basic_operations() {
+        assert_send_sync::<AtomicCounter>();
+
+        let counter = AtomicCounter::with_value(10);
+        assert_eq!(counter.increment(), 11);
+        assert_eq!(counter.decrement(), 10);
+        assert_eq!(counter.add(-15), -5);
+        assert_eq!(counter.add(7), 2);
+        assert_eq!(counter.get(), 2);
+    }
+
+    #[test]
+    fn test_no_lost_updates_under_contention() {
+        const THREADS: i64 = 16;
+        const INCREMENTS: i64 = 100_000;
+
+        let counter = AtomicCounter::new();
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let counter = Arc::clone(&counter);
+                thread::spawn(move || {
+                    for _ in 0..INCREMENTS {
+                        counter.increment();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(counter.get(), THREADS * INCREMENTS);
+        assert_eq!(Arc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn test_concurrent_increment_and_decrement() {
+        let counter = AtomicCounter::new();
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let counter = Arc::clone(&counter);
+                thread::spawn(move || {
+                    for _ in 0..10_000 {
+                        if i % 2 == 0 {
+                            counter.increment();
+                        } else {
+                            counter.decrement();
+                        }
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(counter.get(), 0);
+    }
+}
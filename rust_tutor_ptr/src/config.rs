@@ -0,0 +1,95 @@
+//! 在线程间共享的应用配置
+//!
+//! 来自示例3（Arc）中的`Config`：配置创建后只读，用`Arc<Config>`在多个线程间共享。
+//! 开启`serde`特性后可以和JSON互相转换，方便从文件加载或保存配置。
+
+/// 应用配置
+///
+/// ```
+/// use std::sync::Arc;
+/// use rust_tutor_ptr::config::Config;
+///
+/// let config = Arc::new(Config::new());
+/// let shared = Arc::clone(&config);
+/// assert_eq!(shared.max_connections, 100);
+/// assert_eq!(Arc::strong_count(&config), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
+    pub app_name: String,
+    pub version: String,
+    pub max_connections: usize,
+    pub timeout_seconds: u64,
+}
+
+impl Config {
+    /// 示例中使用的默认配置
+    pub fn new() -> Self {
+        Config {
+            app_name: "智能指针演示应用".to_string(),
+            version: "1.0.0".to_string(),
+            max_connections: 100,
+            timeout_seconds: 30,
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::new()
+    }
+}
+
+/// 把值序列化为JSON字符串（需要开启`serde`特性）
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use rust_tutor_ptr::config::{from_json, to_json, Config};
+///
+/// let json = to_json(&Config::new()).unwrap();
+/// assert!(json.contains(r#""max_connections":100"#));
+/// assert_eq!(from_json::<Config>(&json).unwrap(), Config::new());
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+pub fn to_json<T: serde::Serialize>(value: &T) -> serde_json::Result<String> {
+    serde_json::to_string(value)
+}
+
+/// 从JSON字符串反序列化（需要开启`serde`特性）
+#[cfg(feature = "serde")]
+pub fn from_json<T: serde::de::DeserializeOwned>(json: &str) -> serde_json::Result<T> {
+    serde_json::from_str(json)
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_json_round_trip() {
+        let config = Config {
+            app_name: "测试应用".to_string(),
+            version: "2.1.0".to_string(),
+            max_connections: 8,
+            timeout_seconds: 5,
+        };
+        let json = to_json(&config).unwrap();
+        assert_eq!(
+            json,
+            r#"{"app_name":"测试应用","version":"2.1.0","max_connections":8,"timeout_seconds":5}"#
+        );
+        assert_eq!(from_json::<Config>(&json).unwrap(), config);
+    }
+
+    #[test]
+    fn test_from_json_rejects_invalid_input() {
+        assert!(from_json::<Config>(r#"{"app_name":"x"}"#).is_err());
+        assert!(from_json::<Config>(
+            r#"{"app_name":"x","version":"1","max_connections":-1,"timeout_seconds":1}"#
+        )
+        .is_err());
+        assert!(from_json::<Config>("不是JSON").is_err());
+    }
+}
@@ -13,26 +13,10 @@ use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 use std::sync::Mutex;
+use rust_tutor_ptr::atomic::AtomicCounter;
+use rust_tutor_ptr::config::Config;
 
-// 定义一个共享的配置结构
-#[derive(Debug, Clone)]
-struct Config {
-    app_name: String,
-    version: String,
-    max_connections: usize,
-    timeout_seconds: u64,
-}
-
-impl Config {
-    fn new() -> Self {
-        Config {
-            app_name: "智能指针演示应用".to_string(),
-            version: "1.0.0".to_string(),
-            max_connections: 100,
-            timeout_seconds: 30,
-        }
-    }
-}
+// 共享的配置结构定义在库中（config模块）
 
 // 定义一个工作任务
 #[derive(Debug)]
@@ -58,29 +42,7 @@ impl Task {
     }
 }
 
-// 定义一个简单的线程安全计数器
-#[derive(Debug)]
-struct Counter {
-    value: Mutex<i32>,
-}
-
-impl Counter {
-    fn new() -> Arc<Self> {
-        Arc::new(Counter {
-            value: Mutex::new(0),
-        })
-    }
-    
-    fn increment(&self) {
-        let mut val = self.value.lock().unwrap();
-        *val += 1;
-        println!("计数器增加到: {}", *val);
-    }
-    
-    fn get_value(&self) -> i32 {
-        *self.value.lock().unwrap()
-    }
-}
+// 线程安全的计数器定义在库中（atomic模块），用原子类型代替Mutex，不需要加锁
 
 fn main() {
     println!("=== Rust智能指针教程 - Arc<T> ===\n");
@@ -152,9 +114,9 @@ fn main() {
     
     println!("所有工作完成后，任务队列引用计数: {}\n", Arc::strong_count(&tasks));
     
-    // 4. Arc配合Mutex实现线程安全的可变数据
-    println!("4. Arc + Mutex 实现线程安全的可变数据:");
-    let counter = Counter::new();
+    // 4. Arc配合原子类型实现无锁的可变数据
+    println!("4. Arc + 原子类型 实现无锁计数器:");
+    let counter = AtomicCounter::new();
     println!("初始计数器引用计数: {}", Arc::strong_count(&counter));
     
     let mut counter_handles = vec![];
@@ -164,7 +126,8 @@ fn main() {
         let counter_clone = Arc::clone(&counter);
         let handle = thread::spawn(move || {
             println!("线程 {} 准备增加计数器", i);
-            counter_clone.increment();
+            let value = counter_clone.increment();
+            println!("计数器增加到: {}", value);
         });
         counter_handles.push(handle);
     }
@@ -174,7 +137,7 @@ fn main() {
         handle.join().unwrap();
     }
     
-    println!("最终计数器值: {}", counter.get_value());
+    println!("最终计数器值: {}", counter.get());
     println!("计数器引用计数: {}\n", Arc::strong_count(&counter));
     
     // 5. Arc的内存效率演示
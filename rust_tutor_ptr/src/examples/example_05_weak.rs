@@ -11,6 +11,7 @@
 
 use std::rc::{Rc, Weak};
 use std::cell::RefCell;
+use rust_tutor_ptr::tree::ArenaTree;
 
 // 定义一个树节点，演示父子关系中的循环引用问题
 #[derive(Debug)]
@@ -239,7 +240,26 @@ fn main() {
         println!("孙子节点的父节点值: {}", parent.value);
     }
     
-    println!("根节点的后代数量: {}\n", root.count_descendants());
+    println!("根节点的后代数量: {}", root.count_descendants());
+    
+    // 同样的树也可以不用Rc和Weak：ArenaTree定义在库中（tree模块），
+    // 所有节点放在一个Vec里，父子关系只记录下标，不会出现循环引用
+    let mut arena = ArenaTree::new();
+    let arena_root = arena.add_root(1);
+    let arena_child1 = arena.add_child(arena_root, 2);
+    arena.add_child(arena_root, 3);
+    let arena_grandchild = arena.add_child(arena_child1, 4);
+    let ancestors: Vec<i32> = arena.ancestors(arena_grandchild).map(|id| arena[id]).collect();
+    println!("arena树中孙子节点的祖先: {:?}", ancestors);
+    println!("从根节点到孙子节点的路径（下标）: {:?}", arena.path_to_root(arena_grandchild));
+    println!("arena树的高度: {}", arena.height());
+    // 用缩进表示成文本，也可以再解析回来
+    let text = arena.to_nested_string();
+    print!("arena树的文本表示:\n{}", text);
+    match ArenaTree::from_nested_string(&text) {
+        Ok(parsed) => println!("解析回来的树有{}个节点\n", parsed.len()),
+        Err(e) => println!("解析失败: {}\n", e),
+    }
     
     // 3. 观察者模式
     println!("3. 观察者模式:");
@@ -12,6 +12,10 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 
+// 文本和路径处理函数已经整理到库中
+use rust_tutor_ptr::path::{self, Separator};
+use rust_tutor_ptr::text;
+
 // 定义一个配置管理器，演示Cow在配置处理中的应用
 #[derive(Debug)]
 struct ConfigManager {
@@ -63,96 +67,25 @@ impl ConfigManager {
     }
 }
 
-// 定义一个文本处理器，演示Cow在字符串处理中的应用
-struct TextProcessor;
-
-impl TextProcessor {
-    // 清理文本，只有在需要时才克隆
-    fn clean_text(input: &str) -> Cow<str> {
-        let needs_cleaning = input.chars().any(|c| c.is_whitespace() && c != ' ');
-        
-        if needs_cleaning {
-            // 需要清理，创建新的字符串
-            let cleaned: String = input
-                .chars()
-                .map(|c| if c.is_whitespace() { ' ' } else { c })
-                .collect();
-            Cow::Owned(cleaned)
-        } else {
-            // 不需要清理，直接借用
-            Cow::Borrowed(input)
-        }
-    }
-    
-    // 标准化文本格式
-    fn normalize_text(input: &str) -> Cow<str> {
-        let trimmed = input.trim();
-        
-        if trimmed.len() == input.len() {
-            // 没有需要修剪的空白，直接借用
-            Cow::Borrowed(input)
-        } else {
-            // 需要修剪，创建新字符串
-            Cow::Owned(trimmed.to_string())
-        }
-    }
-    
-    // 添加前缀，演示条件性修改
-    fn add_prefix_if_needed<'a>(input: &'a str, prefix: &'a str) -> Cow<'a, str> {
-        if input.starts_with(prefix) {
-            // 已经有前缀，不需要修改
-            Cow::Borrowed(input)
-        } else {
-            // 需要添加前缀
-            Cow::Owned(format!("{}{}", prefix, input))
-        }
-    }
-}
-
-// 定义一个路径处理器
-struct PathProcessor;
-
-impl PathProcessor {
-    // 标准化路径分隔符
-    fn normalize_path(path: &str) -> Cow<str> {
-        if cfg!(windows) {
-            if path.contains('/') {
-                // Windows上需要将/替换为\
-                Cow::Owned(path.replace('/', "\\"))
-            } else {
-                Cow::Borrowed(path)
-            }
-        } else {
-            if path.contains('\\') {
-                // Unix上需要将\替换为/
-                Cow::Owned(path.replace('\\', "/"))
-            } else {
-                Cow::Borrowed(path)
-            }
-        }
-    }
-    
-    // 确保路径以分隔符结尾
-    fn ensure_trailing_separator(path: &str) -> Cow<str> {
-        let separator = if cfg!(windows) { "\\" } else { "/" };
-        
-        if path.ends_with(separator) {
-            Cow::Borrowed(path)
-        } else {
-            Cow::Owned(format!("{}{}", path, separator))
-        }
-    }
-}
-
 // 定义一个数据转换器，演示Cow在数据处理中的应用
 struct DataConverter;
 
+// 预先计算好的数字表，连续的单位数可以直接借用其中的一段
+const DIGIT_TABLE: &str = "0123456789";
+
 impl DataConverter {
     // 转换数字列表为字符串，只有在需要时才分配新内存
-    fn numbers_to_string(numbers: &[i32]) -> Cow<str> {
+    // 连续递增的单位数（如[3, 4, 5]）正好是数字表的一段，直接借用静态字符串
+    fn numbers_to_string(numbers: &[i32]) -> Cow<'static, str> {
         // 检查是否所有数字都是单位数
-        if numbers.iter().all(|&n| n >= 0 && n <= 9) {
-            // 可以高效地转换为字符串
+        if numbers.iter().all(|&n| (0..=9).contains(&n)) {
+            let is_consecutive = numbers.windows(2).all(|pair| pair[1] == pair[0] + 1);
+            if is_consecutive {
+                // 不需要分配，借用数字表中对应的片段（空切片对应空字符串）
+                let start = numbers.first().map_or(0, |&n| n as usize);
+                return Cow::Borrowed(&DIGIT_TABLE[start..start + numbers.len()]);
+            }
+            // 单位数但不连续，需要逐个转换为字符
             let result: String = numbers.iter().map(|&n| char::from(b'0' + n as u8)).collect();
             Cow::Owned(result)
         } else {
@@ -243,31 +176,47 @@ fn main() {
     let clean_text = "Hello World";
     let dirty_text = "Hello\tWorld\nTest";
     
-    let result1 = TextProcessor::clean_text(clean_text);
+    let result1 = text::clean_whitespace(clean_text);
     println!("清理 '{}': {} (借用: {})", clean_text, result1, matches!(result1, Cow::Borrowed(_)));
     
-    let result2 = TextProcessor::clean_text(dirty_text);
+    let result2 = text::clean_whitespace(dirty_text);
     println!("清理 '{}': {} (借用: {})", dirty_text, result2, matches!(result2, Cow::Borrowed(_)));
     
     // 文本标准化
     let normal_text = "Hello";
     let padded_text = "  Hello  ";
     
-    let norm1 = TextProcessor::normalize_text(normal_text);
+    let norm1 = text::trim_cow(normal_text);
     println!("标准化 '{}': '{}' (借用: {})", normal_text, norm1, matches!(norm1, Cow::Borrowed(_)));
     
-    let norm2 = TextProcessor::normalize_text(padded_text);
+    let norm2 = text::trim_cow(padded_text);
     println!("标准化 '{}': '{}' (借用: {})", padded_text, norm2, matches!(norm2, Cow::Borrowed(_)));
     
     // 添加前缀
     let with_prefix = "Mr. John";
     let without_prefix = "John";
     
-    let prefix1 = TextProcessor::add_prefix_if_needed(with_prefix, "Mr. ");
+    let prefix1 = text::ensure_prefix(with_prefix, "Mr. ");
     println!("添加前缀 '{}': '{}' (借用: {})", with_prefix, prefix1, matches!(prefix1, Cow::Borrowed(_)));
     
-    let prefix2 = TextProcessor::add_prefix_if_needed(without_prefix, "Mr. ");
+    let prefix2 = text::ensure_prefix(without_prefix, "Mr. ");
     println!("添加前缀 '{}': '{}' (借用: {})", without_prefix, prefix2, matches!(prefix2, Cow::Borrowed(_)));
+    
+    // 合并连续空白
+    let tidy_text = "Hello World";
+    let messy_text = "  Hello \t  World\n";
+    
+    let collapse1 = text::collapse_whitespace(tidy_text);
+    println!("合并空白 '{}': '{}' (借用: {})", tidy_text, collapse1, matches!(collapse1, Cow::Borrowed(_)));
+    
+    let collapse2 = text::collapse_whitespace(messy_text);
+    println!("合并空白 {:?}: '{}' (借用: {})", messy_text, collapse2, matches!(collapse2, Cow::Borrowed(_)));
+    
+    // 生成slug
+    for title in ["rust-cow-guide", "Rust Cow: Clone on Write!"] {
+        let slug = text::slugify(title);
+        println!("生成slug '{}': '{}' (借用: {})", title, slug, matches!(slug, Cow::Borrowed(_)));
+    }
     println!();
     
     // 4. 路径处理示例
@@ -277,20 +226,27 @@ fn main() {
     let windows_path = "C:\\Users\\User\\Documents";
     let mixed_path = "/home/user\\documents";
     
-    let norm_unix = PathProcessor::normalize_path(unix_path);
+    let norm_unix = path::normalize_separators(unix_path, Separator::native());
     println!("标准化路径 '{}': '{}' (借用: {})", unix_path, norm_unix, matches!(norm_unix, Cow::Borrowed(_)));
     
-    let norm_mixed = PathProcessor::normalize_path(mixed_path);
+    let norm_mixed = path::normalize_separators(mixed_path, Separator::native());
     println!("标准化路径 '{}': '{}' (借用: {})", mixed_path, norm_mixed, matches!(norm_mixed, Cow::Borrowed(_)));
     
+    // 显式指定分隔符风格，结果与程序运行在哪个平台无关
+    let to_unix = path::normalize_separators(windows_path, Separator::Unix);
+    println!("转换为Unix风格 '{}': '{}' (借用: {})", windows_path, to_unix, matches!(to_unix, Cow::Borrowed(_)));
+    
+    let to_windows = path::normalize_separators(windows_path, Separator::Windows);
+    println!("转换为Windows风格 '{}': '{}' (借用: {})", windows_path, to_windows, matches!(to_windows, Cow::Borrowed(_)));
+    
     // 确保尾部分隔符
     let path_with_sep = "/home/user/";
     let path_without_sep = "/home/user";
     
-    let sep1 = PathProcessor::ensure_trailing_separator(path_with_sep);
+    let sep1 = path::ensure_trailing_separator(path_with_sep, Separator::native());
     println!("确保分隔符 '{}': '{}' (借用: {})", path_with_sep, sep1, matches!(sep1, Cow::Borrowed(_)));
     
-    let sep2 = PathProcessor::ensure_trailing_separator(path_without_sep);
+    let sep2 = path::ensure_trailing_separator(path_without_sep, Separator::native());
     println!("确保分隔符 '{}': '{}' (借用: {})", path_without_sep, sep2, matches!(sep2, Cow::Borrowed(_)));
     println!();
     
@@ -298,13 +254,17 @@ fn main() {
     println!("5. 数据转换示例:");
     
     let single_digits = vec![1, 2, 3, 4, 5];
+    let scattered_digits = vec![3, 1, 4];
     let mixed_numbers = vec![10, 20, 30];
     
     let str1 = DataConverter::numbers_to_string(&single_digits);
-    println!("单位数转换 {:?}: '{}' (拥有: {})", single_digits, str1, matches!(str1, Cow::Owned(_)));
+    println!("连续单位数转换 {:?}: '{}' (借用: {})", single_digits, str1, matches!(str1, Cow::Borrowed(_)));
+    
+    let str2 = DataConverter::numbers_to_string(&scattered_digits);
+    println!("非连续单位数转换 {:?}: '{}' (借用: {})", scattered_digits, str2, matches!(str2, Cow::Borrowed(_)));
     
-    let str2 = DataConverter::numbers_to_string(&mixed_numbers);
-    println!("混合数字转换 {:?}: '{}' (拥有: {})", mixed_numbers, str2, matches!(str2, Cow::Owned(_)));
+    let str3 = DataConverter::numbers_to_string(&mixed_numbers);
+    println!("混合数字转换 {:?}: '{}' (借用: {})", mixed_numbers, str3, matches!(str3, Cow::Borrowed(_)));
     
     // 用户名格式化
     let good_username = "john_doe123";
@@ -435,14 +395,33 @@ mod tests {
     }
     
     #[test]
-    fn test_text_processor() {
-        let clean = TextProcessor::clean_text("hello world");
+    fn test_text_functions() {
+        let clean = text::clean_whitespace("hello world");
         assert!(matches!(clean, Cow::Borrowed(_)));
         
-        let dirty = TextProcessor::clean_text("hello\tworld");
+        let dirty = text::clean_whitespace("hello\tworld");
         assert!(matches!(dirty, Cow::Owned(_)));
     }
     
+    #[test]
+    fn test_numbers_to_string() {
+        let consecutive = DataConverter::numbers_to_string(&[3, 4, 5]);
+        assert_eq!(consecutive, "345");
+        assert!(matches!(consecutive, Cow::Borrowed(_)));
+        
+        let empty = DataConverter::numbers_to_string(&[]);
+        assert_eq!(empty, "");
+        assert!(matches!(empty, Cow::Borrowed(_)));
+        
+        let scattered = DataConverter::numbers_to_string(&[3, 1, 4]);
+        assert_eq!(scattered, "314");
+        assert!(matches!(scattered, Cow::Owned(_)));
+        
+        let mixed = DataConverter::numbers_to_string(&[10, 2, 30]);
+        assert_eq!(mixed, "10,2,30");
+        assert!(matches!(mixed, Cow::Owned(_)));
+    }
+    
     #[test]
     fn test_config_manager() {
         let config = ConfigManager::new();
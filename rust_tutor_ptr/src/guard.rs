@@ -0,0 +1,98 @@
+//! 离开作用域时执行清理代码的守卫
+//!
+//! 示例7（自定义智能指针）中的`ResourceManager`在`Drop`里释放资源。
+//! [`ScopeGuard`]把这个模式抽出来：任意一段清理代码都可以绑定到作用域上，
+//! 无论是正常返回、提前`return`还是panic展开，离开作用域时都会执行。
+
+/// 被丢弃时调用闭包的守卫
+///
+/// ```
+/// use std::cell::Cell;
+/// use rust_tutor_ptr::guard::defer;
+///
+/// let cleaned = Cell::new(false);
+/// {
+///     let _guard = defer(|| cleaned.set(true));
+///     assert!(!cleaned.get());
+/// }
+/// assert!(cleaned.get());
+/// ```
+#[must_use = "守卫被立即丢弃时闭包会马上执行，请把它绑定到变量上"]
+pub struct ScopeGuard<F: FnMut()> {
+    callback: Option<F>,
+}
+
+impl<F: FnMut()> ScopeGuard<F> {
+    /// 创建守卫，`callback`会在守卫被丢弃时执行
+    pub fn new(callback: F) -> Self {
+        ScopeGuard {
+            callback: Some(callback),
+        }
+    }
+
+    /// 取消守卫，闭包不会再执行
+    pub fn dismiss(mut self) {
+        self.callback = None;
+    }
+}
+
+impl<F: FnMut()> Drop for ScopeGuard<F> {
+    fn drop(&mut self) {
+        if let Some(mut callback) = self.callback.take() {
+            callback();
+        }
+    }
+}
+
+/// 创建离开作用域时执行`f`的守卫，等价于[`ScopeGuard::new`]
+pub fn defer<F: FnMut()>(f: F) -> ScopeGuard<F> {
+    ScopeGuard::new(f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+    use std::panic;
+
+    #[test]
+    fn test_runs_on_scope_exit() {
+        let count = Cell::new(0);
+        {
+            let _guard = defer(|| count.set(count.get() + 1));
+            assert_eq!(count.get(), 0);
+        }
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn test_does_not_run_after_dismiss() {
+        let count = Cell::new(0);
+        {
+            let guard = ScopeGuard::new(|| count.set(count.get() + 1));
+            guard.dismiss();
+        }
+        assert_eq!(count.get(), 0);
+    }
+
+    #[test]
+    fn test_guards_run_in_reverse_order() {
+        let log = RefCell::new(Vec::new());
+        {
+            let _first = defer(|| log.borrow_mut().push("first"));
+            let _second = defer(|| log.borrow_mut().push("second"));
+        }
+        assert_eq!(*log.borrow(), vec!["second", "first"]);
+    }
+
+    #[test]
+    fn test_runs_during_panic_unwinding() {
+        let cleaned = Cell::new(false);
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            let _guard = defer(|| cleaned.set(true));
+            panic!("出错了");
+        }));
+        assert!(result.is_err());
+        assert!(cleaned.get());
+    }
+}
@@ -0,0 +1,106 @@
+//! 基于`Rc`的字符串驻留
+//!
+//! 示例2（Rc）展示了多个所有者共享同一份数据，示例6（Cow）展示了尽量避免复制。
+//! 字符串驻留把两者结合起来：相同内容的字符串只保存一份，之后每次驻留都只是增加引用计数。
+
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// 字符串驻留池，相同内容的字符串共享同一块内存
+///
+/// ```
+/// use std::rc::Rc;
+/// use rust_tutor_ptr::intern::StringInterner;
+///
+/// let mut interner = StringInterner::new();
+/// let a = interner.intern("hello");
+/// let b = interner.intern(&String::from("hello"));
+/// assert!(Rc::ptr_eq(&a, &b));
+/// assert_eq!(interner.len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct StringInterner {
+    strings: HashSet<Rc<str>>,
+}
+
+impl StringInterner {
+    /// 创建空的驻留池
+    pub fn new() -> Self {
+        StringInterner::default()
+    }
+
+    /// 驻留字符串：已经存在时返回已有的句柄，否则分配一份新的保存起来
+    pub fn intern(&mut self, s: &str) -> Rc<str> {
+        if let Some(existing) = self.strings.get(s) {
+            return Rc::clone(existing);
+        }
+        let interned: Rc<str> = Rc::from(s);
+        self.strings.insert(Rc::clone(&interned));
+        interned
+    }
+
+    /// 查找已驻留的字符串，不存在时不会分配
+    pub fn get(&self, s: &str) -> Option<Rc<str>> {
+        self.strings.get(s).cloned()
+    }
+
+    /// 是否已经驻留了`s`
+    pub fn contains(&self, s: &str) -> bool {
+        self.strings.contains(s)
+    }
+
+    /// 不同字符串的数量
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equal_strings_share_allocation() {
+        let mut interner = StringInterner::new();
+        let first = interner.intern("hello");
+        let owned = String::from("hel") + "lo";
+        let second = interner.intern(&owned);
+
+        assert!(Rc::ptr_eq(&first, &second));
+        assert_eq!(&*first, "hello");
+        // 池中一份，加上两个句柄
+        assert_eq!(Rc::strong_count(&first), 3);
+        assert_eq!(interner.len(), 1);
+    }
+
+    #[test]
+    fn test_different_strings_are_separate() {
+        let mut interner = StringInterner::new();
+        let hello = interner.intern("hello");
+        let world = interner.intern("world");
+        let empty = interner.intern("");
+
+        assert!(!Rc::ptr_eq(&hello, &world));
+        assert_eq!(interner.len(), 3);
+        assert!(Rc::ptr_eq(&empty, &interner.intern("")));
+        assert_eq!(interner.len(), 3);
+    }
+
+    #[test]
+    fn test_get_does_not_intern() {
+        let mut interner = StringInterner::new();
+        assert!(interner.is_empty());
+        assert_eq!(interner.get("你好"), None);
+        assert!(!interner.contains("你好"));
+
+        let handle = interner.intern("你好");
+        assert!(Rc::ptr_eq(&interner.get("你好").unwrap(), &handle));
+        assert!(interner.contains("你好"));
+        assert_eq!(interner.len(), 1);
+    }
+}
@@ -0,0 +1,24 @@
+//! # Rust 智能指针教程 - 可复用组件
+//!
+//! `src/examples`下的每个示例都是独立的可执行程序，
+//! 这个库把示例中反复出现的模式整理成可以直接复用和测试的组件。
+//!
+//! ## 模块
+//!
+//! - [`atomic`] - 基于原子类型的无锁计数器
+//! - [`config`] - 在线程间共享的应用配置，可选JSON序列化
+//! - [`guard`] - 离开作用域时执行清理闭包的守卫（`defer`）
+//! - [`intern`] - 基于`Rc`的字符串驻留，相同的字符串共享同一块内存
+//! - [`memo`] - 缓存函数结果的记忆化包装，包括同一参数只加载一次的异步版本
+//! - [`path`] - 可指定风格的路径分隔符处理
+//! - [`text`] - 借用优先（`Cow`）的字符串处理函数
+//! - [`tree`] - 节点存放在`Vec`中、用下标连接的树（arena模式）
+
+pub mod atomic;
+pub mod config;
+pub mod guard;
+pub mod intern;
+pub mod memo;
+pub mod path;
+pub mod text;
+pub mod tree;
@@ -0,0 +1,292 @@
+//! 记住函数结果的缓存包装
+//!
+//! 示例4（RefCell）中的缓存通过`&self`修改内部数据，并统计命中次数。
+//! [`Memoized`]把同样的内部可变性用在纯函数上：相同的参数只计算一次，之后直接返回缓存的结果。
+//!
+//! [`AsyncMemoized`]是多线程下的异步版本：每个参数对应一个`Arc<OnceCell<V>>`，
+//! 结果只会被写入一次。同一个参数同时被多个任务请求时，只有一个任务执行加载，
+//! 其余任务等待同一个`OnceCell`。
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::OnceCell;
+
+/// 带缓存的纯函数
+///
+/// ```
+/// use rust_tutor_ptr::memo::Memoized;
+///
+/// let square = Memoized::new(|n: &u64| n * n);
+/// assert_eq!(square.call(12), 144);
+/// assert_eq!(square.call(12), 144);
+/// assert_eq!((square.hits(), square.misses()), (1, 1));
+/// ```
+pub struct Memoized<K, V> {
+    func: Box<dyn Fn(&K) -> V>,
+    cache: RefCell<HashMap<K, V>>,
+    hits: Cell<u64>,
+    misses: Cell<u64>,
+}
+
+impl<K, V> Memoized<K, V>
+where
+    K: Eq + Hash,
+    V: Clone,
+{
+    /// 包装纯函数`func`：相同的参数必须总是得到相同的结果
+    pub fn new(func: impl Fn(&K) -> V + 'static) -> Self {
+        Memoized {
+            func: Box::new(func),
+            cache: RefCell::new(HashMap::new()),
+            hits: Cell::new(0),
+            misses: Cell::new(0),
+        }
+    }
+
+    /// 调用函数，已经计算过的参数直接返回缓存结果的克隆
+    pub fn call(&self, key: K) -> V {
+        if let Some(value) = self.cache.borrow().get(&key) {
+            self.hits.set(self.hits.get() + 1);
+            return value.clone();
+        }
+        self.misses.set(self.misses.get() + 1);
+        // 计算期间不持有借用，函数内部即使读取缓存统计也不会panic
+        let value = (self.func)(&key);
+        self.cache.borrow_mut().insert(key, value.clone());
+        value
+    }
+
+    /// 命中缓存的次数
+    pub fn hits(&self) -> u64 {
+        self.hits.get()
+    }
+
+    /// 未命中、实际调用函数的次数
+    pub fn misses(&self) -> u64 {
+        self.misses.get()
+    }
+
+    /// 已缓存的结果数量
+    pub fn len(&self) -> usize {
+        self.cache.borrow().len()
+    }
+
+    /// 是否还没有缓存任何结果
+    pub fn is_empty(&self) -> bool {
+        self.cache.borrow().is_empty()
+    }
+
+    /// 清空缓存，统计数据保留
+    pub fn clear(&self) {
+        self.cache.borrow_mut().clear();
+    }
+}
+
+impl<K, V> fmt::Debug for Memoized<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Memoized")
+            .field("cached", &self.cache.borrow().len())
+            .field("hits", &self.hits.get())
+            .field("misses", &self.misses.get())
+            .finish()
+    }
+}
+
+type BoxFuture<V> = Pin<Box<dyn Future<Output = V> + Send>>;
+type Loader<K, V> = Box<dyn Fn(K) -> BoxFuture<V> + Send + Sync>;
+
+/// 带缓存的异步加载函数，同一个参数同时只会有一次加载
+///
+/// ```
+/// use rust_tutor_ptr::memo::AsyncMemoized;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let user = AsyncMemoized::new(|id: u32| async move { format!("用户{}", id) });
+/// let (a, b) = tokio::join!(user.call(1), user.call(1));
+/// assert_eq!(a, "用户1");
+/// assert_eq!(b, "用户1");
+/// assert_eq!(user.len(), 1);
+/// # }
+/// ```
+pub struct AsyncMemoized<K, V> {
+    loader: Loader<K, V>,
+    // 锁只在取出或插入单元格时持有，加载期间不持有
+    cells: Mutex<HashMap<K, Arc<OnceCell<V>>>>,
+}
+
+impl<K, V> AsyncMemoized<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    /// 包装异步加载函数`loader`：相同的参数必须总是得到相同的结果
+    pub fn new<F, Fut>(loader: F) -> Self
+    where
+        F: Fn(K) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = V> + Send + 'static,
+    {
+        AsyncMemoized {
+            loader: Box::new(move |key| Box::pin(loader(key))),
+            cells: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 获取`key`对应的结果：已缓存时直接返回，正在加载时等待，否则由当前任务加载
+    pub async fn call(&self, key: K) -> V {
+        // 克隆出Arc之后就可以释放锁，单元格本身由所有请求这个参数的任务共享
+        let cell = Arc::clone(self.cells.lock().unwrap().entry(key.clone()).or_default());
+        // get_or_init保证同时只有一个任务运行加载函数；
+        // 加载的任务被取消时，单元格仍然是空的，下一个等待的任务会接手加载
+        cell.get_or_init(|| (self.loader)(key)).await.clone()
+    }
+
+    /// 读取已缓存的结果，不触发加载
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.cells.lock().unwrap().get(key)?.get().cloned()
+    }
+
+    /// 已缓存的结果数量（不包括正在加载的参数）
+    pub fn len(&self) -> usize {
+        let cells = self.cells.lock().unwrap();
+        cells.values().filter(|cell| cell.initialized()).count()
+    }
+
+    /// 是否还没有缓存任何结果
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K, V> fmt::Debug for AsyncMemoized<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells = self.cells.lock().unwrap();
+        let cached = cells.values().filter(|cell| cell.initialized()).count();
+        f.debug_struct("AsyncMemoized")
+            .field("cached", &cached)
+            .field("pending", &(cells.len() - cached))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_function_called_once_per_key() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let record = Rc::clone(&calls);
+        let describe = Memoized::new(move |n: &i32| {
+            record.borrow_mut().push(*n);
+            format!("数字{}", n)
+        });
+
+        for n in [1, 2, 1, 3, 2, 1] {
+            assert_eq!(describe.call(n), format!("数字{}", n));
+        }
+
+        assert_eq!(*calls.borrow(), vec![1, 2, 3]);
+        assert_eq!(describe.misses(), 3);
+        assert_eq!(describe.hits(), 3);
+        assert_eq!(describe.len(), 3);
+    }
+
+    #[test]
+    fn test_clear_forces_recomputation() {
+        let calls = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&calls);
+        let double = Memoized::new(move |n: &u32| {
+            counter.set(counter.get() + 1);
+            n * 2
+        });
+
+        assert!(double.is_empty());
+        assert_eq!(double.call(5), 10);
+        double.clear();
+        assert!(double.is_empty());
+        assert_eq!(double.call(5), 10);
+        assert_eq!(calls.get(), 2);
+        assert_eq!((double.hits(), double.misses()), (0, 2));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_calls_share_one_load() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration;
+
+        let loads = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&loads);
+        let memo = Arc::new(AsyncMemoized::new(move |key: &'static str| {
+            let counter = Arc::clone(&counter);
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                format!("{}的值", key)
+            }
+        }));
+
+        let tasks: Vec<_> = (0..100)
+            .map(|_| {
+                let memo = Arc::clone(&memo);
+                tokio::spawn(async move { memo.call("key").await })
+            })
+            .collect();
+        let results = tokio::time::timeout(Duration::from_secs(5), async {
+            let mut results = Vec::new();
+            for task in tasks {
+                results.push(task.await.unwrap());
+            }
+            results
+        })
+        .await
+        .expect("有任务没有被唤醒");
+
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+        assert!(results.iter().all(|value| value == "key的值"));
+        assert_eq!(memo.get(&"key"), Some("key的值".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_load_lets_waiter_take_over() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration;
+        use tokio::time::{sleep, timeout};
+
+        // 第一次加载很慢，之后的加载立即完成
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&attempts);
+        let memo = AsyncMemoized::new(move |_key: u32| {
+            let attempt = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if attempt == 0 {
+                    sleep(Duration::from_secs(10)).await;
+                    "慢加载"
+                } else {
+                    "接手加载"
+                }
+            }
+        });
+        assert!(memo.is_empty());
+
+        // 加载者被超时取消，等待者应当接手加载而不是挂起
+        let cancelled = timeout(Duration::from_millis(20), memo.call(1));
+        let waiter = async {
+            sleep(Duration::from_millis(5)).await;
+            timeout(Duration::from_secs(1), memo.call(1)).await
+        };
+        let (first, second) = tokio::join!(cancelled, waiter);
+
+        assert!(first.is_err());
+        assert_eq!(second, Ok("接手加载"));
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(memo.get(&1), Some("接手加载"));
+    }
+}
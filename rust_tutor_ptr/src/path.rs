@@ -0,0 +1,127 @@
+//! 跨平台的路径分隔符处理
+//!
+//! 来自示例6（Cow）中的`PathProcessor`。原来的实现用`cfg!(windows)`在编译期
+//! 决定分隔符，只能在对应的平台上测试另一种行为。这里改为显式传入[`Separator`]，
+//! 需要跟随当前平台时使用[`Separator::native`]。
+
+use std::borrow::Cow;
+
+/// 路径分隔符风格
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separator {
+    /// `/`
+    Unix,
+    /// `\`
+    Windows,
+}
+
+impl Separator {
+    /// 当前编译目标平台使用的分隔符
+    pub fn native() -> Self {
+        if cfg!(windows) {
+            Separator::Windows
+        } else {
+            Separator::Unix
+        }
+    }
+
+    /// 分隔符字符
+    pub fn as_char(self) -> char {
+        match self {
+            Separator::Unix => '/',
+            Separator::Windows => '\\',
+        }
+    }
+
+    /// 另一种风格的分隔符字符，也就是需要被替换掉的字符
+    fn foreign_char(self) -> char {
+        match self {
+            Separator::Unix => '\\',
+            Separator::Windows => '/',
+        }
+    }
+}
+
+/// 把路径中的分隔符统一成`style`风格，不含另一种分隔符时直接借用
+///
+/// ```
+/// use rust_tutor_ptr::path::{normalize_separators, Separator};
+///
+/// assert_eq!(normalize_separators("/home/user\\docs", Separator::Unix), "/home/user/docs");
+/// assert_eq!(normalize_separators("C:/Users/User", Separator::Windows), "C:\\Users\\User");
+/// ```
+pub fn normalize_separators(path: &str, style: Separator) -> Cow<'_, str> {
+    let foreign = style.foreign_char();
+    if path.contains(foreign) {
+        Cow::Owned(path.replace(foreign, style.as_char().encode_utf8(&mut [0; 4])))
+    } else {
+        Cow::Borrowed(path)
+    }
+}
+
+/// 确保路径以`style`风格的分隔符结尾，已经以它结尾时直接借用
+pub fn ensure_trailing_separator(path: &str, style: Separator) -> Cow<'_, str> {
+    let separator = style.as_char();
+    if path.ends_with(separator) {
+        Cow::Borrowed(path)
+    } else {
+        let mut owned = String::with_capacity(path.len() + 1);
+        owned.push_str(path);
+        owned.push(separator);
+        Cow::Owned(owned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_separators_unix() {
+        let unix = normalize_separators("/home/user/documents", Separator::Unix);
+        assert!(matches!(unix, Cow::Borrowed(_)));
+
+        let mixed = normalize_separators("/home/user\\documents", Separator::Unix);
+        assert!(matches!(mixed, Cow::Owned(_)));
+        assert_eq!(mixed, "/home/user/documents");
+    }
+
+    #[test]
+    fn test_normalize_separators_windows() {
+        let windows = normalize_separators("C:\\Users\\User", Separator::Windows);
+        assert!(matches!(windows, Cow::Borrowed(_)));
+
+        let mixed = normalize_separators("C:/Users\\User/Documents", Separator::Windows);
+        assert!(matches!(mixed, Cow::Owned(_)));
+        assert_eq!(mixed, "C:\\Users\\User\\Documents");
+    }
+
+    #[test]
+    fn test_ensure_trailing_separator() {
+        assert!(matches!(
+            ensure_trailing_separator("/home/user/", Separator::Unix),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(
+            ensure_trailing_separator("/home/user", Separator::Unix),
+            "/home/user/"
+        );
+
+        assert!(matches!(
+            ensure_trailing_separator("C:\\Users\\", Separator::Windows),
+            Cow::Borrowed(_)
+        ));
+        // Unix风格的结尾不算Windows分隔符
+        assert_eq!(
+            ensure_trailing_separator("C:\\Users/", Separator::Windows),
+            "C:\\Users/\\"
+        );
+    }
+
+    #[test]
+    fn test_native_matches_build_target() {
+        let expected = if cfg!(windows) { '\\' } else { '/' };
+        assert_eq!(Separator::native().as_char(), expected);
+        assert_eq!(Separator::native().as_char(), std::path::MAIN_SEPARATOR);
+    }
+}
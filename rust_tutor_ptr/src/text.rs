@@ -0,0 +1,229 @@
+//! 借用优先的字符串处理函数
+//!
+//! 大部分来自示例6（Cow）中的`TextProcessor`。所有函数都返回`Cow<str>`：
+//! 输入已经符合要求时直接借用，只有确实需要修改内容时才分配新的`String`。
+
+use std::borrow::Cow;
+
+/// 把制表符、换行等空白字符统一替换为空格
+///
+/// ```
+/// use std::borrow::Cow;
+/// use rust_tutor_ptr::text::clean_whitespace;
+///
+/// assert!(matches!(clean_whitespace("hello world"), Cow::Borrowed(_)));
+/// assert_eq!(clean_whitespace("hello\tworld\n"), "hello world ");
+/// ```
+pub fn clean_whitespace(input: &str) -> Cow<'_, str> {
+    let needs_cleaning = input.chars().any(|c| c.is_whitespace() && c != ' ');
+    if needs_cleaning {
+        let cleaned = input
+            .chars()
+            .map(|c| if c.is_whitespace() { ' ' } else { c })
+            .collect();
+        Cow::Owned(cleaned)
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// 去掉首尾空白，没有需要去掉的空白时直接借用
+///
+/// 与示例6中原来的`normalize_text`相同：需要修剪时返回新分配的`String`，
+/// 调用者可以通过`Cow::Borrowed`知道输入没有被修改。
+/// 只需要修剪后的切片、不关心是否发生了变化时，直接用`str::trim`更合适，它从不分配。
+///
+/// ```
+/// use std::borrow::Cow;
+/// use rust_tutor_ptr::text::trim_cow;
+///
+/// assert!(matches!(trim_cow("Hello"), Cow::Borrowed("Hello")));
+/// assert!(matches!(trim_cow("  Hello  "), Cow::Owned(_)));
+/// ```
+pub fn trim_cow(input: &str) -> Cow<'_, str> {
+    let trimmed = input.trim();
+    if trimmed.len() == input.len() {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(trimmed.to_string())
+    }
+}
+
+/// 确保字符串以`prefix`开头，已经有前缀时直接借用
+///
+/// ```
+/// use rust_tutor_ptr::text::ensure_prefix;
+///
+/// assert_eq!(ensure_prefix("John", "Mr. "), "Mr. John");
+/// assert_eq!(ensure_prefix("Mr. John", "Mr. "), "Mr. John");
+/// ```
+pub fn ensure_prefix<'a>(input: &'a str, prefix: &str) -> Cow<'a, str> {
+    if input.starts_with(prefix) {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(format!("{}{}", prefix, input))
+    }
+}
+
+/// 去掉首尾空白，并把中间连续的空白字符合并成一个空格
+///
+/// 与[`trim_cow`]一致，只有输入本身已经是规范形式（首尾没有空白、只有单个空格分隔）
+/// 时才借用输入，其余情况都返回新拼接的`String`。
+///
+/// ```
+/// use std::borrow::Cow;
+/// use rust_tutor_ptr::text::collapse_whitespace;
+///
+/// assert_eq!(collapse_whitespace("  a \t b\n\nc "), "a b c");
+/// assert!(matches!(collapse_whitespace("a b"), Cow::Borrowed("a b")));
+/// assert!(matches!(collapse_whitespace(" a b "), Cow::Owned(_)));
+/// ```
+pub fn collapse_whitespace(input: &str) -> Cow<'_, str> {
+    let is_collapsed = input.trim().len() == input.len()
+        && !input.contains("  ")
+        && input.chars().all(|c| c == ' ' || !c.is_whitespace());
+    if is_collapsed {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(input.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+}
+
+/// 生成URL友好的slug：ASCII字母转小写，连续的非字母数字字符替换为一个`-`，
+/// 并去掉首尾的`-`
+///
+/// 非ASCII的字母和数字（如中文）原样保留。输入已经是合法slug时直接借用。
+///
+/// ```
+/// use std::borrow::Cow;
+/// use rust_tutor_ptr::text::slugify;
+///
+/// assert_eq!(slugify("Hello World!"), "hello-world");
+/// assert_eq!(slugify("  Rust 智能指针 -- Cow "), "rust-智能指针-cow");
+/// assert!(matches!(slugify("already-a-slug"), Cow::Borrowed(_)));
+/// ```
+pub fn slugify(input: &str) -> Cow<'_, str> {
+    if is_slug(input) {
+        return Cow::Borrowed(input);
+    }
+
+    let mut slug = String::with_capacity(input.len());
+    let mut pending_hyphen = false;
+    for c in input.chars() {
+        if c.is_alphanumeric() {
+            // 分隔符只在两段字母数字之间输出，首尾的分隔符自然被丢弃
+            if pending_hyphen && !slug.is_empty() {
+                slug.push('-');
+            }
+            pending_hyphen = false;
+            slug.push(c.to_ascii_lowercase());
+        } else {
+            pending_hyphen = true;
+        }
+    }
+    Cow::Owned(slug)
+}
+
+/// 是否已经是slugify的输出形式
+fn is_slug(input: &str) -> bool {
+    !input.starts_with('-')
+        && !input.ends_with('-')
+        && !input.contains("--")
+        && input
+            .chars()
+            .all(|c| c == '-' || (c.is_alphanumeric() && !c.is_ascii_uppercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_whitespace() {
+        assert!(matches!(
+            clean_whitespace("hello world"),
+            Cow::Borrowed("hello world")
+        ));
+        let cleaned = clean_whitespace("hello\tworld\ntest");
+        assert!(matches!(cleaned, Cow::Owned(_)));
+        assert_eq!(cleaned, "hello world test");
+    }
+
+    #[test]
+    fn test_trim_cow_borrows_only_when_unchanged() {
+        let clean = "Hello";
+        match trim_cow(clean) {
+            // 借用的就是输入本身
+            Cow::Borrowed(s) => assert_eq!(s.as_ptr(), clean.as_ptr()),
+            Cow::Owned(_) => panic!("没有空白时不应该分配"),
+        }
+        assert!(matches!(trim_cow(""), Cow::Borrowed("")));
+
+        for padded in ["  Hello  ", "Hello\n", "\tHello"] {
+            let trimmed = trim_cow(padded);
+            assert!(matches!(trimmed, Cow::Owned(_)), "输入: {:?}", padded);
+            assert_eq!(trimmed, "Hello");
+        }
+        // 全是空白时得到空字符串
+        assert_eq!(trim_cow("   "), "");
+    }
+
+    #[test]
+    fn test_ensure_prefix() {
+        assert!(matches!(
+            ensure_prefix("Mr. John", "Mr. "),
+            Cow::Borrowed(_)
+        ));
+        let prefixed = ensure_prefix("John", "Mr. ");
+        assert!(matches!(prefixed, Cow::Owned(_)));
+        assert_eq!(prefixed, "Mr. John");
+        assert!(matches!(ensure_prefix("John", ""), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_collapse_whitespace() {
+        assert!(matches!(
+            collapse_whitespace("a b c"),
+            Cow::Borrowed("a b c")
+        ));
+        assert!(matches!(collapse_whitespace(""), Cow::Borrowed("")));
+
+        // 只需要去掉首尾空白时同样会分配，Borrowed总是表示输入没有被修改
+        let trimmed = collapse_whitespace("\t a b c \n");
+        assert!(matches!(trimmed, Cow::Owned(_)));
+        assert_eq!(trimmed, "a b c");
+
+        let collapsed = collapse_whitespace("a  b\tc");
+        assert!(matches!(collapsed, Cow::Owned(_)));
+        assert_eq!(collapsed, "a b c");
+        assert_eq!(collapse_whitespace(" \n\t "), "");
+    }
+
+    #[test]
+    fn test_slugify() {
+        let slug = slugify("Hello World!");
+        assert!(matches!(slug, Cow::Owned(_)));
+        assert_eq!(slug, "hello-world");
+
+        assert!(matches!(
+            slugify("hello-world"),
+            Cow::Borrowed("hello-world")
+        ));
+        assert!(matches!(slugify("rust2024"), Cow::Borrowed(_)));
+        assert!(matches!(slugify(""), Cow::Borrowed("")));
+
+        assert_eq!(
+            slugify("--Rust & Cow: Clone on Write--"),
+            "rust-cow-clone-on-write"
+        );
+        assert_eq!(slugify("a--b"), "a-b");
+        assert_eq!(slugify("!!!"), "");
+    }
+
+    #[test]
+    fn test_slugify_keeps_unicode_letters() {
+        assert_eq!(slugify("智能指针 教程"), "智能指针-教程");
+        assert!(matches!(slugify("智能指针-教程"), Cow::Borrowed(_)));
+        assert_eq!(slugify("Café Über"), "café-Über");
+    }
+}
@@ -0,0 +1,528 @@
+//! 基于arena（对象池）的树
+//!
+//! 示例5（Weak）中的`TreeNode`用`Rc<RefCell<...>>`连接子节点、用`Weak`指向父节点：
+//! 每次访问都要修改引用计数、检查借用状态，写错一个`Rc`就会形成循环引用导致内存泄漏。
+//! [`ArenaTree`]把所有节点放在同一个`Vec`中，父子关系只记录下标。
+//! 整棵树只有一个所有者，不需要`Rc`、`RefCell`和`Weak`，也不可能出现引用循环。
+//!
+//! 树可以用缩进表示成文本（[`ArenaTree::to_nested_string`]），
+//! 再用[`ArenaTree::from_nested_string`]解析回来。
+
+use std::error::Error;
+use std::fmt;
+
+/// 节点在树中的下标，由[`ArenaTree::add_root`]和[`ArenaTree::add_child`]返回
+pub type NodeId = usize;
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    value: T,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+/// 节点保存在`Vec`中、用下标互相引用的树
+///
+/// 可以有多个根节点（森林）。节点只能添加不能删除，所以下标一旦分配就一直有效。
+/// 传入不属于这棵树的下标时，访问方法会panic，与`Vec`越界的行为一致。
+///
+/// ```
+/// use rust_tutor_ptr::tree::ArenaTree;
+///
+/// let mut tree = ArenaTree::new();
+/// let root = tree.add_root("根");
+/// let child = tree.add_child(root, "子");
+/// let grandchild = tree.add_child(child, "孙");
+///
+/// assert_eq!(tree.children(root), &[child]);
+/// let ancestors: Vec<&str> = tree.ancestors(grandchild).map(|id| tree[id]).collect();
+/// assert_eq!(ancestors, ["子", "根"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArenaTree<T> {
+    nodes: Vec<Node<T>>,
+}
+
+impl<T> Default for ArenaTree<T> {
+    fn default() -> Self {
+        ArenaTree { nodes: Vec::new() }
+    }
+}
+
+impl<T> ArenaTree<T> {
+    /// 创建空树
+    pub fn new() -> Self {
+        ArenaTree::default()
+    }
+
+    /// 添加一个没有父节点的根节点
+    pub fn add_root(&mut self, value: T) -> NodeId {
+        self.push(value, None)
+    }
+
+    /// 在`parent`下添加一个子节点，子节点按添加顺序排列
+    pub fn add_child(&mut self, parent: NodeId, value: T) -> NodeId {
+        assert!(parent < self.nodes.len(), "节点{}不存在", parent);
+        let child = self.push(value, Some(parent));
+        self.nodes[parent].children.push(child);
+        child
+    }
+
+    fn push(&mut self, value: T, parent: Option<NodeId>) -> NodeId {
+        self.nodes.push(Node {
+            value,
+            parent,
+            children: Vec::new(),
+        });
+        self.nodes.len() - 1
+    }
+
+    /// 节点的值，下标不存在时返回`None`
+    pub fn get(&self, node: NodeId) -> Option<&T> {
+        self.nodes.get(node).map(|n| &n.value)
+    }
+
+    /// 节点的值的可变引用，下标不存在时返回`None`
+    pub fn get_mut(&mut self, node: NodeId) -> Option<&mut T> {
+        self.nodes.get_mut(node).map(|n| &mut n.value)
+    }
+
+    /// 父节点，根节点返回`None`
+    pub fn parent(&self, node: NodeId) -> Option<NodeId> {
+        self.nodes[node].parent
+    }
+
+    /// 直接子节点
+    pub fn children(&self, node: NodeId) -> &[NodeId] {
+        &self.nodes[node].children
+    }
+
+    /// 从父节点开始一直到根节点的所有祖先（不包括`node`本身）
+    pub fn ancestors(&self, node: NodeId) -> Ancestors<'_, T> {
+        Ancestors {
+            tree: self,
+            next: self.parent(node),
+        }
+    }
+
+    /// 从根节点到`node`的路径，第一个是根节点，最后一个是`node`本身
+    pub fn path_to_root(&self, node: NodeId) -> Vec<NodeId> {
+        let mut path: Vec<NodeId> = std::iter::once(node).chain(self.ancestors(node)).collect();
+        path.reverse();
+        path
+    }
+
+    /// 树的高度：最长的根到叶子路径上的节点数，空树为0，只有根节点时为1
+    ///
+    /// 不使用递归，深度很大的树也不会栈溢出。节点只能添加到已经存在的父节点下，
+    /// 所以父节点的下标总是小于子节点，按下标顺序遍历一次就能算出每个节点的深度。
+    pub fn height(&self) -> usize {
+        let mut depths: Vec<usize> = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let depth = match node.parent {
+                Some(parent) => depths[parent] + 1,
+                None => 1,
+            };
+            depths.push(depth);
+        }
+        depths.into_iter().max().unwrap_or(0)
+    }
+
+    /// 所有根节点，按添加顺序排列
+    pub fn roots(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.parent.is_none())
+            .map(|(id, _)| id)
+    }
+
+    /// 节点总数
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// 是否没有任何节点
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+impl<T: fmt::Display> ArenaTree<T> {
+    /// 用缩进表示树：每个节点一行，每深一层多缩进两个空格，子节点紧跟在父节点之后
+    ///
+    /// 为了能无损地解析回来，值中的反斜杠、换行符和回车符分别转义成`\\`、`\n`、`\r`，
+    /// 开头的空格写成`\s`（否则会被当成缩进）。
+    ///
+    /// ```
+    /// use rust_tutor_ptr::tree::ArenaTree;
+    ///
+    /// let mut tree = ArenaTree::new();
+    /// let root = tree.add_root("src");
+    /// let examples = tree.add_child(root, "examples");
+    /// tree.add_child(examples, "example_05_weak.rs");
+    /// tree.add_child(root, "lib.rs");
+    ///
+    /// let text = tree.to_nested_string();
+    /// assert_eq!(text, "src\n  examples\n    example_05_weak.rs\n  lib.rs\n");
+    /// let parsed = ArenaTree::from_nested_string(&text).unwrap();
+    /// assert_eq!(parsed.to_nested_string(), text);
+    /// ```
+    pub fn to_nested_string(&self) -> String {
+        let mut output = String::new();
+        // 用显式的栈做前序遍历，深度很大的树也不会栈溢出；逆序入栈保证子节点按顺序输出
+        let mut stack: Vec<(NodeId, usize)> = self.roots().map(|root| (root, 0)).collect();
+        stack.reverse();
+        while let Some((node, depth)) = stack.pop() {
+            output.push_str(&"  ".repeat(depth));
+            output.push_str(&escape(&self[node].to_string()));
+            output.push('\n');
+            stack.extend(
+                self.children(node)
+                    .iter()
+                    .rev()
+                    .map(|&child| (child, depth + 1)),
+            );
+        }
+        output
+    }
+}
+
+impl ArenaTree<String> {
+    /// 解析[`ArenaTree::to_nested_string`]产生的文本
+    ///
+    /// 缩进必须是偶数个空格，并且每行最多比上一行深一层；没有缩进的行是根节点。
+    /// 节点下标按文本中的顺序（前序）分配，不一定与原来的树相同。
+    pub fn from_nested_string(s: &str) -> Result<ArenaTree<String>, ParseError> {
+        let mut tree = ArenaTree::new();
+        // path[i]：当前行之前最近的第i层节点
+        let mut path: Vec<NodeId> = Vec::new();
+        for (index, line) in s.lines().enumerate() {
+            let line_number = index + 1;
+            let indent = line.len() - line.trim_start_matches(' ').len();
+            if indent % 2 != 0 {
+                return Err(ParseError::OddIndentation { line: line_number });
+            }
+            let depth = indent / 2;
+            if depth > path.len() {
+                return Err(ParseError::UnexpectedIndent { line: line_number });
+            }
+            let value =
+                unescape(&line[indent..]).ok_or(ParseError::InvalidEscape { line: line_number })?;
+
+            path.truncate(depth);
+            let node = match path.last() {
+                Some(&parent) => tree.add_child(parent, value),
+                None => tree.add_root(value),
+            };
+            path.push(node);
+        }
+        Ok(tree)
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for (index, ch) in value.char_indices() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            ' ' if index == 0 => escaped.push_str("\\s"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// [`escape`]的逆操作，遇到不认识的转义序列返回`None`
+fn unescape(escaped: &str) -> Option<String> {
+    let mut value = String::with_capacity(escaped.len());
+    let mut chars = escaped.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            value.push(ch);
+            continue;
+        }
+        value.push(match chars.next()? {
+            '\\' => '\\',
+            'n' => '\n',
+            'r' => '\r',
+            's' => ' ',
+            _ => return None,
+        });
+    }
+    Some(value)
+}
+
+/// [`ArenaTree::from_nested_string`]解析失败的原因，`line`是从1开始的行号
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// 缩进的空格数是奇数
+    OddIndentation { line: usize },
+    /// 缩进比上一行深了不止一层（或者第一行就有缩进）
+    UnexpectedIndent { line: usize },
+    /// 值中有无法识别的`\\`转义
+    InvalidEscape { line: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::OddIndentation { line } => {
+                write!(f, "第{}行: 缩进必须是偶数个空格", line)
+            }
+            ParseError::UnexpectedIndent { line } => {
+                write!(f, "第{}行: 缩进比上一行多了不止一层", line)
+            }
+            ParseError::InvalidEscape { line } => write!(f, "第{}行: 无效的转义序列", line),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl<T> std::ops::Index<NodeId> for ArenaTree<T> {
+    type Output = T;
+
+    fn index(&self, node: NodeId) -> &T {
+        &self.nodes[node].value
+    }
+}
+
+/// [`ArenaTree::ancestors`]返回的迭代器
+#[derive(Debug)]
+pub struct Ancestors<'a, T> {
+    tree: &'a ArenaTree<T>,
+    next: Option<NodeId>,
+}
+
+impl<T> Iterator for Ancestors<'_, T> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        let current = self.next?;
+        self.next = self.tree.parent(current);
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 构建与示例5相同的树：1 -> (2 -> 4), 3
+    fn sample() -> (ArenaTree<i32>, [NodeId; 4]) {
+        let mut tree = ArenaTree::new();
+        let root = tree.add_root(1);
+        let child1 = tree.add_child(root, 2);
+        let child2 = tree.add_child(root, 3);
+        let grandchild = tree.add_child(child1, 4);
+        (tree, [root, child1, child2, grandchild])
+    }
+
+    #[test]
+    fn test_build_and_children() {
+        let (tree, [root, child1, child2, grandchild]) = sample();
+        assert_eq!(tree.len(), 4);
+        assert_eq!(tree.children(root), &[child1, child2]);
+        assert_eq!(tree.children(child1), &[grandchild]);
+        assert!(tree.children(child2).is_empty());
+        assert_eq!(tree.parent(grandchild), Some(child1));
+        assert_eq!(tree.parent(root), None);
+        assert_eq!(tree[grandchild], 4);
+        assert_eq!(tree.get(99), None);
+    }
+
+    #[test]
+    fn test_ancestors() {
+        let (tree, [root, child1, child2, grandchild]) = sample();
+        assert_eq!(
+            tree.ancestors(grandchild).collect::<Vec<_>>(),
+            vec![child1, root]
+        );
+        assert_eq!(tree.ancestors(child2).collect::<Vec<_>>(), vec![root]);
+        assert_eq!(tree.ancestors(root).count(), 0);
+
+        let values: Vec<i32> = tree.ancestors(grandchild).map(|id| tree[id]).collect();
+        assert_eq!(values, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_forest_and_mutation() {
+        let mut tree = ArenaTree::new();
+        assert!(tree.is_empty());
+        let a = tree.add_root(String::from("a"));
+        let b = tree.add_root(String::from("b"));
+        let a1 = tree.add_child(a, String::from("a1"));
+        assert_eq!(tree.roots().collect::<Vec<_>>(), vec![a, b]);
+
+        tree.get_mut(a1).unwrap().push('!');
+        assert_eq!(tree[a1], "a1!");
+        assert_eq!(tree.ancestors(a1).collect::<Vec<_>>(), vec![a]);
+    }
+
+    #[test]
+    #[should_panic(expected = "节点5不存在")]
+    fn test_add_child_to_missing_parent_panics() {
+        let mut tree = ArenaTree::new();
+        tree.add_root(0);
+        tree.add_child(5, 1);
+    }
+
+    /// 故意不平衡的树：根节点下一侧是长度为5的链，另一侧只有一个叶子
+    ///
+    /// ```text
+    /// 0
+    /// ├── 1
+    /// │   └── 3
+    /// │       └── 4
+    /// │           ├── 5
+    /// │           └── 6
+    /// │               └── 7
+    /// └── 2
+    /// ```
+    fn unbalanced() -> ArenaTree<char> {
+        let mut tree = ArenaTree::new();
+        let root = tree.add_root('a');
+        let left = tree.add_child(root, 'b');
+        tree.add_child(root, 'c');
+        let mid = tree.add_child(left, 'd');
+        let deep = tree.add_child(mid, 'e');
+        tree.add_child(deep, 'f');
+        let deeper = tree.add_child(deep, 'g');
+        tree.add_child(deeper, 'h');
+        tree
+    }
+
+    #[test]
+    fn test_path_to_root() {
+        let tree = unbalanced();
+        assert_eq!(tree.path_to_root(7), vec![0, 1, 3, 4, 6, 7]);
+        let values: String = tree
+            .path_to_root(7)
+            .into_iter()
+            .map(|id| tree[id])
+            .collect();
+        assert_eq!(values, "abdegh");
+        assert_eq!(tree.path_to_root(2), vec![0, 2]);
+        assert_eq!(tree.path_to_root(0), vec![0]);
+    }
+
+    #[test]
+    fn test_height() {
+        assert_eq!(ArenaTree::<i32>::new().height(), 0);
+
+        let mut single = ArenaTree::new();
+        single.add_root(());
+        assert_eq!(single.height(), 1);
+
+        let tree = unbalanced();
+        assert_eq!(tree.height(), 6);
+        // 高度等于最长路径的节点数
+        let longest = (0..tree.len()).map(|id| tree.path_to_root(id).len()).max();
+        assert_eq!(longest, Some(tree.height()));
+
+        // 森林的高度取最高的那棵树
+        let (mut forest, [root, ..]) = sample();
+        let other = forest.add_root(10);
+        let mut node = other;
+        for value in 11..15 {
+            node = forest.add_child(node, value);
+        }
+        assert_eq!(forest.height(), 5);
+        forest.add_child(root, 0);
+        assert_eq!(forest.height(), 5);
+    }
+
+    #[test]
+    fn test_height_of_deep_chain_does_not_overflow() {
+        let mut tree = ArenaTree::new();
+        let mut node = tree.add_root(0);
+        for i in 1..200_000 {
+            node = tree.add_child(node, i);
+        }
+        assert_eq!(tree.height(), 200_000);
+        assert_eq!(tree.path_to_root(node).len(), 200_000);
+    }
+
+    /// 按前序比较两棵树的结构和值（不比较节点下标）
+    fn assert_same_structure(a: &ArenaTree<String>, b: &ArenaTree<String>) {
+        fn walk(tree: &ArenaTree<String>) -> Vec<(usize, String, usize)> {
+            let mut out = Vec::new();
+            let mut stack: Vec<(NodeId, usize)> = tree.roots().map(|r| (r, 0)).collect();
+            stack.reverse();
+            while let Some((node, depth)) = stack.pop() {
+                out.push((depth, tree[node].clone(), tree.children(node).len()));
+                stack.extend(tree.children(node).iter().rev().map(|&c| (c, depth + 1)));
+            }
+            out
+        }
+        assert_eq!(walk(a), walk(b));
+    }
+
+    #[test]
+    fn test_nested_string_round_trip() {
+        let mut tree = ArenaTree::new();
+        let root = tree.add_root(String::from("公司"));
+        let tech = tree.add_child(root, String::from("技术部"));
+        tree.add_child(tech, String::from("后端组"));
+        let frontend = tree.add_child(tech, String::from("前端组"));
+        tree.add_child(frontend, String::from("小王"));
+        tree.add_child(root, String::from("市场部"));
+        // 先加的节点后挂子节点，下标顺序与前序不同
+        tree.add_child(root, String::from("财务部"));
+        tree.add_child(tech, String::from("测试组"));
+
+        let text = tree.to_nested_string();
+        assert_eq!(
+            text,
+            "公司\n  技术部\n    后端组\n    前端组\n      小王\n    测试组\n  市场部\n  财务部\n"
+        );
+        let parsed = ArenaTree::from_nested_string(&text).unwrap();
+        assert_eq!(parsed.len(), tree.len());
+        assert_eq!(parsed.height(), tree.height());
+        assert_same_structure(&parsed, &tree);
+        assert_eq!(parsed.to_nested_string(), text);
+    }
+
+    #[test]
+    fn test_round_trip_is_lossless_for_awkward_values() {
+        let mut tree = ArenaTree::new();
+        let root = tree.add_root(String::from("  以空格开头"));
+        tree.add_child(root, String::from("多行\n文本\r\n"));
+        tree.add_child(root, String::from(r"C:\路径\n不是换行"));
+        let empty = tree.add_child(root, String::new());
+        tree.add_child(empty, String::from("\\s"));
+        tree.add_root(String::from("第二棵树 "));
+
+        let text = tree.to_nested_string();
+        // 每个节点正好一行
+        assert_eq!(text.lines().count(), tree.len());
+        let parsed = ArenaTree::from_nested_string(&text).unwrap();
+        assert_same_structure(&parsed, &tree);
+        assert_eq!(parsed.roots().count(), 2);
+    }
+
+    #[test]
+    fn test_parse_empty_and_errors() {
+        assert!(ArenaTree::from_nested_string("").unwrap().is_empty());
+
+        assert_eq!(
+            ArenaTree::from_nested_string("a\n   b").unwrap_err(),
+            ParseError::OddIndentation { line: 2 }
+        );
+        assert_eq!(
+            ArenaTree::from_nested_string("a\n  b\n      c").unwrap_err(),
+            ParseError::UnexpectedIndent { line: 3 }
+        );
+        assert_eq!(
+            ArenaTree::from_nested_string("  a").unwrap_err(),
+            ParseError::UnexpectedIndent { line: 1 }
+        );
+        let err = ArenaTree::from_nested_string("a\\t").unwrap_err();
+        assert_eq!(err, ParseError::InvalidEscape { line: 1 });
+        assert_eq!(err.to_string(), "第1行: 无效的转义序列");
+        assert!(ArenaTree::from_nested_string("a\\").is_err());
+    }
+}